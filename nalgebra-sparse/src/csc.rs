@@ -12,8 +12,11 @@ use crate::csr::CsrMatrix;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
-use nalgebra::Scalar;
-use num_traits::One;
+use crate::ops::serial::spmm_csc_dense;
+use crate::ops::Op;
+use nalgebra::linalg::LinearOperator;
+use nalgebra::{ClosedAdd, ClosedMul, DVector, Scalar};
+use num_traits::{One, Zero};
 use std::slice::{Iter, IterMut};
 
 /// A CSC representation of a sparse matrix.
@@ -582,6 +585,25 @@ impl<T> Default for CscMatrix<T> {
     }
 }
 
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn nrows(&self) -> usize {
+        CscMatrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        CscMatrix::ncols(self)
+    }
+
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        // `y` may contain NaNs, which would be propagated even with a zero `beta`.
+        y.fill(T::zero());
+        spmm_csc_dense(T::zero(), y, T::one(), Op::NoOp(self), Op::NoOp(x));
+    }
+}
+
 /// Convert pattern format errors into more meaningful CSC-specific errors.
 ///
 /// This ensures that the terminology is consistent: we are talking about rows and columns,
@@ -1,4 +1,5 @@
-use nalgebra::DMatrix;
+use nalgebra::linalg::LinearOperator;
+use nalgebra::{DMatrix, DVector};
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
@@ -732,4 +733,15 @@ proptest! {
         prop_assert_eq!(csc.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csc), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csc_linear_operator_apply(csc in csc_strategy()) {
+        let x = DVector::from_fn(csc.ncols(), |i, _| i as i32 - 2);
+        // The previous content of the output must be ignored.
+        let mut y = DVector::from_element(csc.nrows(), 7);
+        csc.apply(&x, &mut y);
+        prop_assert_eq!(y, DMatrix::from(&csc) * x);
+        prop_assert_eq!(LinearOperator::<i32>::nrows(&csc), csc.nrows());
+        prop_assert_eq!(LinearOperator::<i32>::ncols(&csc), csc.ncols());
+    }
 }
@@ -1,4 +1,5 @@
-use nalgebra::DMatrix;
+use nalgebra::linalg::LinearOperator;
+use nalgebra::{DMatrix, DVector};
 use nalgebra_sparse::csr::CsrMatrix;
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
@@ -726,4 +727,15 @@ proptest! {
         prop_assert_eq!(csr.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csr), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csr_linear_operator_apply(csr in csr_strategy()) {
+        let x = DVector::from_fn(csr.ncols(), |i, _| i as i32 - 2);
+        // The previous content of the output must be ignored.
+        let mut y = DVector::from_element(csr.nrows(), 7);
+        csr.apply(&x, &mut y);
+        prop_assert_eq!(y, DMatrix::from(&csr) * x);
+        prop_assert_eq!(LinearOperator::<i32>::nrows(&csr), csr.nrows());
+        prop_assert_eq!(LinearOperator::<i32>::ncols(&csr), csr.ncols());
+    }
 }
@@ -0,0 +1,62 @@
+use nalgebra::linalg::{lanczos_shift_invert, FnOperator, KrylovEigenOptions, LinearOperator};
+use nalgebra::DVector;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::factorization::CscCholesky;
+
+#[test]
+fn cs_cholesky_shift_invert_lanczos() {
+    // The smallest eigenpairs of the Laplacian of a 40x30 grid graph.
+    let (nx, ny) = (40, 30);
+    let n = nx * ny;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..nx {
+        for j in 0..ny {
+            let k = i * ny + j;
+            let neighbors = [(i + 1 < nx, k + ny), (j + 1 < ny, k + 1)];
+            for &(exists, l) in neighbors.iter() {
+                if exists {
+                    coo.push(k, l, -1.0);
+                    coo.push(l, k, -1.0);
+                    coo.push(k, k, 1.0);
+                    coo.push(l, l, 1.0);
+                }
+            }
+        }
+    }
+    let laplacian = CsrMatrix::from(&coo);
+
+    let sigma = -1.0e-2;
+    let shifted = CscMatrix::from(&coo) + CscMatrix::identity(n) * -sigma;
+    let cholesky = CscCholesky::factor(&shifted).unwrap();
+    let inverse = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+        y.copy_from(x);
+        cholesky.solve_mut(&mut *y);
+    });
+
+    let options = KrylovEigenOptions {
+        ncv: 20,
+        tolerance: 1.0e-12,
+        max_niter: 0,
+        ..KrylovEigenOptions::new(6)
+    };
+    let eigen = lanczos_shift_invert(&inverse, sigma, options);
+    assert!(eigen.converged);
+
+    // The eigenvalues of the grid Laplacian are the sums of those of two path Laplacians.
+    let path = |m: usize, p: usize| 2.0 - 2.0 * (std::f64::consts::PI * p as f64 / m as f64).cos();
+    let mut expected: Vec<f64> = (0..nx)
+        .flat_map(|p| (0..ny).map(move |q| path(nx, p) + path(ny, q)))
+        .collect();
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for i in 0..6 {
+        assert!((eigen.eigenvalues[i] - expected[i]).abs() < 1.0e-9);
+
+        let v = eigen.eigenvectors.column(i).into_owned();
+        let mut lv = DVector::zeros(n);
+        laplacian.apply(&v, &mut lv);
+        assert!((lv - v * eigen.eigenvalues[i]).norm() < 1.0e-8);
+    }
+}
@@ -3,6 +3,7 @@ mod convert_serial;
 mod coo;
 mod csc;
 mod csr;
+mod krylov_eigen;
 mod matrix_market;
 mod ops;
 mod pattern;
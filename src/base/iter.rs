@@ -279,12 +279,33 @@ impl<'a, T, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
 /// An iterator through the rows of a matrix.
 pub struct RowIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
     mat: &'a Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> RowIter<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<T, R, C, S>) -> Self {
-        RowIter { mat, curr: 0 }
+        RowIter {
+            mat,
+            range: 0..mat.nrows(),
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        // SAFETY: this makes sure the generated ranges are valid.
+        let split_pos = (self.range.start + index).min(self.range.end);
+
+        let left_iter = RowIter {
+            mat: self.mat,
+            range: self.range.start..split_pos,
+        };
+
+        let right_iter = RowIter {
+            mat: self.mat,
+            range: split_pos..self.range.end,
+        };
+
+        (left_iter, right_iter)
     }
 }
 
@@ -293,9 +314,10 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> Iterator for RowIter<'a
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.nrows() {
-            let res = self.mat.row(self.curr);
-            self.curr += 1;
+        debug_assert!(self.range.start <= self.range.end);
+        if self.range.start < self.range.end {
+            let res = self.mat.row(self.range.start);
+            self.range.start += 1;
             Some(res)
         } else {
             None
@@ -304,15 +326,29 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> Iterator for RowIter<'a
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.nrows() - self.curr,
-            Some(self.mat.nrows() - self.curr),
-        )
+        let hint = self.range.len();
+        (hint, Some(hint))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.nrows() - self.curr
+        self.range.len()
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> DoubleEndedIterator
+    for RowIter<'a, T, R, C, S>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        debug_assert!(self.range.start <= self.range.end);
+        if !self.range.is_empty() {
+            self.range.end -= 1;
+            debug_assert!(self.range.end < self.mat.nrows());
+            debug_assert!(self.range.end >= self.range.start);
+            Some(self.mat.row(self.range.end))
+        } else {
+            None
+        }
     }
 }
 
@@ -321,7 +357,7 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterat
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.nrows() - self.curr
+        self.range.end - self.range.start
     }
 }
 
@@ -329,19 +365,40 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterat
 #[derive(Debug)]
 pub struct RowIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
     mat: *mut Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
     phantom: PhantomData<&'a mut Matrix<T, R, C, S>>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> RowIterMut<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<T, R, C, S>) -> Self {
+        let range = 0..mat.nrows();
         RowIterMut {
             mat,
-            curr: 0,
+            range,
             phantom: PhantomData,
         }
     }
 
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        // SAFETY: this makes sure the generated ranges are valid.
+        let split_pos = (self.range.start + index).min(self.range.end);
+
+        let left_iter = RowIterMut {
+            mat: self.mat,
+            range: self.range.start..split_pos,
+            phantom: PhantomData,
+        };
+
+        let right_iter = RowIterMut {
+            mat: self.mat,
+            range: split_pos..self.range.end,
+            phantom: PhantomData,
+        };
+
+        (left_iter, right_iter)
+    }
+
     fn nrows(&self) -> usize {
         unsafe { (*self.mat).nrows() }
     }
@@ -354,9 +411,10 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.nrows() {
-            let res = unsafe { (*self.mat).row_mut(self.curr) };
-            self.curr += 1;
+        debug_assert!(self.range.start <= self.range.end);
+        if self.range.start < self.range.end {
+            let res = unsafe { (*self.mat).row_mut(self.range.start) };
+            self.range.start += 1;
             Some(res)
         } else {
             None
@@ -365,12 +423,13 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.nrows() - self.curr, Some(self.nrows() - self.curr))
+        let hint = self.range.len();
+        (hint, Some(hint))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.nrows() - self.curr
+        self.range.len()
     }
 }
 
@@ -379,7 +438,23 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIte
 {
     #[inline]
     fn len(&self) -> usize {
-        self.nrows() - self.curr
+        self.range.len()
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> DoubleEndedIterator
+    for RowIterMut<'a, T, R, C, S>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        debug_assert!(self.range.start <= self.range.end);
+        if !self.range.is_empty() {
+            self.range.end -= 1;
+            debug_assert!(self.range.end < self.nrows());
+            debug_assert!(self.range.end >= self.range.start);
+            Some(unsafe { (*self.mat).row_mut(self.range.end) })
+        } else {
+            None
+        }
     }
 }
 
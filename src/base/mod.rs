@@ -44,6 +44,8 @@ pub mod uninit;
 
 #[cfg(feature = "rayon")]
 pub mod par_iter;
+#[cfg(feature = "rayon")]
+mod par_ops;
 
 #[cfg(feature = "rkyv-serialize-no-std")]
 mod rkyv_wrappers;
@@ -51,6 +53,7 @@ mod rkyv_wrappers;
 pub use self::matrix::*;
 pub use self::norm::*;
 pub use self::scalar::*;
+pub use self::statistics::*;
 pub use self::unit::*;
 
 pub use self::default_allocator::*;
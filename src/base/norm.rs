@@ -9,6 +9,9 @@ use crate::base::{DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVect
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
 use crate::{ComplexField, Scalar, SimdComplexField, Unit};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
 use simba::scalar::ClosedNeg;
 use simba::simd::{SimdOption, SimdPartialOrd, SimdValue};
 
@@ -48,6 +51,24 @@ pub struct LpNorm(pub i32);
 /// L-infinite norm aka. Chebytchev norm aka. uniform norm aka. suppremum norm.
 #[derive(Copy, Clone, Debug)]
 pub struct UniformNorm;
+/// Operator norm induced by the vector 1-norm, aka. maximum absolute column sum.
+#[derive(Copy, Clone, Debug)]
+pub struct InducedOneNorm;
+/// Operator norm induced by the vector infinity-norm, aka. maximum absolute row sum.
+#[derive(Copy, Clone, Debug)]
+pub struct InducedInfNorm;
+/// Operator norm induced by the vector 2-norm, aka. spectral norm.
+///
+/// This is the largest singular value of the matrix, computed with an SVD.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Copy, Clone, Debug)]
+pub struct SpectralNorm;
+/// Nuclear norm aka. trace norm.
+///
+/// This is the sum of the singular values of the matrix, computed with an SVD.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Copy, Clone, Debug)]
+pub struct NuclearNorm;
 
 impl<T: SimdComplexField> Norm<T> for EuclideanNorm {
     #[inline]
@@ -157,6 +178,182 @@ impl<T: SimdComplexField> Norm<T> for UniformNorm {
     }
 }
 
+impl<T: SimdComplexField> Norm<T> for InducedOneNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::SimdRealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        m.column_iter().fold(T::SimdRealField::zero(), |acc, col| {
+            let sum = col.fold(T::SimdRealField::zero(), |a, b| a + b.simd_modulus());
+            acc.simd_max(sum)
+        })
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::SimdRealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        m1.column_iter().zip(m2.column_iter()).fold(
+            T::SimdRealField::zero(),
+            |acc, (col1, col2)| {
+                let sum = col1.zip_fold(&col2, T::SimdRealField::zero(), |a, b, c| {
+                    a + (b - c).simd_modulus()
+                });
+                acc.simd_max(sum)
+            },
+        )
+    }
+}
+
+impl<T: SimdComplexField> Norm<T> for InducedInfNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::SimdRealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        m.row_iter().fold(T::SimdRealField::zero(), |acc, row| {
+            let sum = row.fold(T::SimdRealField::zero(), |a, b| a + b.simd_modulus());
+            acc.simd_max(sum)
+        })
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::SimdRealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        m1.row_iter()
+            .zip(m2.row_iter())
+            .fold(T::SimdRealField::zero(), |acc, (row1, row2)| {
+                let sum = row1.zip_fold(&row2, T::SimdRealField::zero(), |a, b, c| {
+                    a + (b - c).simd_modulus()
+                });
+                acc.simd_max(sum)
+            })
+    }
+}
+
+/// Copies `m1 - m2` into a dynamically-sized matrix so it can be decomposed regardless of the
+/// dimensions of the inputs.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn dynamic_difference<T, R1, C1, S1, R2, C2, S2>(
+    m1: &Matrix<T, R1, C1, S1>,
+    m2: &Matrix<T, R2, C2, S2>,
+) -> DMatrix<T>
+where
+    T: ComplexField,
+    R1: Dim,
+    C1: Dim,
+    S1: Storage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+{
+    assert_eq!(m1.shape(), m2.shape(), "Matrix dimension mismatch.");
+    DMatrix::from_fn(m1.nrows(), m1.ncols(), |i, j| {
+        m1[(i, j)].clone() - m2[(i, j)].clone()
+    })
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> Norm<T> for SpectralNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::RealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        if m.is_empty() {
+            return T::RealField::zero();
+        }
+
+        let m = DMatrix::from_fn(m.nrows(), m.ncols(), |i, j| m[(i, j)].clone());
+        m.singular_values_unordered().max()
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::RealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.norm(&dynamic_difference(m1, m2))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> Norm<T> for NuclearNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::RealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        if m.is_empty() {
+            return T::RealField::zero();
+        }
+
+        let m = DMatrix::from_fn(m.nrows(), m.ncols(), |i, j| m[(i, j)].clone());
+        m.singular_values_unordered().sum()
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::RealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.norm(&dynamic_difference(m1, m2))
+    }
+}
+
 /// # Magnitude and norms
 impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// The squared L2 norm of this vector.
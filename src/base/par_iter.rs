@@ -5,11 +5,13 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 use crate::{
-    iter::{ColumnIter, ColumnIterMut},
-    Dim, Matrix, MatrixView, MatrixViewMut, RawStorage, RawStorageMut, Scalar, U1,
+    iter::{ColumnIter, ColumnIterMut, RowIter, RowIterMut},
+    Dim, Dyn, Matrix, MatrixView, MatrixViewMut, RawStorage, RawStorageMut, Scalar, U1,
 };
 use rayon::iter::plumbing::Producer;
 use rayon::{iter::plumbing::bridge, prelude::*};
+use std::marker::PhantomData;
+use std::ops::Range;
 
 /// A rayon parallel iterator over the columns of a matrix. It is created
 /// using the [`par_column_iter`] method of [`Matrix`].
@@ -152,6 +154,264 @@ where
     }
 }
 
+/// A rayon parallel iterator over the rows of a matrix. It is created
+/// using the [`par_row_iter`] method of [`Matrix`].
+///
+/// *Only available if compiled with the feature `rayon`.*
+/// [`par_row_iter`]: crate::Matrix::par_row_iter
+/// [`Matrix`]: crate::Matrix
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub struct ParRowIter<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> {
+    mat: &'a Matrix<T, R, Cols, S>,
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+impl<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> ParallelIterator
+    for ParRowIter<'a, T, R, Cols, S>
+where
+    T: Sync + Send + Scalar,
+    S: Sync,
+{
+    type Item = MatrixView<'a, T, U1, Cols, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.nrows())
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`.*
+impl<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> IndexedParallelIterator
+    for ParRowIter<'a, T, R, Cols, S>
+where
+    T: Send + Sync + Scalar,
+    S: Sync,
+{
+    fn len(&self) -> usize {
+        self.mat.nrows()
+    }
+
+    fn drive<C: rayon::iter::plumbing::Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        let producer = RowProducer(RowIter::new(self.mat));
+        callback.callback(producer)
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// A rayon parallel iterator through the mutable rows of a matrix.
+///
+/// The rows of a column-major matrix are strided, so each row is a view with a non-unit
+/// row stride.
+/// *Only available if compiled with the feature `rayon`.*
+pub struct ParRowIterMut<
+    'a,
+    T,
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+> {
+    mat: &'a mut Matrix<T, R, Cols, S>,
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`*
+impl<'a, T, R, Cols, S> ParallelIterator for ParRowIterMut<'a, T, R, Cols, S>
+where
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    type Item = MatrixViewMut<'a, T, U1, Cols, S::RStride, S::CStride>;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.nrows())
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`*
+impl<'a, T, R, Cols, S> IndexedParallelIterator for ParRowIterMut<'a, T, R, Cols, S>
+where
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    fn drive<C: rayon::iter::plumbing::Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.mat.nrows()
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        let producer = RowProducerMut(RowIterMut::new(self.mat));
+        callback.callback(producer)
+    }
+}
+
+/// A rayon parallel iterator over disjoint blocks of consecutive columns of a matrix. It is
+/// created using the [`par_columns_chunks`] method of [`Matrix`].
+///
+/// *Only available if compiled with the feature `rayon`.*
+/// [`par_columns_chunks`]: crate::Matrix::par_columns_chunks
+/// [`Matrix`]: crate::Matrix
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub struct ParColumnsChunks<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> {
+    mat: &'a Matrix<T, R, Cols, S>,
+    chunk_size: usize,
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+impl<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> ParallelIterator
+    for ParColumnsChunks<'a, T, R, Cols, S>
+where
+    T: Sync + Send + Scalar,
+    S: Sync,
+{
+    type Item = MatrixView<'a, T, R, Dyn, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`.*
+impl<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> IndexedParallelIterator
+    for ParColumnsChunks<'a, T, R, Cols, S>
+where
+    T: Send + Sync + Scalar,
+    S: Sync,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols().div_ceil(self.chunk_size)
+    }
+
+    fn drive<C: rayon::iter::plumbing::Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        let nchunks = IndexedParallelIterator::len(&self);
+        callback.callback(ColumnsChunksProducer {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: 0..nchunks,
+        })
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// A rayon parallel iterator over disjoint mutable blocks of consecutive columns of a matrix.
+/// It is created using the [`par_columns_chunks_mut`] method of [`Matrix`].
+///
+/// *Only available if compiled with the feature `rayon`.*
+/// [`par_columns_chunks_mut`]: crate::Matrix::par_columns_chunks_mut
+/// [`Matrix`]: crate::Matrix
+pub struct ParColumnsChunksMut<
+    'a,
+    T,
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+> {
+    mat: &'a mut Matrix<T, R, Cols, S>,
+    chunk_size: usize,
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`*
+impl<'a, T, R, Cols, S> ParallelIterator for ParColumnsChunksMut<'a, T, R, Cols, S>
+where
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    type Item = MatrixViewMut<'a, T, R, Dyn, S::RStride, S::CStride>;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+/// *Only available if compiled with the feature `rayon`*
+impl<'a, T, R, Cols, S> IndexedParallelIterator for ParColumnsChunksMut<'a, T, R, Cols, S>
+where
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols> + RawStorageMut<T, R, Cols>,
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    fn drive<C: rayon::iter::plumbing::Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.mat.ncols().div_ceil(self.chunk_size)
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        let nchunks = IndexedParallelIterator::len(&self);
+        callback.callback(ColumnsChunksProducerMut {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: 0..nchunks,
+            phantom: PhantomData,
+        })
+    }
+}
+
 #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
 /// # Parallel iterators using `rayon`
 /// *Only available if compiled with the feature `rayon`*
@@ -218,6 +478,111 @@ where
     {
         ParColumnIterMut::new(self)
     }
+
+    /// Iterate through the rows of the matrix in parallel using rayon.
+    ///
+    /// Since matrices are stored in column-major order, each row is a strided view.
+    ///
+    /// # Example
+    /// Using parallel row iterators to compute the maximum of each row:
+    /// ```
+    /// use nalgebra::{dmatrix, DMatrix};
+    /// use rayon::prelude::*;
+    ///
+    /// let matrix : DMatrix<f64> = dmatrix![1.0, 0.0, 5.0;
+    ///     2.0, 4.0, 1.0;
+    ///     3.0, 2.0, 2.0;
+    /// ];
+    /// let row_max: Vec<f64> = matrix.par_row_iter().map(|row| row.max()).collect();
+    ///
+    /// assert_eq!(row_max, vec![5.0, 4.0, 3.0]);
+    /// ```
+    pub fn par_row_iter(&self) -> ParRowIter<'_, T, R, Cols, S> {
+        ParRowIter { mat: self }
+    }
+
+    /// Mutably iterate through the rows of this matrix in parallel using rayon.
+    ///
+    /// Since matrices are stored in column-major order, each row is a strided view.
+    ///
+    /// # Example
+    /// Normalize each row of a matrix with respect to its own norm.
+    ///
+    /// ```
+    /// use nalgebra::{dmatrix, DMatrix};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut matrix : DMatrix<f64> = dmatrix![
+    ///     3.0, 4.0;
+    ///     0.0, 2.0;
+    /// ];
+    /// matrix.par_row_iter_mut().for_each(|mut row| row /= row.norm());
+    ///
+    /// assert_eq!(matrix, dmatrix![0.6, 0.8; 0.0, 1.0]);
+    /// ```
+    pub fn par_row_iter_mut(&mut self) -> ParRowIterMut<'_, T, R, Cols, S>
+    where
+        S: RawStorageMut<T, R, Cols>,
+    {
+        ParRowIterMut { mat: self }
+    }
+
+    /// Iterate in parallel using rayon through disjoint blocks of `chunk_size` consecutive
+    /// columns of this matrix.
+    ///
+    /// The last block has fewer than `chunk_size` columns if `chunk_size` does not divide the
+    /// number of columns. Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let matrix = DMatrix::from_fn(3, 5, |i, j| (i + j) as f64);
+    /// let widths: Vec<usize> = matrix.par_columns_chunks(2).map(|block| block.ncols()).collect();
+    ///
+    /// assert_eq!(widths, vec![2, 2, 1]);
+    /// ```
+    pub fn par_columns_chunks(&self, chunk_size: usize) -> ParColumnsChunks<'_, T, R, Cols, S> {
+        assert!(chunk_size > 0, "The chunk size must be non-zero.");
+        ParColumnsChunks {
+            mat: self,
+            chunk_size,
+        }
+    }
+
+    /// Mutably iterate in parallel using rayon through disjoint blocks of `chunk_size`
+    /// consecutive columns of this matrix.
+    ///
+    /// The last block has fewer than `chunk_size` columns if `chunk_size` does not divide the
+    /// number of columns. Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut matrix = DMatrix::<f64>::zeros(2, 5);
+    /// matrix
+    ///     .par_columns_chunks_mut(2)
+    ///     .enumerate()
+    ///     .for_each(|(k, mut block)| block.fill(k as f64));
+    ///
+    /// assert_eq!(matrix.row(0), nalgebra::RowDVector::from_vec(vec![0.0, 0.0, 1.0, 1.0, 2.0]));
+    /// ```
+    pub fn par_columns_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> ParColumnsChunksMut<'_, T, R, Cols, S>
+    where
+        S: RawStorageMut<T, R, Cols>,
+    {
+        assert!(chunk_size > 0, "The chunk size must be non-zero.");
+        ParColumnsChunksMut {
+            mat: self,
+            chunk_size,
+        }
+    }
 }
 
 /// A private helper newtype that wraps the `ColumnIter` and implements
@@ -283,3 +648,235 @@ unsafe impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Send
     for ColumnIterMut<'a, T, R, C, S>
 {
 }
+
+/// See `ColumnProducer`. A private wrapper newtype that keeps the Producer
+/// implementation private
+struct RowProducer<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>>(RowIter<'a, T, R, C, S>);
+
+impl<'a, T, R: Dim, Cols: Dim, S: RawStorage<T, R, Cols>> Producer
+    for RowProducer<'a, T, R, Cols, S>
+where
+    T: Send + Sync + Scalar,
+    S: Sync,
+{
+    type Item = MatrixView<'a, T, U1, Cols, S::RStride, S::CStride>;
+    type IntoIter = RowIter<'a, T, R, Cols, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left_iter, right_iter) = self.0.split_at(index);
+        (Self(left_iter), Self(right_iter))
+    }
+}
+
+/// See `ColumnProducer`. A private wrapper newtype that keeps the Producer
+/// implementation private
+struct RowProducerMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>>(RowIterMut<'a, T, R, C, S>);
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Producer
+    for RowProducerMut<'a, T, R, C, S>
+where
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    type Item = MatrixViewMut<'a, T, U1, C, S::RStride, S::CStride>;
+    type IntoIter = RowIterMut<'a, T, R, C, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left_iter, right_iter) = self.0.split_at(index);
+        (Self(left_iter), Self(right_iter))
+    }
+}
+
+/// this implementation is safe because we are enforcing exclusive access
+/// to the rows through the active range of the iterator, and because
+/// the elements and the storage can be sent to another thread
+unsafe impl<'a, T: Scalar + Send, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C> + Send> Send
+    for RowIterMut<'a, T, R, C, S>
+{
+}
+
+/// A private sequential iterator and rayon producer over the blocks of `chunk_size`
+/// consecutive columns of a matrix whose indices are in `range`.
+struct ColumnsChunksProducer<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
+    mat: &'a Matrix<T, R, C, S>,
+    chunk_size: usize,
+    range: Range<usize>,
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> ColumnsChunksProducer<'a, T, R, C, S> {
+    fn chunk(&self, k: usize) -> MatrixView<'a, T, R, Dyn, S::RStride, S::CStride> {
+        let start = k * self.chunk_size;
+        let end = (start + self.chunk_size).min(self.mat.ncols());
+        self.mat.columns_range(start..end)
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Iterator
+    for ColumnsChunksProducer<'a, T, R, C, S>
+{
+    type Item = MatrixView<'a, T, R, Dyn, S::RStride, S::CStride>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let k = self.range.next()?;
+        Some(self.chunk(k))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> DoubleEndedIterator
+    for ColumnsChunksProducer<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let k = self.range.next_back()?;
+        Some(self.chunk(k))
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> ExactSizeIterator
+    for ColumnsChunksProducer<'a, T, R, C, S>
+{
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Producer
+    for ColumnsChunksProducer<'a, T, R, C, S>
+where
+    T: Send + Sync + Scalar,
+    S: Sync,
+{
+    type Item = MatrixView<'a, T, R, Dyn, S::RStride, S::CStride>;
+    type IntoIter = Self;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let split_pos = (self.range.start + index).min(self.range.end);
+        let left = ColumnsChunksProducer {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: self.range.start..split_pos,
+        };
+        let right = ColumnsChunksProducer {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: split_pos..self.range.end,
+        };
+
+        (left, right)
+    }
+}
+
+/// See `ColumnsChunksProducer`. The mutable blocks are disjoint because each chunk index is
+/// yielded only once.
+struct ColumnsChunksProducerMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
+    mat: *mut Matrix<T, R, C, S>,
+    chunk_size: usize,
+    range: Range<usize>,
+    phantom: PhantomData<&'a mut Matrix<T, R, C, S>>,
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>>
+    ColumnsChunksProducerMut<'a, T, R, C, S>
+{
+    fn chunk(&mut self, k: usize) -> MatrixViewMut<'a, T, R, Dyn, S::RStride, S::CStride> {
+        // SAFETY: the chunks with distinct indices are disjoint, and each index is only yielded
+        // once.
+        unsafe {
+            let mat = &mut *self.mat;
+            let start = k * self.chunk_size;
+            let end = (start + self.chunk_size).min(mat.ncols());
+            mat.columns_range_mut(start..end)
+        }
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
+    for ColumnsChunksProducerMut<'a, T, R, C, S>
+{
+    type Item = MatrixViewMut<'a, T, R, Dyn, S::RStride, S::CStride>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let k = self.range.next()?;
+        Some(self.chunk(k))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> DoubleEndedIterator
+    for ColumnsChunksProducerMut<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let k = self.range.next_back()?;
+        Some(self.chunk(k))
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIterator
+    for ColumnsChunksProducerMut<'a, T, R, C, S>
+{
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Producer
+    for ColumnsChunksProducerMut<'a, T, R, C, S>
+where
+    T: Send + Sync + Scalar,
+    S: Send + Sync,
+{
+    type Item = MatrixViewMut<'a, T, R, Dyn, S::RStride, S::CStride>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let split_pos = (self.range.start + index).min(self.range.end);
+        let left = ColumnsChunksProducerMut {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: self.range.start..split_pos,
+            phantom: PhantomData,
+        };
+        let right = ColumnsChunksProducerMut {
+            mat: self.mat,
+            chunk_size: self.chunk_size,
+            range: split_pos..self.range.end,
+            phantom: PhantomData,
+        };
+
+        (left, right)
+    }
+}
+
+/// this implementation is safe because we are enforcing exclusive access
+/// to the blocks of columns through the active range of the producer,
+/// and because the elements and the storage can be sent to another thread
+unsafe impl<'a, T: Scalar + Send, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C> + Send> Send
+    for ColumnsChunksProducerMut<'a, T, R, C, S>
+{
+}
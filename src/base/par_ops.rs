@@ -0,0 +1,311 @@
+//! Parallel matrix products and reductions using rayon.
+
+use num::{One, Zero};
+use rayon::prelude::*;
+use simba::scalar::{ClosedAdd, ClosedMul, ComplexField};
+
+use crate::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, Dyn, U1};
+use crate::base::storage::{RawStorage, RawStorageMut, Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, MatrixView, MatrixViewMut, OMatrix, Scalar, Vector};
+
+/// The minimum number of scalar multiplications performed by each task of the parallel matrix
+/// products. Smaller products are not worth the scheduling overhead.
+const PAR_MIN_WORK: usize = 1 << 16;
+
+/// Computes `c = alpha * a * b + beta * c`, recursively splitting the columns (or the rows, if
+/// there is a single column) of `c` between rayon tasks.
+fn par_gemm_rec<T, RS1, CS1, RS2, CS2, RS3, CS3>(
+    mut c: MatrixViewMut<'_, T, Dyn, Dyn, RS1, CS1>,
+    alpha: T,
+    a: MatrixView<'_, T, Dyn, Dyn, RS2, CS2>,
+    b: MatrixView<'_, T, Dyn, Dyn, RS3, CS3>,
+    beta: T,
+) where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul + Send + Sync,
+    RS1: Dim,
+    CS1: Dim,
+    RS2: Dim,
+    CS2: Dim,
+    RS3: Dim,
+    CS3: Dim,
+{
+    let (nrows, ncols) = c.shape();
+
+    if nrows * ncols * a.ncols() < 2 * PAR_MIN_WORK || nrows * ncols < 2 {
+        c.gemm(alpha, &a, &b, beta);
+    } else if ncols >= 2 {
+        let mid = ncols / 2;
+        let (left, right) = c.columns_range_pair_mut(..mid, mid..);
+        let (b_left, b_right) = (b.columns_range(..mid), b.columns_range(mid..));
+        let (a2, alpha2, beta2) = (a.clone(), alpha.clone(), beta.clone());
+
+        let _ = rayon::join(
+            || par_gemm_rec(left, alpha, a, b_left, beta),
+            || par_gemm_rec(right, alpha2, a2, b_right, beta2),
+        );
+    } else {
+        let mid = nrows / 2;
+        let (top, bottom) = c.rows_range_pair_mut(..mid, mid..);
+        let (a_top, a_bottom) = (a.rows_range(..mid), a.rows_range(mid..));
+        let (b2, alpha2, beta2) = (b.clone(), alpha.clone(), beta.clone());
+
+        let _ = rayon::join(
+            || par_gemm_rec(top, alpha, a_top, b, beta),
+            || par_gemm_rec(bottom, alpha2, a_bottom, b2, beta2),
+        );
+    }
+}
+
+/// Computes `y = alpha * a * x + beta * y`, recursively splitting the rows of `y` between rayon
+/// tasks.
+fn par_gemv_rec<T, RS1, CS1, RS2, CS2, S3>(
+    mut y: MatrixViewMut<'_, T, Dyn, U1, RS1, CS1>,
+    alpha: T,
+    a: MatrixView<'_, T, Dyn, Dyn, RS2, CS2>,
+    x: &Vector<T, Dyn, S3>,
+    beta: T,
+) where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul + Send + Sync,
+    RS1: Dim,
+    CS1: Dim,
+    RS2: Dim,
+    CS2: Dim,
+    S3: Storage<T, Dyn> + Sync,
+{
+    let nrows = y.nrows();
+
+    if nrows * a.ncols() < 2 * PAR_MIN_WORK || nrows < 2 {
+        y.gemv(alpha, &a, x, beta);
+    } else {
+        let mid = nrows / 2;
+        let (top, bottom) = y.rows_range_pair_mut(..mid, mid..);
+        let (a_top, a_bottom) = (a.rows_range(..mid), a.rows_range(mid..));
+        let (alpha2, beta2) = (alpha.clone(), beta.clone());
+
+        let _ = rayon::join(
+            || par_gemv_rec(top, alpha, a_top, x, beta),
+            || par_gemv_rec(bottom, alpha2, a_bottom, x, beta2),
+        );
+    }
+}
+
+/// # Parallel matrix products using `rayon`
+/// *Only available if compiled with the feature `rayon`*
+impl<T, S> Matrix<T, Dyn, Dyn, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul + Send + Sync,
+    S: StorageMut<T, Dyn, Dyn>,
+{
+    /// Computes `self = alpha * a * b + beta * self` in parallel using rayon, where `a, b, self`
+    /// are dynamically-sized matrices and `alpha` and `beta` are scalar.
+    ///
+    /// The columns of `self` are split between the threads of the rayon thread pool, and each
+    /// thread performs a sequential [`gemm`](Self::gemm). If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_fn(300, 200, |i, j| (i + j) as f64);
+    /// let b = DMatrix::from_fn(200, 100, |i, j| (i * j) as f64);
+    /// let mut c = DMatrix::zeros(300, 100);
+    ///
+    /// c.par_gemm(2.0, &a, &b, 0.0);
+    /// assert_relative_eq!(c, (&a * &b) * 2.0);
+    /// ```
+    pub fn par_gemm<SB, SC>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, Dyn, Dyn, SB>,
+        b: &Matrix<T, Dyn, Dyn, SC>,
+        beta: T,
+    ) where
+        SB: Storage<T, Dyn, Dyn> + Sync,
+        SC: Storage<T, Dyn, Dyn> + Sync,
+    {
+        assert_eq!(
+            self.shape(),
+            (a.nrows(), b.ncols()),
+            "Gemm: dimensions mismatch for addition."
+        );
+        assert_eq!(
+            a.ncols(),
+            b.nrows(),
+            "Gemm: dimensions mismatch for multiplication."
+        );
+
+        par_gemm_rec(
+            self.columns_range_mut(..),
+            alpha,
+            a.columns_range(..),
+            b.columns_range(..),
+            beta,
+        );
+    }
+}
+
+/// # Parallel matrix-vector products using `rayon`
+/// *Only available if compiled with the feature `rayon`*
+impl<T, S> Vector<T, Dyn, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul + Send + Sync,
+    S: StorageMut<T, Dyn>,
+{
+    /// Computes `self = alpha * a * x + beta * self` in parallel using rayon, where `a` is a
+    /// dynamically-sized matrix, `x` a vector, and `alpha, beta` two scalars.
+    ///
+    /// The rows of `self` are split between the threads of the rayon thread pool. If `beta` is
+    /// zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let a = DMatrix::from_fn(1000, 300, |i, j| (i + j) as f64);
+    /// let x = DVector::from_fn(300, |i, _| i as f64);
+    /// let mut y = DVector::from_element(1000, 1.0);
+    ///
+    /// y.par_gemv(2.0, &a, &x, 3.0);
+    /// assert_relative_eq!(y, &a * &x * 2.0 + DVector::from_element(1000, 3.0));
+    /// ```
+    pub fn par_gemv<SB, SC>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, Dyn, Dyn, SB>,
+        x: &Vector<T, Dyn, SC>,
+        beta: T,
+    ) where
+        SB: Storage<T, Dyn, Dyn> + Sync,
+        SC: Storage<T, Dyn> + Sync,
+    {
+        assert!(
+            self.nrows() == a.nrows() && a.ncols() == x.nrows(),
+            "Gemv: dimensions mismatch."
+        );
+
+        par_gemv_rec(self.rows_range_mut(..), alpha, a.columns_range(..), x, beta);
+    }
+}
+
+/// # Parallel maps and reductions using `rayon`
+/// *Only available if compiled with the feature `rayon`*
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S>
+where
+    T: Scalar + Send + Sync,
+    S: Sync,
+{
+    /// Returns a matrix containing the result of `f` applied to each of its entries, evaluated
+    /// in parallel using rayon.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * j) as f64);
+    /// assert_eq!(m.par_map(|e| e.sqrt()), m.map(|e| e.sqrt()));
+    /// ```
+    #[must_use]
+    pub fn par_map<T2: Scalar + Send, F: Fn(T) -> T2 + Sync>(&self, f: F) -> OMatrix<T2, R, C>
+    where
+        DefaultAllocator: Allocator<T2, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let data: Vec<T2> = (0..self.len())
+            .into_par_iter()
+            .map(|i| f(self[i].clone()))
+            .collect();
+
+        Matrix::from_iterator_generic(nrows, ncols, data)
+    }
+
+    /// Replaces each component of `self` by the result of a closure `f` applied on it and on the
+    /// corresponding component of `rhs`, in parallel using rayon.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut a = DMatrix::from_fn(100, 50, |i, j| (i + j) as f64);
+    /// let b = DMatrix::from_fn(100, 50, |i, j| (i * j) as f64);
+    /// let expected = a.zip_map(&b, |x, y| x * y);
+    ///
+    /// a.par_zip_apply(&b, |x, y| *x *= y);
+    /// assert_eq!(a, expected);
+    /// ```
+    pub fn par_zip_apply<T2, R2, C2, S2>(
+        &mut self,
+        rhs: &Matrix<T2, R2, C2, S2>,
+        f: impl Fn(&mut T, T2) + Sync,
+    ) where
+        S: RawStorageMut<T, R, C> + Send,
+        T2: Scalar + Send + Sync,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T2, R2, C2> + Sync,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+    {
+        assert_eq!(
+            self.shape(),
+            rhs.shape(),
+            "Matrix simultaneous traversal error: dimension mismatch."
+        );
+
+        self.par_column_iter_mut()
+            .zip(rhs.par_column_iter())
+            .for_each(|(mut a, b)| {
+                for (x, y) in a.iter_mut().zip(b.iter()) {
+                    f(x, y.clone())
+                }
+            });
+    }
+
+    /// The sum of all the elements of this matrix, computed in parallel using rayon.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DVector;
+    /// let v = DVector::from_fn(10000, |i, _| i as u64);
+    /// assert_eq!(v.par_sum(), 49995000);
+    /// ```
+    #[must_use]
+    pub fn par_sum(&self) -> T
+    where
+        T: ClosedAdd + Zero,
+    {
+        (0..self.len())
+            .into_par_iter()
+            .map(|i| self[i].clone())
+            .reduce(T::zero, |a, b| a + b)
+    }
+
+    /// The squared L2 norm of this matrix, computed in parallel using rayon.
+    #[must_use]
+    pub fn par_norm_squared(&self) -> T::RealField
+    where
+        T: ComplexField,
+        T::RealField: Send,
+    {
+        (0..self.len())
+            .into_par_iter()
+            .map(|i| self[i].clone().modulus_squared())
+            .reduce(T::RealField::zero, |a, b| a + b)
+    }
+
+    /// The L2 norm of this matrix, computed in parallel using rayon.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(300, 200, |i, j| (i as f64 - j as f64).sin());
+    /// assert_relative_eq!(m.par_norm(), m.norm(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn par_norm(&self) -> T::RealField
+    where
+        T: ComplexField,
+        T::RealField: Send,
+    {
+        self.par_norm_squared().sqrt()
+    }
+}
@@ -1,10 +1,19 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use crate::allocator::Allocator;
-use crate::storage::RawStorage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorView, U1};
+use crate::storage::{RawStorage, Storage};
+use crate::{
+    Const, DefaultAllocator, Dim, Matrix, OMatrix, OVector, RowOVector, Scalar, Vector, VectorView,
+    U1,
+};
 use num::{One, Zero};
-use simba::scalar::{ClosedAdd, ClosedMul, Field, SupersetOf};
+use simba::scalar::{ClosedAdd, ClosedMul, ComplexField, Field, SupersetOf};
 use std::mem::MaybeUninit;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{storage::StorageMut, MatrixView, RealField};
+
 /// # Folding on columns and rows
 impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
     /// Returns a row vector where each element is the result of the application of `f` on the
@@ -520,3 +529,493 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 }
+
+/// # Covariance and correlation
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The covariance matrix of the columns of this matrix, where each row is an observation and
+    /// each column is a variable.
+    ///
+    /// Like `.variance`, this is normalized by the number of observations. For complex matrices,
+    /// the element `(i, j)` is the mean of `conj(xᵢ - μᵢ) * (xⱼ - μⱼ)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// let expected = Matrix2::new(2.0 / 3.0, 1.0 / 3.0,
+    ///                             1.0 / 3.0, 2.0 / 3.0);
+    /// assert_relative_eq!(m.covariance(), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn covariance(&self) -> OMatrix<T, C, C>
+    where
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C>,
+    {
+        let ncols = self.shape_generic().1;
+
+        if self.nrows() == 0 {
+            return OMatrix::zeros_generic(ncols, ncols);
+        }
+
+        let mean = self.row_mean();
+        let mut centered = self.clone_owned();
+        for mut row in centered.row_iter_mut() {
+            row -= &mean;
+        }
+
+        centered.ad_mul(&centered) / crate::convert::<_, T>(self.nrows() as f64)
+    }
+
+    /// The correlation matrix of the columns of this matrix, where each row is an observation and
+    /// each column is a variable.
+    ///
+    /// This is the covariance matrix normalized by the standard deviations of the variables.
+    /// The correlations involving a constant variable are not defined, and set to `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// let expected = Matrix2::new(1.0, 0.5,
+    ///                             0.5, 1.0);
+    /// assert_relative_eq!(m.correlation(), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn correlation(&self) -> OMatrix<T, C, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, U1, C>
+            + Allocator<T, C, C>
+            + Allocator<T::RealField, C>,
+    {
+        let mut cov = self.covariance();
+        let std_dev = cov.map_diagonal(|e| e.real().sqrt());
+
+        for j in 0..cov.ncols() {
+            for i in 0..cov.nrows() {
+                cov[(i, j)] = cov[(i, j)]
+                    .clone()
+                    .unscale(std_dev[i].clone() * std_dev[j].clone());
+            }
+        }
+
+        cov
+    }
+
+    /// The weighted mean of all the rows of this matrix, where `weights` contains one
+    /// non-negative weight per row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// let weights = Vector3::new(1.0, 1.0, 2.0);
+    /// assert_eq!(m.weighted_row_mean(&weights), RowVector2::new(2.25, 3.0));
+    /// ```
+    #[must_use]
+    pub fn weighted_row_mean<SW>(&self, weights: &Vector<T::RealField, R, SW>) -> RowOVector<T, C>
+    where
+        SW: RawStorage<T::RealField, R>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        assert_eq!(
+            weights.len(),
+            self.nrows(),
+            "Weighted mean: one weight per row is required."
+        );
+
+        let ncols = self.shape_generic().1;
+        let total = weights
+            .iter()
+            .cloned()
+            .fold(T::RealField::zero(), |a, b| a + b);
+        let mut res = RowOVector::zeros_generic(Const::<1>, ncols);
+
+        for (row, w) in self.row_iter().zip(weights.iter()) {
+            let w = T::from_real(w.clone() / total.clone());
+            res.zip_apply(&row, |r, x| *r += x * w.clone());
+        }
+
+        res
+    }
+
+    /// The weighted covariance matrix of the columns of this matrix, where each row is an
+    /// observation with the corresponding non-negative weight from `weights`.
+    ///
+    /// This is normalized by the sum of the weights, so that it reduces to `.covariance` if all
+    /// the weights are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// let weights = Vector3::new(2.0, 2.0, 2.0);
+    /// assert_relative_eq!(m.weighted_covariance(&weights), m.covariance(), epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn weighted_covariance<SW>(&self, weights: &Vector<T::RealField, R, SW>) -> OMatrix<T, C, C>
+    where
+        SW: RawStorage<T::RealField, R>,
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C>,
+    {
+        let mean = self.weighted_row_mean(weights);
+        let total = weights
+            .iter()
+            .cloned()
+            .fold(T::RealField::zero(), |a, b| a + b);
+
+        // Scale each centered observation by the square root of its normalized weight.
+        let mut centered = self.clone_owned();
+        for (mut row, w) in centered.row_iter_mut().zip(weights.iter()) {
+            row -= &mean;
+            row *= T::from_real((w.clone() / total.clone()).sqrt());
+        }
+
+        centered.ad_mul(&centered)
+    }
+}
+
+/// The interpolation method used by `Matrix::quantile` when the requested quantile lies between
+/// two elements `a ≤ b` of the sorted data.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum QuantileInterpolation {
+    /// Linear interpolation between `a` and `b`.
+    Linear,
+    /// The lower element `a`.
+    Lower,
+    /// The higher element `b`.
+    Higher,
+    /// The nearest of `a` and `b`, or the one with an even index if both are equally near.
+    Nearest,
+    /// The mean of `a` and `b`.
+    Midpoint,
+}
+
+/// Reorders a sequence in-place with the transpositions `swap`, so that its `k`-th element
+/// becomes its former `perm[k]`-th element.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn apply_permutation(perm: &[usize], mut swap: impl FnMut(usize, usize)) {
+    // `at[k]` is the former index of the element currently at `k`, and `pos` its inverse.
+    let mut at: Vec<usize> = (0..perm.len()).collect();
+    let mut pos = at.clone();
+
+    for (k, &orig) in perm.iter().enumerate() {
+        let cur = pos[orig];
+
+        if cur != k {
+            swap(k, cur);
+            at.swap(k, cur);
+            pos[at[k]] = k;
+            pos[at[cur]] = cur;
+        }
+    }
+}
+
+/// # Order statistics
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// The indices of the elements of this matrix, in column-major order, sorted by increasing
+    /// value.
+    ///
+    /// The sort is stable. Incomparable elements, e.g., `NaN`, are considered equal to any other
+    /// element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector4;
+    ///
+    /// let v = Vector4::new(3.0, 1.0, 4.0, 1.5);
+    /// assert_eq!(v.argsort(), vec![1, 3, 0, 2]);
+    /// ```
+    #[must_use]
+    pub fn argsort(&self) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| {
+            self[i]
+                .partial_cmp(&self[j])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        indices
+    }
+
+    /// The `q`-th quantile of all the elements of this matrix, with `0 ≤ q ≤ 1`.
+    ///
+    /// If the quantile lies between two elements, it is computed with the given `interpolation`
+    /// method. Panics if this matrix is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, QuantileInterpolation};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.quantile(0.25, QuantileInterpolation::Linear), 2.25);
+    /// assert_eq!(m.quantile(0.25, QuantileInterpolation::Lower), 2.0);
+    /// assert_eq!(m.quantile(0.25, QuantileInterpolation::Higher), 3.0);
+    /// assert_eq!(m.quantile(0.25, QuantileInterpolation::Nearest), 2.0);
+    /// assert_eq!(m.quantile(0.25, QuantileInterpolation::Midpoint), 2.5);
+    /// ```
+    #[must_use]
+    pub fn quantile(&self, q: f64, interpolation: QuantileInterpolation) -> T
+    where
+        T: RealField,
+    {
+        assert!(
+            !self.is_empty(),
+            "Cannot compute the quantile of an empty matrix."
+        );
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "Quantile: the probability must be between 0 and 1."
+        );
+
+        let mut sorted: Vec<T> = self.iter().cloned().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let h = q * (sorted.len() - 1) as f64;
+        let lo = h as usize;
+        let hi = (lo + 1).min(sorted.len() - 1);
+        let frac = h - lo as f64;
+        let (a, b) = (sorted[lo].clone(), sorted[hi].clone());
+
+        match interpolation {
+            QuantileInterpolation::Linear => a.clone() + (b - a) * crate::convert(frac),
+            QuantileInterpolation::Lower => a,
+            QuantileInterpolation::Higher if frac == 0.0 => a,
+            QuantileInterpolation::Higher => b,
+            QuantileInterpolation::Nearest
+                if frac < 0.5 || (frac == 0.5 && lo.is_multiple_of(2)) =>
+            {
+                a
+            }
+            QuantileInterpolation::Nearest => b,
+            QuantileInterpolation::Midpoint if frac == 0.0 => a,
+            QuantileInterpolation::Midpoint => (a + b) * crate::convert(0.5),
+        }
+    }
+
+    /// The median of all the elements of this matrix.
+    ///
+    /// If this matrix has an even number of elements, this is the mean of the two middle ones.
+    /// Panics if this matrix is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 50.0, 6.0);
+    /// assert_eq!(m.median(), 3.5);
+    /// ```
+    #[must_use]
+    pub fn median(&self) -> T
+    where
+        T: RealField,
+    {
+        self.quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// The median of all the rows of this matrix.
+    ///
+    /// Use `.row_median_tr` if you need the result in a column vector instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        9.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_median(), RowVector2::new(2.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn row_median(&self) -> RowOVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| col.median())
+    }
+
+    /// The median of all the rows of this matrix. The result is transposed and returned as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, Vector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        9.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_median_tr(), Vector2::new(2.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn row_median_tr(&self) -> OVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.compress_rows_tr(|col| col.median())
+    }
+
+    /// The median of all the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 7.0, 3.0,
+    ///                        4.0, 5.0, 9.0);
+    /// assert_eq!(m.column_median(), Vector2::new(3.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn column_median(&self) -> OVector<T, R>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.column_quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// The `q`-th quantile of all the rows of this matrix, with `0 ≤ q ≤ 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, QuantileInterpolation, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        9.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_quantile(1.0, QuantileInterpolation::Linear), RowVector2::new(9.0, 6.0));
+    /// ```
+    #[must_use]
+    pub fn row_quantile(&self, q: f64, interpolation: QuantileInterpolation) -> RowOVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| col.quantile(q, interpolation))
+    }
+
+    /// The `q`-th quantile of all the columns of this matrix, with `0 ≤ q ≤ 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, QuantileInterpolation, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 7.0, 3.0,
+    ///                        4.0, 5.0, 9.0);
+    /// assert_eq!(m.column_quantile(0.0, QuantileInterpolation::Linear), Vector2::new(1.0, 4.0));
+    /// ```
+    #[must_use]
+    pub fn column_quantile(&self, q: f64, interpolation: QuantileInterpolation) -> OVector<T, R>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let nrows = self.shape_generic().0;
+        OVector::from_fn_generic(nrows, Const::<1>, |i, _| {
+            self.row(i).quantile(q, interpolation)
+        })
+    }
+
+    /// Sorts the columns of this matrix in-place with the comparison function `compare`, and
+    /// returns the former indices of the sorted columns.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let mut m = Matrix2x3::new(3.0, 1.0, 2.0,
+    ///                            0.0, 5.0, 4.0);
+    /// // Sort the columns by their first component.
+    /// let perm = m.sort_columns_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+    /// assert_eq!(perm, vec![1, 2, 0]);
+    /// assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                              5.0, 4.0, 0.0));
+    /// ```
+    pub fn sort_columns_by(
+        &mut self,
+        mut compare: impl FnMut(
+            &VectorView<'_, T, R, S::RStride, S::CStride>,
+            &VectorView<'_, T, R, S::RStride, S::CStride>,
+        ) -> std::cmp::Ordering,
+    ) -> Vec<usize>
+    where
+        S: StorageMut<T, R, C>,
+    {
+        let mut perm: Vec<usize> = (0..self.ncols()).collect();
+        perm.sort_by(|&i, &j| compare(&self.column(i), &self.column(j)));
+        apply_permutation(&perm, |i, j| self.swap_columns(i, j));
+        perm
+    }
+
+    /// Sorts the rows of this matrix in-place with the comparison function `compare`, and
+    /// returns the former indices of the sorted rows.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    ///
+    /// let mut m = Matrix3x2::new(3.0, 0.0,
+    ///                            1.0, 5.0,
+    ///                            2.0, 4.0);
+    /// // Sort the rows by decreasing second component.
+    /// let perm = m.sort_rows_by(|a, b| b[1].partial_cmp(&a[1]).unwrap());
+    /// assert_eq!(perm, vec![1, 2, 0]);
+    /// assert_eq!(m, Matrix3x2::new(1.0, 5.0,
+    ///                              2.0, 4.0,
+    ///                              3.0, 0.0));
+    /// ```
+    pub fn sort_rows_by(
+        &mut self,
+        mut compare: impl FnMut(
+            &MatrixView<'_, T, U1, C, S::RStride, S::CStride>,
+            &MatrixView<'_, T, U1, C, S::RStride, S::CStride>,
+        ) -> std::cmp::Ordering,
+    ) -> Vec<usize>
+    where
+        S: StorageMut<T, R, C>,
+    {
+        let mut perm: Vec<usize> = (0..self.nrows()).collect();
+        perm.sort_by(|&i, &j| compare(&self.row(i), &self.row(j)));
+
+        apply_permutation(&perm, |i, j| self.swap_rows(i, j));
+        perm
+    }
+}
@@ -0,0 +1,715 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Mul;
+
+use num::{One, Zero};
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DVector, DefaultAllocator, Matrix, OMatrix};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+/// A square tridiagonal matrix, stored as its three diagonals.
+///
+/// Linear systems involving a tridiagonal matrix of dimension `n` are solved in `O(n)`
+/// operations with the Thomas algorithm.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TridiagonalMatrix<T: ComplexField> {
+    subdiagonal: DVector<T>,
+    diagonal: DVector<T>,
+    superdiagonal: DVector<T>,
+}
+
+impl<T: ComplexField> TridiagonalMatrix<T> {
+    /// Creates a tridiagonal matrix from its subdiagonal, diagonal, and superdiagonal.
+    ///
+    /// The subdiagonal and the superdiagonal must have one element less than the diagonal.
+    pub fn new(subdiagonal: DVector<T>, diagonal: DVector<T>, superdiagonal: DVector<T>) -> Self {
+        let n = diagonal.len();
+        assert!(
+            subdiagonal.len() + 1 == n.max(1) && superdiagonal.len() + 1 == n.max(1),
+            "Tridiagonal matrix: the off-diagonals must have one element less than the diagonal."
+        );
+
+        Self {
+            subdiagonal,
+            diagonal,
+            superdiagonal,
+        }
+    }
+
+    /// Creates a tridiagonal matrix from the three central diagonals of the square matrix `m`.
+    ///
+    /// The other elements of `m` are ignored.
+    pub fn from_dense<R: Dim, C: Dim, S: Storage<T, R, C>>(m: &Matrix<T, R, C, S>) -> Self {
+        assert!(
+            m.is_square(),
+            "Tridiagonal matrix: the matrix must be square."
+        );
+        let n = m.nrows();
+        let off_len = n.max(1) - 1;
+
+        Self {
+            subdiagonal: DVector::from_fn(off_len, |i, _| m[(i + 1, i)].clone()),
+            diagonal: DVector::from_fn(n, |i, _| m[(i, i)].clone()),
+            superdiagonal: DVector::from_fn(off_len, |i, _| m[(i, i + 1)].clone()),
+        }
+    }
+
+    /// The dense matrix equal to `self`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let n = self.nrows();
+        let mut res = DMatrix::from_diagonal(&self.diagonal);
+
+        for i in 1..n {
+            res[(i, i - 1)] = self.subdiagonal[i - 1].clone();
+            res[(i - 1, i)] = self.superdiagonal[i - 1].clone();
+        }
+
+        res
+    }
+
+    /// The banded matrix equal to `self`, with unit lower and upper bandwidths.
+    #[must_use]
+    pub fn to_banded(&self) -> BandedMatrix<T> {
+        BandedMatrix::from_fn(self.nrows(), 1, 1, |i, j| {
+            if i == j {
+                self.diagonal[i].clone()
+            } else if i > j {
+                self.subdiagonal[j].clone()
+            } else {
+                self.superdiagonal[i].clone()
+            }
+        })
+    }
+
+    /// The number of rows and columns of this matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The elements `(i + 1, i)` of this matrix.
+    #[must_use]
+    pub fn subdiagonal(&self) -> &DVector<T> {
+        &self.subdiagonal
+    }
+
+    /// The elements `(i, i)` of this matrix.
+    #[must_use]
+    pub fn diagonal(&self) -> &DVector<T> {
+        &self.diagonal
+    }
+
+    /// The elements `(i, i + 1)` of this matrix.
+    #[must_use]
+    pub fn superdiagonal(&self) -> &DVector<T> {
+        &self.superdiagonal
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// This uses the Thomas algorithm, i.e., Gaussian elimination without pivoting, which is
+    /// stable if `self` is diagonally dominant or positive-definite. Use
+    /// `self.to_banded().lu()` for general tridiagonal matrices. Returns `None` if a zero pivot
+    /// is encountered.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// Returns `false` if a zero pivot is encountered, in which case `b` is left unchanged. See
+    /// [`TridiagonalMatrix::solve`] for details.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.nrows();
+        assert_eq!(b.nrows(), n, "Tridiagonal solve: dimensions mismatch.");
+
+        if n == 0 {
+            return true;
+        }
+
+        // Forward elimination of the subdiagonal, which does not depend on `b`.
+        let mut pivots: Vec<T> = Vec::with_capacity(n);
+        let mut sup: Vec<T> = Vec::with_capacity(n - 1);
+
+        for i in 0..n {
+            let pivot = if i == 0 {
+                self.diagonal[0].clone()
+            } else {
+                self.diagonal[i].clone() - self.subdiagonal[i - 1].clone() * sup[i - 1].clone()
+            };
+
+            if pivot.is_zero() {
+                return false;
+            }
+
+            if i + 1 < n {
+                sup.push(self.superdiagonal[i].clone() / pivot.clone());
+            }
+            pivots.push(pivot);
+        }
+
+        for mut col in b.column_iter_mut() {
+            col[0] = col[0].clone() / pivots[0].clone();
+            for i in 1..n {
+                col[i] = (col[i].clone() - self.subdiagonal[i - 1].clone() * col[i - 1].clone())
+                    / pivots[i].clone();
+            }
+
+            for i in (0..n - 1).rev() {
+                col[i] = col[i].clone() - sup[i].clone() * col[i + 1].clone();
+            }
+        }
+
+        true
+    }
+}
+
+impl<T: ComplexField> From<TridiagonalMatrix<T>> for DMatrix<T> {
+    fn from(m: TridiagonalMatrix<T>) -> Self {
+        m.to_dense()
+    }
+}
+
+impl<T: ComplexField> From<TridiagonalMatrix<T>> for BandedMatrix<T> {
+    fn from(m: TridiagonalMatrix<T>) -> Self {
+        m.to_banded()
+    }
+}
+
+impl<'a, T, R2, C2, S2> Mul<&'a Matrix<T, R2, C2, S2>> for &'a TridiagonalMatrix<T>
+where
+    T: ComplexField,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, R2, C2>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    fn mul(self, rhs: &'a Matrix<T, R2, C2, S2>) -> Self::Output {
+        let n = self.nrows();
+        assert_eq!(rhs.nrows(), n, "Matrix multiplication dimensions mismatch.");
+
+        let (nrows, ncols) = rhs.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            let mut res = self.diagonal[i].clone() * rhs[(i, j)].clone();
+            if i > 0 {
+                res += self.subdiagonal[i - 1].clone() * rhs[(i - 1, j)].clone();
+            }
+            if i + 1 < n {
+                res += self.superdiagonal[i].clone() * rhs[(i + 1, j)].clone();
+            }
+            res
+        })
+    }
+}
+
+/// A square banded matrix, whose nonzero elements `(i, j)` satisfy `j ≤ i + ku` and
+/// `i ≤ j + kl`, where `kl` and `ku` are its lower and upper bandwidths.
+///
+/// The band is stored column by column, like the LAPACK band storage: the element `(i, j)` is
+/// stored at the row `ku + i - j` of the column `j` of a `(kl + ku + 1) × n` matrix. Its LU and
+/// Cholesky decompositions take `O(n * kl * (kl + ku))` and `O(n * kl²)` operations.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedMatrix<T: ComplexField> {
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    band: DMatrix<T>,
+}
+
+impl<T: ComplexField> BandedMatrix<T> {
+    /// Creates a `n × n` banded matrix filled with zeros, with the given lower and upper
+    /// bandwidths.
+    pub fn zeros(n: usize, lower_bandwidth: usize, upper_bandwidth: usize) -> Self {
+        Self {
+            lower_bandwidth,
+            upper_bandwidth,
+            band: DMatrix::zeros(lower_bandwidth + upper_bandwidth + 1, n),
+        }
+    }
+
+    /// Creates a `n × n` banded matrix with the given lower and upper bandwidths, whose elements
+    /// inside of the band are computed by `f(i, j)`.
+    pub fn from_fn(
+        n: usize,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+        mut f: impl FnMut(usize, usize) -> T,
+    ) -> Self {
+        let mut res = Self::zeros(n, lower_bandwidth, upper_bandwidth);
+
+        for j in 0..n {
+            for i in j.saturating_sub(upper_bandwidth)..n.min(j + lower_bandwidth + 1) {
+                res.band[(upper_bandwidth + i - j, j)] = f(i, j);
+            }
+        }
+
+        res
+    }
+
+    /// Creates a banded matrix from the elements of the band of the square matrix `m`.
+    ///
+    /// The elements of `m` outside of the band are ignored.
+    pub fn from_dense<R: Dim, C: Dim, S: Storage<T, R, C>>(
+        m: &Matrix<T, R, C, S>,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+    ) -> Self {
+        assert!(m.is_square(), "Banded matrix: the matrix must be square.");
+        Self::from_fn(m.nrows(), lower_bandwidth, upper_bandwidth, |i, j| {
+            m[(i, j)].clone()
+        })
+    }
+
+    /// The dense matrix equal to `self`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let n = self.nrows();
+        DMatrix::from_fn(n, n, |i, j| self.get(i, j).cloned().unwrap_or_else(T::zero))
+    }
+
+    /// The number of rows and columns of this matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.band.ncols()
+    }
+
+    /// The number of nonzero subdiagonals of this matrix.
+    #[must_use]
+    pub fn lower_bandwidth(&self) -> usize {
+        self.lower_bandwidth
+    }
+
+    /// The number of nonzero superdiagonals of this matrix.
+    #[must_use]
+    pub fn upper_bandwidth(&self) -> usize {
+        self.upper_bandwidth
+    }
+
+    /// The band of this matrix, in the format described in [`BandedMatrix`].
+    #[must_use]
+    pub fn band(&self) -> &DMatrix<T> {
+        &self.band
+    }
+
+    /// A reference to the element `(i, j)`, or `None` if it lies outside of the band or of the
+    /// matrix.
+    #[must_use]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.band_index(i, j).map(|ij| &self.band[ij])
+    }
+
+    /// A mutable reference to the element `(i, j)`, or `None` if it lies outside of the band or
+    /// of the matrix.
+    #[must_use]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.band_index(i, j).map(move |ij| &mut self.band[ij])
+    }
+
+    fn band_index(&self, i: usize, j: usize) -> Option<(usize, usize)> {
+        let n = self.nrows();
+        let in_band = i + self.upper_bandwidth >= j && i <= j + self.lower_bandwidth;
+
+        if i < n && j < n && in_band {
+            Some((self.upper_bandwidth + i - j, j))
+        } else {
+            None
+        }
+    }
+
+    /// Computes the LU decomposition with partial (row) pivoting of this matrix.
+    pub fn lu(self) -> BandedLU<T> {
+        BandedLU::new(self)
+    }
+
+    /// Attempts to compute the Cholesky decomposition of this matrix, which must be
+    /// Hermitian-definite-positive.
+    ///
+    /// Only the lower band is read. Returns `None` if the matrix is not definite-positive.
+    pub fn cholesky(self) -> Option<BandedCholesky<T>> {
+        BandedCholesky::new(self)
+    }
+}
+
+impl<T: ComplexField> From<BandedMatrix<T>> for DMatrix<T> {
+    fn from(m: BandedMatrix<T>) -> Self {
+        m.to_dense()
+    }
+}
+
+impl<'a, T, R2, C2, S2> Mul<&'a Matrix<T, R2, C2, S2>> for &'a BandedMatrix<T>
+where
+    T: ComplexField,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, R2, C2>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    fn mul(self, rhs: &'a Matrix<T, R2, C2, S2>) -> Self::Output {
+        let n = self.nrows();
+        assert_eq!(rhs.nrows(), n, "Matrix multiplication dimensions mismatch.");
+
+        let (kl, ku) = (self.lower_bandwidth, self.upper_bandwidth);
+        let (nrows, ncols) = rhs.shape_generic();
+        let mut res = OMatrix::zeros_generic(nrows, ncols);
+
+        for c in 0..rhs.ncols() {
+            for j in 0..n {
+                let x = rhs[(j, c)].clone();
+                for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                    res[(i, c)] += self.band[(ku + i - j, j)].clone() * x.clone();
+                }
+            }
+        }
+
+        res
+    }
+}
+
+/// The LU decomposition with partial (row) pivoting of a banded matrix.
+///
+/// The row interchanges increase the upper bandwidth of `U` to `kl + ku`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct BandedLU<T: ComplexField> {
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    // The element `(i, j)` of `U` or of `L` is at `(kl + ku + i - j, j)`.
+    lu: DMatrix<T>,
+    pivots: Vec<usize>,
+}
+
+impl<T: ComplexField> BandedLU<T> {
+    /// Computes the LU decomposition with partial (row) pivoting of `matrix`.
+    pub fn new(matrix: BandedMatrix<T>) -> Self {
+        let n = matrix.nrows();
+        let (kl, ku) = (matrix.lower_bandwidth, matrix.upper_bandwidth);
+        let ku2 = kl + ku;
+
+        let mut lu = DMatrix::zeros(kl + ku2 + 1, n);
+        lu.rows_mut(kl, ku + kl + 1).copy_from(&matrix.band);
+
+        let mut pivots = Vec::with_capacity(n);
+
+        for j in 0..n {
+            let last_row = n.min(j + kl + 1);
+            let last_col = n.min(j + ku2 + 1);
+
+            let mut piv = j;
+            let mut max = lu[(ku2, j)].clone().norm1();
+            for i in j + 1..last_row {
+                let norm = lu[(ku2 + i - j, j)].clone().norm1();
+                if norm > max {
+                    piv = i;
+                    max = norm;
+                }
+            }
+            pivots.push(piv);
+
+            if max.is_zero() {
+                continue;
+            }
+
+            if piv != j {
+                for k in j..last_col {
+                    lu.swap((ku2 + j - k, k), (ku2 + piv - k, k));
+                }
+            }
+
+            let diag = lu[(ku2, j)].clone();
+            for i in j + 1..last_row {
+                lu[(ku2 + i - j, j)] /= diag.clone();
+            }
+
+            for k in j + 1..last_col {
+                let u_jk = lu[(ku2 + j - k, k)].clone();
+                if u_jk.is_zero() {
+                    continue;
+                }
+
+                for i in j + 1..last_row {
+                    let l_ij = lu[(ku2 + i - j, j)].clone();
+                    lu[(ku2 + i - k, k)] -= l_ij * u_jk.clone();
+                }
+            }
+        }
+
+        Self {
+            lower_bandwidth: kl,
+            upper_bandwidth: ku,
+            lu,
+            pivots,
+        }
+    }
+
+    // The position in `self.lu` of the element `(i, j)` of `L` or `U`.
+    fn index(&self, i: usize, j: usize) -> (usize, usize) {
+        (self.lower_bandwidth + self.upper_bandwidth + i - j, j)
+    }
+
+    /// The number of rows and columns of the decomposed matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.lu.ncols()
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        (0..self.nrows()).all(|i| !self.lu[self.index(i, i)].is_zero())
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for (i, piv) in self.pivots.iter().enumerate() {
+            det *= self.lu[self.index(i, i)].clone();
+            if *piv != i {
+                det = -det;
+            }
+        }
+
+        det
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.nrows();
+        assert_eq!(b.nrows(), n, "Banded LU solve: dimensions mismatch.");
+
+        if !self.is_invertible() {
+            return false;
+        }
+
+        let kl = self.lower_bandwidth;
+        let ku2 = kl + self.upper_bandwidth;
+
+        for mut col in b.column_iter_mut() {
+            // Apply the row interchanges and `L⁻¹`.
+            for j in 0..n {
+                col.swap_rows(j, self.pivots[j]);
+                let x = col[j].clone();
+                for i in j + 1..n.min(j + kl + 1) {
+                    col[i] -= self.lu[self.index(i, j)].clone() * x.clone();
+                }
+            }
+
+            // Apply `U⁻¹`.
+            for j in (0..n).rev() {
+                col[j] /= self.lu[self.index(j, j)].clone();
+                let x = col[j].clone();
+                for i in j.saturating_sub(ku2)..j {
+                    col[i] -= self.lu[self.index(i, j)].clone() * x.clone();
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// The Cholesky decomposition `L * Lᴴ` of a Hermitian-definite-positive banded matrix, where the
+/// lower-triangular factor `L` has the same lower bandwidth.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct BandedCholesky<T: ComplexField> {
+    // The element `(i, j)` of `L` is at `(i - j, j)`.
+    chol: DMatrix<T>,
+}
+
+impl<T: ComplexField> BandedCholesky<T> {
+    /// Attempts to compute the Cholesky decomposition of `matrix`.
+    ///
+    /// Only the lower band of `matrix` is read. Returns `None` if it is not definite-positive.
+    pub fn new(matrix: BandedMatrix<T>) -> Option<Self> {
+        let n = matrix.nrows();
+        let (kl, ku) = (matrix.lower_bandwidth, matrix.upper_bandwidth);
+        let mut chol = matrix.band.rows(ku, kl + 1).into_owned();
+
+        for j in 0..n {
+            let first = j.saturating_sub(kl);
+
+            let mut diag = chol[(0, j)].clone().real();
+            for k in first..j {
+                diag -= chol[(j - k, k)].clone().modulus_squared();
+            }
+
+            if diag <= T::RealField::zero() {
+                return None;
+            }
+
+            let diag = diag.sqrt();
+            chol[(0, j)] = T::from_real(diag.clone());
+
+            for i in j + 1..n.min(j + kl + 1) {
+                let mut l_ij = chol[(i - j, j)].clone();
+                for k in i.saturating_sub(kl)..j {
+                    l_ij -= chol[(i - k, k)].clone() * chol[(j - k, k)].clone().conjugate();
+                }
+                chol[(i - j, j)] = l_ij.unscale(diag.clone());
+            }
+        }
+
+        Some(Self { chol })
+    }
+
+    /// The number of rows and columns of the decomposed matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.chol.ncols()
+    }
+
+    /// The lower-triangular factor `L` of this decomposition, as a banded matrix.
+    #[must_use]
+    pub fn l(&self) -> BandedMatrix<T> {
+        let kl = self.chol.nrows() - 1;
+        BandedMatrix {
+            lower_bandwidth: kl,
+            upper_bandwidth: 0,
+            band: self.chol.clone(),
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T::RealField {
+        let mut prod_diag = T::RealField::one();
+        for j in 0..self.nrows() {
+            prod_diag *= self.chol[(0, j)].clone().real();
+        }
+
+        prod_diag.clone() * prod_diag
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.nrows();
+        assert_eq!(b.nrows(), n, "Banded Cholesky solve: dimensions mismatch.");
+        let kl = self.chol.nrows() - 1;
+
+        for mut col in b.column_iter_mut() {
+            // Solve `L * y = b`.
+            for j in 0..n {
+                col[j] /= self.chol[(0, j)].clone();
+                let y = col[j].clone();
+                for i in j + 1..n.min(j + kl + 1) {
+                    col[i] -= self.chol[(i - j, j)].clone() * y.clone();
+                }
+            }
+
+            // Solve `Lᴴ * x = y`.
+            for i in (0..n).rev() {
+                let mut x = col[i].clone();
+                for k in i + 1..n.min(i + kl + 1) {
+                    x -= self.chol[(k - i, i)].clone().conjugate() * col[k].clone();
+                }
+                col[i] = x / self.chol[(0, i)].clone();
+            }
+        }
+    }
+}
@@ -6,11 +6,13 @@ use simba::scalar::ComplexField;
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, Vector};
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector, Vector};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 
+use crate::linalg::condition::estimate_rcond;
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -262,6 +264,29 @@ where
         Some(Cholesky { chol: matrix })
     }
 
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// See [`estimate_rcond`](crate::linalg::estimate_rcond) for details on the estimator. The
+    /// result is close to zero for ill-conditioned matrices.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let l = self.l();
+        // The decomposed matrix is hermitian, so it is its own adjoint.
+        let mul = |x: &mut OVector<T, D>| {
+            *x = &l * l.ad_mul(&*x);
+            true
+        };
+        let solve = |x: &mut OVector<T, D>| {
+            self.solve_mut(x);
+            true
+        };
+
+        estimate_rcond(self.chol.shape_generic().0, mul, mul, solve, solve)
+    }
+
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
     /// performs a rank one update such that we end up with the decomposition of `M + sigma * (v * v.adjoint())`.
     #[inline]
@@ -6,10 +6,11 @@ use crate::allocator::{Allocator, Reallocator};
 use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector, Unit};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
-use crate::storage::StorageMut;
+use crate::storage::{Storage, StorageMut};
 use crate::ComplexField;
 
 use crate::geometry::Reflection;
+use crate::linalg::least_squares::{solve_householder_qr, LeastSquaresSolution};
 use crate::linalg::{householder, PermutationSequence};
 use std::mem::MaybeUninit;
 
@@ -78,8 +79,20 @@ where
         let mut diag = Matrix::uninit(min_nrows_ncols, Const::<1>);
 
         for i in 0..min_nrows_ncols.value() {
-            let piv = matrix.view_range(i.., i..).icamax_full();
-            let col_piv = piv.1 + i;
+            // Pivot on the column of the remaining submatrix with the largest norm, so that the
+            // magnitudes of the diagonal elements of `R` are non-increasing.
+            let mut col_piv = i;
+            let mut max_norm_squared = T::RealField::zero();
+
+            for j in i..ncols.value() {
+                let norm_squared = matrix.view_range(i.., j).norm_squared();
+
+                if norm_squared > max_norm_squared {
+                    col_piv = j;
+                    max_norm_squared = norm_squared;
+                }
+            }
+
             matrix.swap_columns(i, col_piv);
             p.append_permutation(i, col_piv);
 
@@ -198,6 +211,69 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
+
+    /// Computes the numerical rank of the decomposed matrix, i.e., the number of leading diagonal
+    /// elements of `R` with a magnitude greater than `eps * |R₀₀|`.
+    ///
+    /// The tolerance `eps` is relative to the largest diagonal element of `R`, which makes the
+    /// rank independent of the scaling of the decomposed matrix.
+    #[must_use]
+    pub fn rank(&self, eps: T::RealField) -> usize {
+        assert!(
+            eps >= T::RealField::zero(),
+            "ColPivQR rank: the epsilon must be non-negative."
+        );
+
+        let threshold = match self.diag.iter().next() {
+            Some(r00) => eps * r00.clone().modulus(),
+            None => return 0,
+        };
+
+        self.diag
+            .iter()
+            .take_while(|e| (*e).clone().modulus() > threshold)
+            .count()
+    }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// This works for overdetermined, underdetermined, and rank-deficient systems. The rank of
+    /// the decomposed matrix is determined with [`Self::rank`]: the columns of `R` past the
+    /// rank are ignored, and the corresponding components of `x` are set to zero. The result is
+    /// a basic solution, which is not necessarily the minimum-norm solution if the decomposed
+    /// matrix is rank-deficient or underdetermined.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> LeastSquaresSolution<T, C, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert_eq!(
+            self.col_piv_qr.nrows(),
+            b.nrows(),
+            "ColPivQR least squares: matrix dimension mismatch."
+        );
+
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        let rank = self.rank(eps);
+        // The diagonal elements of R up to the rank are non-zero.
+        let (mut solution, residual_norm) =
+            solve_householder_qr(&self.col_piv_qr, &self.diag, rank, &qtb);
+        self.p.inv_permute_rows(&mut solution);
+
+        LeastSquaresSolution {
+            solution,
+            residual_norm,
+            rank,
+        }
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> ColPivQR<T, D, D>
@@ -0,0 +1,304 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin, DimMinimum, Dyn, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::{ColPivQR, LeastSquaresSolution, PermutationSequence};
+
+/// The complete orthogonal decomposition of a general matrix.
+///
+/// This computes `A * P = Q * [T 0; 0 0] * Zᴴ` where `P` is a permutation matrix, `Q` and `Z`
+/// are unitary matrices, and `T` is an upper-triangular `rank x rank` matrix. It is obtained by
+/// cancelling the trailing columns of the `R` factor of a column-pivoted QR decomposition with
+/// householder reflections applied from the right.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>> +
+                           Allocator<T, DimMinimum<R, C>, C> +
+                           Allocator<T, C, C>,
+         ColPivQR<T, R, C>: Serialize,
+         OMatrix<T, DimMinimum<R, C>, C>: Serialize,
+         OMatrix<T, C, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>> +
+                           Allocator<T, DimMinimum<R, C>, C> +
+                           Allocator<T, C, C>,
+         ColPivQR<T, R, C>: Deserialize<'de>,
+         OMatrix<T, DimMinimum<R, C>, C>: Deserialize<'de>,
+         OMatrix<T, C, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct CompleteOrthogonalDecomposition<T: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, C, C>,
+{
+    col_piv_qr: ColPivQR<T, R, C>,
+    t: OMatrix<T, DimMinimum<R, C>, C>,
+    z: OMatrix<T, C, C>,
+    rank: usize,
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> Copy for CompleteOrthogonalDecomposition<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, C, C>,
+    ColPivQR<T, R, C>: Copy,
+    OMatrix<T, DimMinimum<R, C>, C>: Copy,
+    OMatrix<T, C, C>: Copy,
+{
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> CompleteOrthogonalDecomposition<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, R>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, C, C>,
+{
+    /// Computes the complete orthogonal decomposition of `matrix`.
+    ///
+    /// The numerical rank of `matrix` is the number of leading diagonal elements of the `R`
+    /// factor of its column-pivoted QR decomposition with a magnitude greater than
+    /// `eps * |R₀₀|`. The tolerance `eps` is thus relative to the largest diagonal element of
+    /// `R`, as in [`ColPivQR::rank`].
+    pub fn new(matrix: OMatrix<T, R, C>, eps: T::RealField) -> Self {
+        let ncols = matrix.shape_generic().1;
+        let col_piv_qr = ColPivQR::new(matrix);
+        let rank = col_piv_qr.rank(eps);
+
+        let mut t = col_piv_qr.r();
+        t.rows_range_mut(rank..).fill(T::zero());
+        let mut z = OMatrix::identity_generic(ncols, ncols);
+        let n = t.ncols();
+
+        // Cancel the columns `rank..` of the rows of `[R₁₁ R₁₂]`, starting from the last one,
+        // with householder reflections acting on the columns `i` and `rank..`.
+        for i in (0..rank).rev() {
+            let tail_norm_squared = t
+                .view_range(i, rank..)
+                .iter()
+                .fold(T::RealField::zero(), |a, e| a + e.clone().modulus_squared());
+
+            if tail_norm_squared.is_zero() {
+                continue;
+            }
+
+            // The reflection maps the adjoint `c` of the row `i` to `β * e₀`.
+            let c0 = t[(i, i)].clone().conjugate();
+            let norm = (c0.clone().modulus_squared() + tail_norm_squared.clone()).sqrt();
+            let sign = if c0.is_zero() {
+                T::one()
+            } else {
+                c0.clone().unscale(c0.clone().modulus())
+            };
+            let beta = -sign.scale(norm);
+            let u0 = c0 - beta.clone();
+            let u_norm_squared = u0.clone().modulus_squared() + tail_norm_squared;
+            let factor: T = crate::convert(2.0);
+            let factor = factor.unscale(u_norm_squared);
+
+            let u_tail = t.view_range(i, rank..).adjoint();
+
+            reflect_rows(&mut t, i + 1, i, rank, &u0, &u_tail, &factor);
+            reflect_rows(&mut z, n, i, rank, &u0, &u_tail, &factor);
+
+            t.view_range_mut(i, rank..).fill(T::zero());
+            t[(i, i)] = beta.conjugate();
+        }
+
+        CompleteOrthogonalDecomposition {
+            col_piv_qr,
+            t,
+            z,
+            rank,
+        }
+    }
+
+    /// The numerical rank of the decomposed matrix.
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Computes the unitary matrix `Q` of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
+    {
+        self.col_piv_qr.q()
+    }
+
+    /// The upper trapezoidal matrix `[T 0; 0 0]` of this decomposition, where only the leading
+    /// `rank x rank` block `T` is non-zero.
+    #[must_use]
+    pub fn t(&self) -> &OMatrix<T, DimMinimum<R, C>, C> {
+        &self.t
+    }
+
+    /// The unitary matrix `Z` of this decomposition.
+    #[must_use]
+    pub fn z(&self) -> &OMatrix<T, C, C> {
+        &self.z
+    }
+
+    /// The column permutation `P` of this decomposition.
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<DimMinimum<R, C>> {
+        self.col_piv_qr.p()
+    }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// This computes the minimum-norm solution, even if the decomposed matrix is rank-deficient
+    /// or underdetermined.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> LeastSquaresSolution<T, C, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert_eq!(
+            self.col_piv_qr.col_piv_qr_internal().nrows(),
+            b.nrows(),
+            "CompleteOrthogonalDecomposition solve matrix dimension mismatch."
+        );
+
+        let rank = self.rank;
+        let mut qtb = b.clone_owned();
+        self.col_piv_qr.q_tr_mul(&mut qtb);
+
+        // The diagonal of `T` is non-zero up to the rank.
+        let _ = self
+            .t
+            .view_range(..rank, ..rank)
+            .solve_upper_triangular_mut(&mut qtb.rows_range_mut(..rank));
+
+        let mut solution = OMatrix::zeros_generic(self.z.shape_generic().0, b.shape_generic().1);
+        solution.gemm(
+            T::one(),
+            &self.z.columns_range(..rank),
+            &qtb.rows_range(..rank),
+            T::zero(),
+        );
+        self.col_piv_qr.p().inv_permute_rows(&mut solution);
+
+        LeastSquaresSolution {
+            solution,
+            residual_norm: qtb.rows_range(rank..).norm(),
+            rank,
+        }
+    }
+
+    /// Computes an orthonormal basis of the null space of the decomposed matrix.
+    ///
+    /// The returned matrix has one column per basis vector.
+    #[must_use]
+    pub fn null_space(&self) -> OMatrix<T, C, Dyn>
+    where
+        DefaultAllocator: Allocator<T, C, Dyn>,
+    {
+        let mut basis = self.z.columns_range(self.rank..).into_owned();
+        self.col_piv_qr.p().inv_permute_rows(&mut basis);
+        basis
+    }
+
+    /// Computes an orthonormal basis of the column space (the range) of the decomposed matrix.
+    ///
+    /// The returned matrix has one column per basis vector.
+    #[must_use]
+    pub fn column_space(&self) -> OMatrix<T, R, Dyn>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>> + Allocator<T, R, Dyn>,
+    {
+        self.q().columns_range(..self.rank).into_owned()
+    }
+}
+
+/// Multiplies the first `nrows` rows of `m` by the householder reflection `I - factor * u * uᴴ`
+/// from the right, where the reflection only acts on the column `i` and the columns `rank..`.
+fn reflect_rows<T: ComplexField, R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(
+    m: &mut Matrix<T, R2, C2, S2>,
+    nrows: usize,
+    i: usize,
+    rank: usize,
+    u0: &T,
+    u_tail: &OMatrix<T, Dyn, U1>,
+    factor: &T,
+) {
+    for row in 0..nrows {
+        let mut s = m[(row, i)].clone() * u0.clone();
+        for (k, u) in u_tail.iter().enumerate() {
+            s += m[(row, rank + k)].clone() * u.clone();
+        }
+        s *= factor.clone();
+
+        m[(row, i)] -= s.clone() * u0.clone().conjugate();
+        for (k, u) in u_tail.iter().enumerate() {
+            m[(row, rank + k)] -= s.clone() * u.clone().conjugate();
+        }
+    }
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, R>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, C, C>,
+{
+    /// Computes an orthonormal basis of the null space of this matrix.
+    ///
+    /// The numerical rank of this matrix is determined with a column-pivoted QR decomposition,
+    /// with the tolerance `eps` relative to the largest diagonal element of its `R` factor: see
+    /// [`CompleteOrthogonalDecomposition::new`].
+    #[must_use]
+    pub fn null_space(&self, eps: T::RealField) -> OMatrix<T, C, Dyn>
+    where
+        DefaultAllocator: Allocator<T, C, Dyn>,
+    {
+        CompleteOrthogonalDecomposition::new(self.clone_owned(), eps).null_space()
+    }
+
+    /// Computes an orthonormal basis of the column space (the range) of this matrix.
+    ///
+    /// The numerical rank of this matrix is determined with a column-pivoted QR decomposition,
+    /// with the tolerance `eps` relative to the largest diagonal element of its `R` factor: see
+    /// [`CompleteOrthogonalDecomposition::new`].
+    #[must_use]
+    pub fn column_space(&self, eps: T::RealField) -> OMatrix<T, R, Dyn>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>> + Allocator<T, R, Dyn>,
+    {
+        CompleteOrthogonalDecomposition::new(self.clone_owned(), eps).column_space()
+    }
+}
@@ -0,0 +1,163 @@
+use num::{One, Zero};
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OVector};
+use crate::dimension::{Dim, U1};
+
+/// The maximum number of power iterations performed by `estimate_one_norm`.
+const MAX_ITERATIONS: usize = 5;
+
+fn one_norm<T: ComplexField, D: Dim>(v: &OVector<T, D>) -> T::RealField
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    v.iter()
+        .fold(T::RealField::zero(), |a, e| a + e.clone().modulus())
+}
+
+/// Estimates the 1-norm of a square operator `B` of dimension `dim` with the Hager-Higham
+/// algorithm (the one used by LAPACK's `xLACN2`).
+///
+/// The operator is only accessed through `apply`, which computes `B * x` in-place, and
+/// `apply_adjoint`, which computes `Bᴴ * x` in-place. Both return `false` if the product could
+/// not be computed (for example when `B` is the inverse of a singular matrix), in which case
+/// `None` is returned.
+///
+/// The result is a lower bound of `‖B‖₁` which is exact in most practical cases.
+pub(crate) fn estimate_one_norm<T, D>(
+    dim: D,
+    mut apply: impl FnMut(&mut OVector<T, D>) -> bool,
+    mut apply_adjoint: impl FnMut(&mut OVector<T, D>) -> bool,
+) -> Option<T::RealField>
+where
+    T: ComplexField,
+    D: Dim,
+    DefaultAllocator: Allocator<T, D>,
+{
+    let n = dim.value();
+
+    if n == 0 {
+        return Some(T::RealField::zero());
+    }
+
+    let inv_n = T::RealField::one() / crate::convert(n as f64);
+    let mut x = OVector::from_element_generic(dim, U1, T::from_real(inv_n));
+    let mut estimate = T::RealField::zero();
+    let mut prev_j = None;
+
+    for iter in 0..MAX_ITERATIONS {
+        if !apply(&mut x) {
+            return None;
+        }
+
+        let new_estimate = one_norm(&x);
+
+        if iter > 0 && new_estimate <= estimate {
+            break;
+        }
+
+        estimate = new_estimate;
+
+        if n == 1 {
+            return Some(estimate);
+        }
+
+        // The subgradient of the 1-norm at `B * x`.
+        x.apply(|e| {
+            *e = if e.is_zero() {
+                T::one()
+            } else {
+                e.clone().unscale(e.clone().modulus())
+            }
+        });
+
+        if !apply_adjoint(&mut x) {
+            return None;
+        }
+
+        let j = x.icamax();
+
+        // `B * e_j` cannot increase the estimate (Higham's convergence test).
+        if iter > 0 && (x[j].clone().modulus() <= estimate || prev_j == Some(j)) {
+            break;
+        }
+
+        x.fill(T::zero());
+        x[j] = T::one();
+        prev_j = Some(j);
+    }
+
+    // Higham's alternative estimate protects against the rare cases where the power
+    // iterations stall on a poor local maximum.
+    let denom: T::RealField = crate::convert((n - 1) as f64);
+    let mut alt = OVector::from_fn_generic(dim, U1, |i, _| {
+        let sign = if i % 2 == 0 {
+            T::RealField::one()
+        } else {
+            -T::RealField::one()
+        };
+        let i: T::RealField = crate::convert(i as f64);
+        T::from_real(sign * (T::RealField::one() + i / denom.clone()))
+    });
+
+    if !apply(&mut alt) {
+        return None;
+    }
+
+    let alt_estimate = one_norm(&alt) * crate::convert(2.0 / (3.0 * n as f64));
+
+    if alt_estimate > estimate {
+        estimate = alt_estimate;
+    }
+
+    Some(estimate)
+}
+
+/// Estimates the reciprocal condition number `1 / (‖A‖₁ ‖A⁻¹‖₁)` of a square matrix `A` given
+/// closures computing in-place products with `A`, `Aᴴ`, `A⁻¹` and `A⁻ᴴ`.
+///
+/// This uses the Hager-Higham estimator (the one used by LAPACK's `xLACN2`) of `‖A‖₁` and
+/// `‖A⁻¹‖₁`, which only performs a few products and solves, so it is much cheaper than
+/// computing `A⁻¹` when the closures use the factors of a decomposition of `A`. The estimates
+/// are lower bounds of the norms which are exact in most practical cases. The `rcond` methods
+/// of [`LU`](crate::linalg::LU), [`FullPivLU`](crate::linalg::FullPivLU),
+/// [`QR`](crate::linalg::QR) and [`Cholesky`](crate::linalg::Cholesky) are based on this
+/// function.
+///
+/// Each closure computes its product in-place, and returns `false` if it could not be computed
+/// (for example if `A` is singular). Returns zero if `A` is singular and one if `A` is empty.
+///
+/// # Arguments
+///
+/// * `dim`           − the dimension of `A`.
+/// * `apply`         − computes `A * x`.
+/// * `apply_adjoint` − computes `Aᴴ * x`.
+/// * `solve`         − computes `A⁻¹ * x`.
+/// * `solve_adjoint` − computes `A⁻ᴴ * x`.
+pub fn estimate_rcond<T, D>(
+    dim: D,
+    apply: impl FnMut(&mut OVector<T, D>) -> bool,
+    apply_adjoint: impl FnMut(&mut OVector<T, D>) -> bool,
+    solve: impl FnMut(&mut OVector<T, D>) -> bool,
+    solve_adjoint: impl FnMut(&mut OVector<T, D>) -> bool,
+) -> T::RealField
+where
+    T: ComplexField,
+    D: Dim,
+    DefaultAllocator: Allocator<T, D>,
+{
+    if dim.value() == 0 {
+        return T::RealField::one();
+    }
+
+    let norm = estimate_one_norm(dim, apply, apply_adjoint);
+    let inv_norm = estimate_one_norm(dim, solve, solve_adjoint);
+
+    match (norm, inv_norm) {
+        (Some(norm), Some(inv_norm)) if !norm.is_zero() && !inv_norm.is_zero() => {
+            T::RealField::one() / (norm * inv_norm)
+        }
+        _ => T::RealField::zero(),
+    }
+}
@@ -1,10 +1,96 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use std::cmp;
 
 use crate::base::allocator::Allocator;
 use crate::base::default_allocator::DefaultAllocator;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimSub, DimSum};
 use crate::storage::Storage;
-use crate::{zero, OVector, RealField, Vector, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{DMatrix, Matrix};
+use crate::{OVector, RealField, Vector, U1};
+
+/// The size of the output of a two-dimensional convolution or correlation.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConvolutionMode {
+    /// The output contains every position where the kernel overlaps the matrix, and has
+    /// `nrows + kernel_nrows - 1` rows and `ncols + kernel_ncols - 1` columns.
+    Full,
+    /// The output contains only the positions where the kernel lies entirely inside the matrix,
+    /// and has `nrows - kernel_nrows + 1` rows and `ncols - kernel_ncols + 1` columns.
+    Valid,
+    /// The output has the same size as the matrix, and is centered with respect to the `Full`
+    /// output.
+    Same,
+}
+
+/// The values assumed outside of a matrix by a two-dimensional convolution or correlation.
+///
+/// With a matrix row `a b c d`, each variant extends it as follows:
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConvolutionBoundary {
+    /// `0 0 0 | a b c d | 0 0 0`.
+    Zero,
+    /// `c b a | a b c d | d c b`: the matrix is mirrored about its edges.
+    Reflect,
+    /// `b c d | a b c d | a b c`: the matrix is repeated periodically.
+    Wrap,
+    /// `a a a | a b c d | d d d`: the edge elements are repeated.
+    Clamp,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl ConvolutionBoundary {
+    /// The index of the element of a dimension of length `len` assumed at the (possibly
+    /// out-of-bounds) index `i`, or `None` if it is zero.
+    fn map_index(self, i: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+
+        if n == 0 {
+            return None;
+        }
+
+        if (0..n).contains(&i) {
+            return Some(i as usize);
+        }
+
+        match self {
+            ConvolutionBoundary::Zero => None,
+            ConvolutionBoundary::Reflect => {
+                let r = i.rem_euclid(2 * n);
+                Some(if r < n { r } else { 2 * n - 1 - r } as usize)
+            }
+            ConvolutionBoundary::Wrap => Some(i.rem_euclid(n) as usize),
+            ConvolutionBoundary::Clamp => Some(i.clamp(0, n - 1) as usize),
+        }
+    }
+}
+
+/// The output length, along one dimension of length `len`, of a correlation with a kernel of
+/// length `ker`, and the offset of the kernel relative to the first output element.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn output_len_and_offset(mode: ConvolutionMode, len: usize, ker: usize) -> (usize, usize) {
+    match mode {
+        ConvolutionMode::Full => (len + ker - 1, ker - 1),
+        ConvolutionMode::Valid => (len + 1 - ker, 0),
+        ConvolutionMode::Same => (len, ker / 2),
+    }
+}
+
+/// For each sum `t = i + p` of an output index `i` and a kernel index `p`, the index of the
+/// input element it reads, or `None` if it is zero.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn index_map(
+    boundary: ConvolutionBoundary,
+    len: usize,
+    out_len: usize,
+    ker: usize,
+    offset: usize,
+) -> Vec<Option<usize>> {
+    (0..out_len + ker - 1)
+        .map(|t| boundary.map_index(t as isize - offset as isize, len))
+        .collect()
+}
 
 impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// Returns the convolution of the target vector and a kernel.
@@ -40,6 +126,13 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
             .0
             .add(kernel.shape_generic().0)
             .sub(Const::<1>);
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if super::fft::use_fft_convolution(vec, ker) {
+            let full = super::fft::convolve_full_fft(self, &kernel);
+            return OVector::from_fn_generic(result_len, Const::<1>, |i, _| full[i].clone());
+        }
+
         let mut conv = OVector::zeros_generic(result_len, Const::<1>);
 
         for i in 0..(vec + ker - 1) {
@@ -93,6 +186,15 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
             .0
             .add(Const::<1>)
             .sub(kernel.shape_generic().0);
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if super::fft::use_fft_convolution(vec, ker) {
+            let full = super::fft::convolve_full_fft(self, &kernel);
+            return OVector::from_fn_generic(result_len, Const::<1>, |i, _| {
+                full[i + ker - 1].clone()
+            });
+        }
+
         let mut conv = OVector::zeros_generic(result_len, Const::<1>);
 
         for i in 0..(vec - ker + 1) {
@@ -105,7 +207,9 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
 
     /// Returns the convolution of the target vector and a kernel.
     ///
-    /// The output convolution is the same size as vector, centered with respect to the ‘full’ output.
+    /// The output convolution is the same size as vector, centered with respect to the ‘full’ output:
+    /// its element `i` is the element `i + (kernel.len() - 1) / 2` of the ‘full’ output, as in
+    /// NumPy's `convolve(.., "same")`.
     /// # Arguments
     ///
     /// * `kernel` - A Vector with size > 0
@@ -126,19 +230,325 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
             panic!("convolve_same expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.",vec,ker);
         }
 
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if super::fft::use_fft_convolution(vec, ker) {
+            let full = super::fft::convolve_full_fft(self, &kernel);
+            return OVector::from_fn_generic(self.shape_generic().0, Const::<1>, |i, _| {
+                full[i + (ker - 1) / 2].clone()
+            });
+        }
+
         let mut conv = OVector::zeros_generic(self.shape_generic().0, Const::<1>);
 
+        // The output is `full[offset..offset + vec]`, where `full[t] = Σⱼ self[t - j] * kernel[j]`.
+        let offset = (ker - 1) / 2;
+
         for i in 0..vec {
+            let t = i + offset;
             for j in 0..ker {
-                let val = if i + j < 1 || i + j >= vec + 1 {
-                    zero::<T>()
-                } else {
-                    self[i + j - 1].clone()
-                };
-                conv[i] += val * kernel[ker - j - 1].clone();
+                if j <= t && t - j < vec {
+                    conv[i] += self[t - j].clone() * kernel[j].clone();
+                }
             }
         }
 
         conv
     }
 }
+
+/// # Two-dimensional convolution and correlation
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: RealField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Checks the kernel dimensions, and computes the output dimensions and the index maps of
+    /// the rows and columns.
+    #[allow(clippy::type_complexity)]
+    fn correlation2d_setup(
+        &self,
+        name: &str,
+        kernel_shape: (usize, usize),
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> ((usize, usize), Vec<Option<usize>>, Vec<Option<usize>>) {
+        let (nrows, ncols) = self.shape();
+        let (kr, kc) = kernel_shape;
+
+        if kr == 0 || kc == 0 {
+            panic!("{} expects a non-empty kernel.", name);
+        }
+
+        if mode == ConvolutionMode::Valid && (kr > nrows || kc > ncols) {
+            panic!(
+                "{} expects the kernel to fit in the matrix in `Valid` mode, received a {}x{} matrix and a {}x{} kernel.",
+                name, nrows, ncols, kr, kc
+            );
+        }
+
+        let (out_nrows, row_offset) = output_len_and_offset(mode, nrows, kr);
+        let (out_ncols, col_offset) = output_len_and_offset(mode, ncols, kc);
+        let row_map = index_map(boundary, nrows, out_nrows, kr, row_offset);
+        let col_map = index_map(boundary, ncols, out_ncols, kc, col_offset);
+
+        ((out_nrows, out_ncols), row_map, col_map)
+    }
+
+    /// Computes `out[(i, j)] = Σ self[(i + p - offset_r, j + q - offset_c)] * weight(p, q)`.
+    fn correlate2d_with(
+        &self,
+        name: &str,
+        kernel_shape: (usize, usize),
+        weight: impl Fn(usize, usize) -> T,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T> {
+        let ((out_nrows, out_ncols), row_map, col_map) =
+            self.correlation2d_setup(name, kernel_shape, mode, boundary);
+        let (kr, kc) = kernel_shape;
+        let mut out = DMatrix::zeros(out_nrows, out_ncols);
+
+        for j in 0..out_ncols {
+            for q in 0..kc {
+                let Some(src_j) = col_map[j + q] else {
+                    continue;
+                };
+
+                for p in 0..kr {
+                    let w = weight(p, q);
+
+                    for i in 0..out_nrows {
+                        if let Some(src_i) = row_map[i + p] {
+                            out[(i, j)] += self[(src_i, src_j)].clone() * w.clone();
+                        }
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Computes the correlation of `self` with the separable kernel `col_weight(p) * row_weight(q)`
+    /// as a correlation along the columns followed by a correlation along the rows.
+    fn correlate2d_separable_with(
+        &self,
+        name: &str,
+        kernel_shape: (usize, usize),
+        col_weight: impl Fn(usize) -> T,
+        row_weight: impl Fn(usize) -> T,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T> {
+        let ((out_nrows, out_ncols), row_map, col_map) =
+            self.correlation2d_setup(name, kernel_shape, mode, boundary);
+        let (kr, kc) = kernel_shape;
+
+        // Correlate each column of the matrix with the column kernel.
+        let mut tmp = DMatrix::zeros(out_nrows, self.ncols());
+        for j in 0..self.ncols() {
+            for p in 0..kr {
+                let w = col_weight(p);
+
+                for i in 0..out_nrows {
+                    if let Some(src_i) = row_map[i + p] {
+                        tmp[(i, j)] += self[(src_i, j)].clone() * w.clone();
+                    }
+                }
+            }
+        }
+
+        // Correlate each row of the result with the row kernel.
+        let mut out = DMatrix::zeros(out_nrows, out_ncols);
+        for j in 0..out_ncols {
+            for q in 0..kc {
+                if let Some(src_j) = col_map[j + q] {
+                    out.column_mut(j)
+                        .axpy(row_weight(q), &tmp.column(src_j), T::one());
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Returns the two-dimensional convolution of this matrix with a kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel`   - A non-empty matrix.
+    /// * `mode`     - The size of the output, see [`ConvolutionMode`].
+    /// * `boundary` - The values assumed outside of `self`, see [`ConvolutionBoundary`]. This is
+    ///   ignored in `Valid` mode.
+    ///
+    /// # Errors
+    /// The kernel must be non-empty and, in `Valid` mode, not larger than `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{dmatrix, ConvolutionBoundary, ConvolutionMode};
+    /// let image = dmatrix![1.0, 2.0, 3.0;
+    ///                      4.0, 5.0, 6.0];
+    /// let kernel = dmatrix![1.0, 0.0;
+    ///                       0.0, -1.0];
+    ///
+    /// let full = image.convolve2d(&kernel, ConvolutionMode::Full, ConvolutionBoundary::Zero);
+    /// assert_eq!(full, dmatrix![1.0, 2.0,  3.0,  0.0;
+    ///                           4.0, 4.0,  4.0, -3.0;
+    ///                           0.0, -4.0, -5.0, -6.0]);
+    ///
+    /// let valid = image.convolve2d(&kernel, ConvolutionMode::Valid, ConvolutionBoundary::Zero);
+    /// assert_eq!(valid, dmatrix![4.0, 4.0]);
+    /// ```
+    #[must_use]
+    pub fn convolve2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (kr, kc) = kernel.shape();
+        self.correlate2d_with(
+            "convolve2d",
+            (kr, kc),
+            |p, q| kernel[(kr - 1 - p, kc - 1 - q)].clone(),
+            mode,
+            boundary,
+        )
+    }
+
+    /// Returns the two-dimensional correlation of this matrix with a kernel, i.e., its
+    /// convolution with the kernel rotated by 180 degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel`   - A non-empty matrix.
+    /// * `mode`     - The size of the output, see [`ConvolutionMode`].
+    /// * `boundary` - The values assumed outside of `self`, see [`ConvolutionBoundary`]. This is
+    ///   ignored in `Valid` mode.
+    ///
+    /// # Errors
+    /// The kernel must be non-empty and, in `Valid` mode, not larger than `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{dmatrix, ConvolutionBoundary, ConvolutionMode};
+    /// let image = dmatrix![1.0, 2.0, 3.0;
+    ///                      4.0, 5.0, 6.0];
+    /// let kernel = dmatrix![1.0, 1.0];
+    ///
+    /// let same = image.correlate2d(&kernel, ConvolutionMode::Same, ConvolutionBoundary::Clamp);
+    /// assert_eq!(same, dmatrix![2.0, 3.0, 5.0;
+    ///                           8.0, 9.0, 11.0]);
+    /// ```
+    #[must_use]
+    pub fn correlate2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        self.correlate2d_with(
+            "correlate2d",
+            kernel.shape(),
+            |p, q| kernel[(p, q)].clone(),
+            mode,
+            boundary,
+        )
+    }
+
+    /// Returns the two-dimensional convolution of this matrix with the separable kernel
+    /// `column_kernel * row_kernel.transpose()`.
+    ///
+    /// This gives the same result as [`Matrix::convolve2d`], but only performs
+    /// `column_kernel.len() + row_kernel.len()` multiplications per element instead of
+    /// `column_kernel.len() * row_kernel.len()`. Gaussian, box, and Sobel kernels are separable.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_kernel` - A non-empty vector, applied along the columns of `self`.
+    /// * `row_kernel`    - A non-empty vector, applied along the rows of `self`.
+    /// * `mode`          - The size of the output, see [`ConvolutionMode`].
+    /// * `boundary`      - The values assumed outside of `self`, see [`ConvolutionBoundary`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{dvector, DMatrix, ConvolutionBoundary, ConvolutionMode};
+    /// let image = DMatrix::from_fn(20, 30, |i, j| ((i * j) as f64).sin());
+    /// let smooth = dvector![0.25, 0.5, 0.25];
+    /// let derivative = dvector![1.0, 0.0, -1.0];
+    /// let (mode, boundary) = (ConvolutionMode::Same, ConvolutionBoundary::Reflect);
+    ///
+    /// let sobel = image.convolve2d_separable(&smooth, &derivative, mode, boundary);
+    /// let expected = image.convolve2d(&(&smooth * derivative.transpose()), mode, boundary);
+    /// assert_relative_eq!(sobel, expected, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn convolve2d_separable<D1, S1, D2, S2>(
+        &self,
+        column_kernel: &Vector<T, D1, S1>,
+        row_kernel: &Vector<T, D2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        D1: Dim,
+        S1: Storage<T, D1>,
+        D2: Dim,
+        S2: Storage<T, D2>,
+    {
+        let (kr, kc) = (column_kernel.len(), row_kernel.len());
+        self.correlate2d_separable_with(
+            "convolve2d_separable",
+            (kr, kc),
+            |p| column_kernel[kr - 1 - p].clone(),
+            |q| row_kernel[kc - 1 - q].clone(),
+            mode,
+            boundary,
+        )
+    }
+
+    /// Returns the two-dimensional correlation of this matrix with the separable kernel
+    /// `column_kernel * row_kernel.transpose()`.
+    ///
+    /// This gives the same result as [`Matrix::correlate2d`] with fewer multiplications, see
+    /// [`Matrix::convolve2d_separable`].
+    ///
+    /// # Arguments
+    ///
+    /// * `column_kernel` - A non-empty vector, applied along the columns of `self`.
+    /// * `row_kernel`    - A non-empty vector, applied along the rows of `self`.
+    /// * `mode`          - The size of the output, see [`ConvolutionMode`].
+    /// * `boundary`      - The values assumed outside of `self`, see [`ConvolutionBoundary`].
+    #[must_use]
+    pub fn correlate2d_separable<D1, S1, D2, S2>(
+        &self,
+        column_kernel: &Vector<T, D1, S1>,
+        row_kernel: &Vector<T, D2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        D1: Dim,
+        S1: Storage<T, D1>,
+        D2: Dim,
+        S2: Storage<T, D2>,
+    {
+        self.correlate2d_separable_with(
+            "correlate2d_separable",
+            (column_kernel.len(), row_kernel.len()),
+            |p| column_kernel[p].clone(),
+            |q| row_kernel[q].clone(),
+            mode,
+            boundary,
+        )
+    }
+}
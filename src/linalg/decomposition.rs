@@ -1,10 +1,13 @@
 use crate::storage::Storage;
 use crate::{
-    Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, RealField, Schur,
-    SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    Allocator, Bidiagonal, Cholesky, ColPivQR, Complex, ComplexField, DefaultAllocator, Dim,
+    DimDiff, DimMin, DimMinimum, DimSub, Eigen, FullPivLU, Hessenberg, Matrix, OMatrix, RealField,
+    Schur, SymmetricEigen, SymmetricTridiagonal, LDLT, LU, QR, SVD, U1, UDU,
 };
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{CompleteOrthogonalDecomposition, Dyn, PCA};
+
 /// # Rectangular matrix decomposition
 ///
 /// This section contains the methods for computing some common decompositions of rectangular
@@ -14,6 +17,7 @@ use crate::{
 /// | -------------------------|---------------------|--------------|
 /// | QR                       | `Q * R`             | `Q` is an unitary matrix, and `R` is upper-triangular. |
 /// | QR with column pivoting  | `Q * R * P⁻¹`       | `Q` is an unitary matrix, and `R` is upper-triangular. `P` is a permutation matrix. |
+/// | Complete orthogonal      | `Q * [T 0] * Zᴴ * P⁻¹` | `Q` and `Z` are unitary matrices, and `T` is upper-triangular with a size equal to the rank. `P` is a permutation matrix. |
 /// | LU with partial pivoting | `P⁻¹ * L * U`       | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` is a permutation matrix. |
 /// | LU with full pivoting    | `P⁻¹ * L * U * Q⁻¹` | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` and `Q` are permutation matrices. |
 /// | SVD                      | `U * Σ * Vᵀ`        | `U` and `V` are two orthogonal matrices and `Σ` is a diagonal matrix containing the singular values. |
@@ -74,6 +78,27 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         ColPivQR::new(self.into_owned())
     }
 
+    /// Computes the complete orthogonal decomposition of this matrix.
+    ///
+    /// The numerical rank is the number of diagonal elements of the column-pivoted QR
+    /// decomposition with a magnitude greater than `eps`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn complete_orthogonal_decomposition(
+        self,
+        eps: T::RealField,
+    ) -> CompleteOrthogonalDecomposition<T, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, R>
+            + Allocator<T, DimMinimum<R, C>>
+            + Allocator<(usize, usize), DimMinimum<R, C>>
+            + Allocator<T, DimMinimum<R, C>, C>
+            + Allocator<T, C, C>,
+    {
+        CompleteOrthogonalDecomposition::new(self.into_owned(), eps)
+    }
+
     /// Computes the Singular Value Decomposition using implicit shift.
     /// The singular values are guaranteed to be sorted in descending order.
     /// If this order is not required consider using `svd_unordered`.
@@ -188,6 +213,26 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         SVD::try_new_unordered(self.into_owned(), compute_u, compute_v, eps, max_niter)
     }
 
+    /// Computes the Singular Value Decomposition using one-sided Jacobi rotations.
+    ///
+    /// This is slower than `svd`, but computes the small singular values with a high relative
+    /// accuracy. The singular values are guaranteed to be sorted in descending order.
+    pub fn svd_jacobi(self, compute_u: bool, compute_v: bool) -> SVD<T, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, C, R>
+            + Allocator<T, R, R>
+            + Allocator<T, C, C>
+            + Allocator<T, R>
+            + Allocator<T, C>
+            + Allocator<T, DimMinimum<R, C>, C>
+            + Allocator<T, R, DimMinimum<R, C>>
+            + Allocator<T::RealField, DimMinimum<R, C>>,
+    {
+        SVD::new_jacobi(self.into_owned(), compute_u, compute_v)
+    }
+
     /// Computes the Polar Decomposition of  a `matrix` (indirectly uses SVD).
     pub fn polar(self) -> (OMatrix<T, R, R>, OMatrix<T, R, C>)
     where
@@ -245,6 +290,40 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField, S: Storage<T, Dyn, Dyn>> Matrix<T, Dyn, Dyn, S> {
+    /// Computes the economy-size Singular Value Decomposition of this matrix.
+    ///
+    /// The matrix is first reduced by a QR decomposition so that only a `min(m, n) × min(m, n)`
+    /// matrix is bidiagonalized. The singular values are guaranteed to be sorted in descending
+    /// order.
+    pub fn svd_economy(self, compute_u: bool, compute_v: bool) -> SVD<T, Dyn, Dyn> {
+        SVD::new_economy(self.into_owned(), compute_u, compute_v)
+    }
+
+    /// Computes an approximation of the `k` largest singular values and singular vectors of this
+    /// matrix with a randomized algorithm.
+    ///
+    /// See [`SVD::new_truncated`] for details. The singular values are guaranteed to be sorted
+    /// in descending order.
+    #[cfg(feature = "rand")]
+    pub fn svd_truncated<G: rand::Rng + ?Sized>(
+        &self,
+        k: usize,
+        oversample: usize,
+        power_iters: usize,
+        rng: &mut G,
+    ) -> SVD<T, Dyn, Dyn> {
+        SVD::new_truncated(self, k, oversample, power_iters, rng)
+    }
+
+    /// Computes the `n_components` first principal components of this matrix, where each row is
+    /// an observation and each column is a variable.
+    pub fn pca(&self, n_components: usize) -> PCA<T> {
+        PCA::new(self, n_components)
+    }
+}
+
 /// # Square matrix decomposition
 ///
 /// This section contains the methods for computing some common decompositions of square
@@ -255,7 +334,9 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
 /// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
+/// | LDLᵀ with pivoting       | `P⁻¹ * L * D * Lᵀ * P`   | `L` is lower-triangular with a diagonal filled with `1`, `D` is block-diagonal with 1x1 and 2x2 blocks, and `P` is a permutation matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
+/// | Eigendecomposition       | `V * Λ * V⁻¹`            | `V` is a complex matrix of eigenvectors, and `Λ` is a complex diagonal matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
@@ -282,6 +363,18 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         UDU::new(self.into_owned())
     }
 
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of this matrix.
+    ///
+    /// The input matrix `self` is assumed to be symmetric, possibly indefinite, and only its
+    /// lower-triangular part is read.
+    pub fn ldlt(self) -> LDLT<T, D>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+    {
+        LDLT::new(self.into_owned())
+    }
+
     /// Computes the Hessenberg decomposition of this matrix using householder reflections.
     pub fn hessenberg(self) -> Hessenberg<T, D>
     where
@@ -325,6 +418,47 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the eigendecomposition of this real square matrix.
+    ///
+    /// The matrix does not need to be symmetric: eigenvalues and eigenvectors are complex in
+    /// general.
+    pub fn eigen(self) -> Eigen<T, D>
+    where
+        T: RealField,
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D, D>
+            + Allocator<T, D>
+            + Allocator<Complex<T>, D, D>
+            + Allocator<Complex<T>, D>,
+    {
+        Eigen::new(self.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of this real square matrix with
+    /// user-specified convergence parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_eigen(self, eps: T, max_niter: usize) -> Option<Eigen<T, D>>
+    where
+        T: RealField,
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D, D>
+            + Allocator<T, D>
+            + Allocator<Complex<T>, D, D>
+            + Allocator<Complex<T>, D>,
+    {
+        Eigen::try_new(self.into_owned(), eps, max_niter)
+    }
+
     /// Computes the eigendecomposition of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
@@ -1,114 +1,193 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+use num::Zero;
 use num_complex::Complex;
-use simba::scalar::ComplexField;
-use std::cmp;
-use std::fmt::Display;
-use std::ops::MulAssign;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::allocator::Allocator;
-use crate::base::dimension::{Dim, DimDiff, DimSub, Dyn, U1, U2, U3};
-use crate::base::storage::Storage;
-use crate::base::{
-    DefaultAllocator, Hessenberg, OMatrix, OVector, SquareMatrix, Unit, Vector2, Vector3,
-};
-use crate::constraint::{DimEq, ShapeConstraint};
-
-use crate::geometry::{Reflection, UnitComplex};
-use crate::linalg::householder;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::{DefaultAllocator, OMatrix, OVector};
+use crate::linalg::schur::{block_size_at, block_size_before};
 use crate::linalg::Schur;
 
-/// Eigendecomposition of a real matrix with real eigenvalues (or complex eigen values for complex matrices).
+/// Eigendecomposition of a real square matrix with real or complex eigenvalues.
+///
+/// The eigenvectors are computed from the real Schur decomposition of the matrix, hence do not
+/// require the matrix to be symmetric. Complex eigenvalues always come in conjugate pairs.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(serialize = "DefaultAllocator: Allocator<T, D>,
-         OVector<T, D>: Serialize,
-         OMatrix<T, D, D>: Serialize"))
+    serde(bound(serialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D>,
+         OVector<Complex<T>, D>: Serialize,
+         OMatrix<Complex<T>, D, D>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D>,
-         OVector<T, D>: Serialize,
-         OMatrix<T, D, D>: Deserialize<'de>"))
+    serde(bound(deserialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D>,
+         OVector<Complex<T>, D>: Deserialize<'de>,
+         OMatrix<Complex<T>, D, D>: Deserialize<'de>"))
 )]
 #[derive(Clone, Debug)]
-pub struct Eigen<T: ComplexField, D: Dim>
+pub struct Eigen<T: RealField, D: Dim>
 where
-    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D>,
 {
-    pub eigenvectors: OMatrix<T, D, D>,
-    pub eigenvalues: OVector<T, D>,
+    /// The (right) eigenvectors of the decomposed matrix.
+    ///
+    /// The `i`-th column has a unit norm and is associated to the `i`-th eigenvalue. If the
+    /// decomposed matrix is not diagonalizable, some eigenvectors are (nearly) collinear.
+    pub eigenvectors: OMatrix<Complex<T>, D, D>,
+
+    /// The unsorted eigenvalues of the decomposed matrix.
+    ///
+    /// Each pair of complex conjugate eigenvalues is stored contiguously, the one with a
+    /// positive imaginary part first.
+    pub eigenvalues: OVector<Complex<T>, D>,
 }
 
-impl<T: ComplexField, D: Dim> Copy for Eigen<T, D>
+impl<T: RealField, D: Dim> Copy for Eigen<T, D>
 where
-    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
-    OMatrix<T, D, D>: Copy,
-    OVector<T, D>: Copy,
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D>,
+    OMatrix<Complex<T>, D, D>: Copy,
+    OVector<Complex<T>, D>: Copy,
 {
 }
 
-impl<T: ComplexField, D: Dim> Eigen<T, D>
+impl<T: RealField, D: Dim> Eigen<T, D>
 where
-    D: DimSub<U1>,                               // For Hessenberg.
-    ShapeConstraint: DimEq<Dyn, DimDiff<D, U1>>, // For Hessenberg.
+    D: DimSub<U1>, // For Hessenberg.
     DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
         + Allocator<T, DimDiff<D, U1>>
         + Allocator<T, D, D>
-        + Allocator<T, D>,
-    // XXX: for debug
-    DefaultAllocator: Allocator<usize, D, D>,
-    OMatrix<T, D, D>: Display,
+        + Allocator<T, D>
+        + Allocator<Complex<T>, D, D>
+        + Allocator<Complex<T>, D>,
 {
-    /// Computes the eigendecomposition of a diagonalizable matrix with Complex eigenvalues.
-    pub fn new(m: OMatrix<T, D, D>) -> Option<Eigen<T, D>> {
+    /// Computes the eigendecomposition of the given square matrix.
+    pub fn new(m: OMatrix<T, D, D>) -> Self {
+        Self::try_new(m, T::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes the eigendecomposition of the given square matrix with user-specified
+    /// convergence parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new(m: OMatrix<T, D, D>, eps: T, max_niter: usize) -> Option<Self> {
         assert!(
             m.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
 
-        let dim = m.nrows();
-        let (mut eigenvectors, mut eigenvalues) = Schur::new(m, 0).unwrap().unpack();
-
-        println!("Schur eigenvalues: {}", eigenvalues);
-
-        // Check that the eigenvalues are all Complex.
-        for i in 0..dim - 1 {
-            if !eigenvalues[(i + 1, i)].is_zero() {
-                return None;
-            }
-        }
-
-        for j in 1..dim {
-            for i in 0..j {
-                let diff = eigenvalues[(i, i)] - eigenvalues[(j, j)];
-
-                if diff.is_zero() && !eigenvalues[(i, j)].is_zero() {
-                    return None;
+        let schur = Schur::try_new(m, eps, max_niter)?;
+        let eigenvalues = schur.complex_eigenvalues();
+        let (q, t) = schur.unpack();
+
+        let dim = t.nrows();
+        let (nrows, ncols) = t.shape_generic();
+        let tc: OMatrix<Complex<T>, D, D> = t.map(Complex::from_real);
+        let qc: OMatrix<Complex<T>, D, D> = q.map(Complex::from_real);
+
+        // Perturbation used in place of the (near) zero pivots appearing with repeated
+        // eigenvalues, as done by LAPACK's `trevc`.
+        let ulp = T::default_epsilon();
+        let t_amax = t.amax();
+
+        let mut eigenvectors = OMatrix::zeros_generic(nrows, ncols);
+        let mut x: OVector<Complex<T>, D> = OVector::zeros_generic(nrows, U1);
+
+        let mut k = 0;
+        while k < dim {
+            let size = block_size_at(&t, k);
+
+            for (l, lambda) in eigenvalues.rows_range(k..k + size).iter().enumerate() {
+                if l == 1 {
+                    // The second eigenvector of a 2x2 block is the conjugate of the first one.
+                    let first = eigenvectors.column(k).map(|e: Complex<T>| e.conj());
+                    eigenvectors.column_mut(k + 1).copy_from(&first);
+                    continue;
                 }
 
-                let z = -eigenvalues[(i, j)] / diff;
+                let mut smin = ulp.clone() * (lambda.clone().norm1() + t_amax.clone());
+                if smin.is_zero() {
+                    smin = ulp.clone();
+                }
 
-                for k in j + 1..dim {
-                    eigenvalues[(i, k)] -= z * eigenvalues[(j, k)];
+                // Eigenvector of the diagonal block itself.
+                x.fill(Complex::zero());
+                if size == 1 {
+                    x[k] = Complex::from_real(T::one());
+                } else {
+                    x[k] = lambda.clone() - tc[(k + 1, k + 1)].clone();
+                    x[k + 1] = tc[(k + 1, k)].clone();
                 }
 
-                for k in 0..dim {
-                    eigenvectors[(k, j)] += z * eigenvectors[(k, i)];
+                // Back-substitution on the remaining blocks of (T - λI) x = 0.
+                let end = k + size;
+                let mut i_end = k;
+                while i_end > 0 {
+                    let p = block_size_before(&t, i_end);
+                    let i = i_end - p;
+
+                    let r0 = -tc
+                        .view_range(i, i_end..end)
+                        .tr_dot(&x.rows_range(i_end..end));
+                    if p == 1 {
+                        let mut d = tc[(i, i)].clone() - lambda.clone();
+                        if d.clone().norm1() < smin {
+                            d = Complex::from_real(smin.clone());
+                        }
+                        x[i] = r0 / d;
+                    } else {
+                        let r1 = -tc
+                            .view_range(i + 1, i_end..end)
+                            .tr_dot(&x.rows_range(i_end..end));
+                        let a = tc[(i, i)].clone() - lambda.clone();
+                        let b = tc[(i, i + 1)].clone();
+                        let c = tc[(i + 1, i)].clone();
+                        let d = tc[(i + 1, i + 1)].clone() - lambda.clone();
+
+                        let mut det = a.clone() * d.clone() - b.clone() * c.clone();
+                        if det.clone().norm1() < smin {
+                            det = Complex::from_real(smin.clone());
+                        }
+
+                        x[i] = (d * r0.clone() - b * r1.clone()) / det.clone();
+                        x[i + 1] = (a * r1 - c * r0) / det;
+                    }
+
+                    i_end = i;
                 }
+
+                let mut v = eigenvectors.column_mut(k);
+                v.gemv(
+                    Complex::from_real(T::one()),
+                    &qc.columns_range(..end),
+                    &x.rows_range(..end),
+                    Complex::zero(),
+                );
+                let _ = v.normalize_mut();
             }
-        }
 
-        // Normalize the eigenvector basis.
-        for i in 0..dim {
-            let _ = eigenvectors.column_mut(i).normalize_mut();
+            k += size;
         }
 
         Some(Eigen {
             eigenvectors,
-            eigenvalues: eigenvalues.diagonal(),
+            eigenvalues,
         })
     }
+
+    /// Returns `true` if all the eigenvalues are real.
+    #[must_use]
+    pub fn eigenvalues_are_real(&self) -> bool {
+        self.eigenvalues.iter().all(|e| e.im.is_zero())
+    }
 }
@@ -0,0 +1,328 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::Zero;
+use num_complex::Complex;
+
+use crate::allocator::Allocator;
+use crate::base::{DVector, DefaultAllocator, OVector, Vector};
+use crate::dimension::{Dim, U1};
+use crate::storage::Storage;
+use crate::RealField;
+
+/// Whether the full convolution of two real sequences of lengths `n` and `k` is cheaper to
+/// compute with FFTs than directly.
+///
+/// The direct convolution performs `n * k` multiplications, while the FFT path performs three
+/// real transforms of the next power of two `len ≥ n + k - 1`, each costing about
+/// `2 * len * log2(len)` real multiplications.
+pub(crate) fn use_fft_convolution(n: usize, k: usize) -> bool {
+    let len = (n + k - 1).next_power_of_two();
+    let log2_len = len.trailing_zeros() as usize;
+
+    n.min(k) >= 32 && n.saturating_mul(k) > 6 * len * log2_len
+}
+
+/// Computes the full convolution of two non-empty real vectors with FFTs.
+pub(crate) fn convolve_full_fft<T, D1, S1, D2, S2>(
+    a: &Vector<T, D1, S1>,
+    b: &Vector<T, D2, S2>,
+) -> DVector<T>
+where
+    T: RealField,
+    D1: Dim,
+    S1: Storage<T, D1>,
+    D2: Dim,
+    S2: Storage<T, D2>,
+{
+    let conv_len = a.len() + b.len() - 1;
+    let len = conv_len.next_power_of_two();
+    let spectrum = zero_padded(a, len)
+        .rfft()
+        .component_mul(&zero_padded(b, len).rfft());
+    let conv = spectrum.irfft(len);
+
+    conv.rows(0, conv_len).into_owned()
+}
+
+/// Copies `v` into a vector of length `len`, padded with zeros.
+fn zero_padded<T: RealField, D: Dim, S: Storage<T, D>>(
+    v: &Vector<T, D, S>,
+    len: usize,
+) -> DVector<T> {
+    let mut res = DVector::zeros(len);
+    for (r, e) in res.iter_mut().zip(v.iter()) {
+        *r = e.clone();
+    }
+    res
+}
+
+/// The unit complex numbers `exp(sign * 2πi * j / n)` for `0 ≤ j < n / 2`.
+fn twiddles<T: RealField>(n: usize, inverse: bool) -> Vec<Complex<T>> {
+    let sign = if inverse { T::one() } else { -T::one() };
+    let scale = sign * T::two_pi() / crate::convert(n as f64);
+
+    (0..n / 2)
+        .map(|j| {
+            let (sin, cos) = (scale.clone() * crate::convert(j as f64)).sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect()
+}
+
+/// Iterative radix-2 Cooley-Tukey FFT, whose length must be a power of two.
+///
+/// The result is not normalized.
+fn fft_radix2<T: RealField>(buf: &mut [Complex<T>], inverse: bool) {
+    let n = buf.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal permutation.
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            buf.swap(i, j);
+        }
+    }
+
+    let twiddles = twiddles(n, inverse);
+    let mut len = 2;
+
+    while len <= n {
+        let half = len / 2;
+        let step = n / len;
+
+        for start in (0..n).step_by(len) {
+            for j in 0..half {
+                let u = buf[start + j].clone();
+                let v = buf[start + j + half].clone() * twiddles[j * step].clone();
+                buf[start + j] = u.clone() + v.clone();
+                buf[start + j + half] = u - v;
+            }
+        }
+
+        len *= 2;
+    }
+}
+
+/// Bluestein's chirp-z FFT, for any length.
+///
+/// This expresses the DFT as a convolution computed with radix-2 FFTs of a power of two
+/// greater than `2 * n - 1`. The result is not normalized.
+fn fft_bluestein<T: RealField>(buf: &mut [Complex<T>], inverse: bool) {
+    let n = buf.len();
+    let len = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { T::one() } else { -T::one() };
+    let scale = sign * T::pi() / crate::convert(n as f64);
+
+    // The chirp `exp(sign * πi * j² / n)`. Reducing `j²` modulo `2 * n` keeps the angle accurate.
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|j| {
+            let j2 = (j as u128 * j as u128 % (2 * n as u128)) as f64;
+            let (sin, cos) = (scale.clone() * crate::convert(j2)).sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect();
+
+    let mut a: Vec<Complex<T>> = (0..len).map(|_| Complex::zero()).collect();
+    let mut b: Vec<Complex<T>> = (0..len).map(|_| Complex::zero()).collect();
+
+    for j in 0..n {
+        a[j] = buf[j].clone() * chirp[j].clone();
+    }
+
+    b[0] = chirp[0].clone().conj();
+    for j in 1..n {
+        b[j] = chirp[j].clone().conj();
+        b[len - j] = chirp[j].clone().conj();
+    }
+
+    fft_radix2(&mut a, false);
+    fft_radix2(&mut b, false);
+    for (a, b) in a.iter_mut().zip(b) {
+        *a = a.clone() * b;
+    }
+    fft_radix2(&mut a, true);
+
+    let inv_len: T = crate::convert(1.0 / len as f64);
+    for j in 0..n {
+        buf[j] = a[j].clone() * chirp[j].clone() * inv_len.clone();
+    }
+}
+
+/// Computes the unnormalized DFT of `buf` in-place.
+fn fft_in_place<T: RealField>(buf: &mut [Complex<T>], inverse: bool) {
+    if buf.len().is_power_of_two() {
+        fft_radix2(buf, inverse)
+    } else if buf.len() > 1 {
+        fft_bluestein(buf, inverse)
+    }
+}
+
+/// # Fast Fourier transforms
+impl<T: RealField, D: Dim, S: Storage<Complex<T>, D>> Vector<Complex<T>, D, S> {
+    /// Computes the discrete Fourier transform of this vector with a fast Fourier transform.
+    ///
+    /// The `k`-th component of the result is `Σ self[j] * exp(-2πi * j * k / n)`, where `n` is
+    /// the length of this vector. Powers of two use a radix-2 algorithm, and other lengths use
+    /// Bluestein's algorithm, so that every length takes `O(n log(n))` operations.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Complex, Vector3};
+    /// let v = Vector3::new(1.0, 2.0, 3.0).map(|e| Complex::new(e, 0.0));
+    /// let spectrum = v.fft();
+    /// let h = 3.0f64.sqrt() / 2.0;
+    ///
+    /// assert_relative_eq!(spectrum[0], Complex::new(6.0, 0.0), epsilon = 1.0e-12);
+    /// assert_relative_eq!(spectrum[1], Complex::new(-1.5, h), epsilon = 1.0e-12);
+    /// assert_relative_eq!(spectrum[2], Complex::new(-1.5, -h), epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn fft(&self) -> OVector<Complex<T>, D>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D>,
+    {
+        let mut buf: Vec<_> = self.iter().cloned().collect();
+        fft_in_place(&mut buf, false);
+        OVector::from_iterator_generic(self.shape_generic().0, U1, buf)
+    }
+
+    /// Computes the inverse discrete Fourier transform of this vector with a fast Fourier
+    /// transform.
+    ///
+    /// The `j`-th component of the result is `Σ self[k] * exp(2πi * j * k / n) / n`, where `n`
+    /// is the length of this vector, so that `v.fft().ifft()` is equal to `v` up to rounding
+    /// errors.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Complex, DVector};
+    /// let v = DVector::from_fn(12, |i, _| Complex::new(i as f64, 1.0 / (i as f64 + 1.0)));
+    /// assert_relative_eq!(v.fft().ifft(), v, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn ifft(&self) -> OVector<Complex<T>, D>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D>,
+    {
+        let mut buf: Vec<_> = self.iter().cloned().collect();
+        fft_in_place(&mut buf, true);
+
+        let inv_n: T = crate::convert(1.0 / self.len() as f64);
+        OVector::from_iterator_generic(
+            self.shape_generic().0,
+            U1,
+            buf.into_iter().map(|e| e * inv_n.clone()),
+        )
+    }
+
+    /// Computes the real signal of length `n` whose discrete Fourier transform starts with
+    /// this vector, i.e., the inverse of [`Matrix::rfft`](crate::Matrix::rfft).
+    ///
+    /// This vector must contain the `n / 2 + 1` first components of the spectrum. The imaginary
+    /// parts of its first component, and of its last component if `n` is even, are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DVector;
+    /// let v = DVector::from_fn(9, |i, _| (i as f64).sin());
+    /// assert_relative_eq!(v.rfft().irfft(9), v, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn irfft(&self, n: usize) -> DVector<T> {
+        assert_eq!(
+            self.len(),
+            n / 2 + 1,
+            "irfft: the spectrum must have `n / 2 + 1` components."
+        );
+
+        if n == 0 {
+            return DVector::zeros(0);
+        }
+
+        // Rebuild the full Hermitian spectrum.
+        let mut buf: Vec<_> = (0..n)
+            .map(|k| {
+                if k <= n / 2 {
+                    self[k].clone()
+                } else {
+                    self[n - k].clone().conj()
+                }
+            })
+            .collect();
+        buf[0].im = T::zero();
+        if n.is_multiple_of(2) {
+            buf[n / 2].im = T::zero();
+        }
+
+        fft_in_place(&mut buf, true);
+
+        let inv_n: T = crate::convert(1.0 / n as f64);
+        DVector::from_iterator(n, buf.into_iter().map(|e| e.re * inv_n.clone()))
+    }
+}
+
+impl<T: RealField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// Computes the discrete Fourier transform of this real vector.
+    ///
+    /// Since the spectrum of a real signal is Hermitian, only its `n / 2 + 1` first components
+    /// are returned, where `n` is the length of this vector. For even lengths, this performs a
+    /// complex FFT of half the length.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Complex, DVector};
+    /// let v = DVector::from_fn(10, |i, _| (i * i) as f64);
+    /// let spectrum = v.map(|e| Complex::new(e, 0.0)).fft();
+    ///
+    /// assert_relative_eq!(v.rfft(), spectrum.rows(0, 6).into_owned(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn rfft(&self) -> DVector<Complex<T>> {
+        let n = self.len();
+
+        if n == 0 {
+            return DVector::from_element(1, Complex::zero());
+        }
+
+        if !n.is_multiple_of(2) {
+            let mut buf: Vec<_> = self
+                .iter()
+                .map(|e| Complex::new(e.clone(), T::zero()))
+                .collect();
+            fft_in_place(&mut buf, false);
+            buf.truncate(n / 2 + 1);
+            return DVector::from_vec(buf);
+        }
+
+        // Pack the even and odd samples into the real and imaginary parts of a complex signal
+        // `z` of length `m = n / 2`, whose spectrum is `Z[k] = E[k] + i * O[k]`.
+        let m = n / 2;
+        let mut z: Vec<_> = (0..m)
+            .map(|j| Complex::new(self[2 * j].clone(), self[2 * j + 1].clone()))
+            .collect();
+        fft_in_place(&mut z, false);
+
+        // `X[k] = E[k] + exp(-2πi * k / n) * O[k]`.
+        let half: T = crate::convert(0.5);
+        let scale = -T::two_pi() / crate::convert(n as f64);
+
+        DVector::from_fn(m + 1, |k, _| {
+            let zk = z[k % m].clone();
+            let zc = z[(m - k) % m].clone().conj();
+            let even = (zk.clone() + zc.clone()) * half.clone();
+            let odd = (zk - zc) * Complex::new(T::zero(), -half.clone());
+            let (sin, cos) = (scale.clone() * crate::convert(k as f64)).sin_cos();
+
+            even + odd * Complex::new(cos, sin)
+        })
+    }
+}
@@ -8,6 +8,7 @@ use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
 
+use crate::linalg::condition::estimate_rcond;
 use crate::linalg::lu;
 use crate::linalg::PermutationSequence;
 
@@ -244,6 +245,48 @@ where
         !self.lu[(dim - 1, dim - 1)].is_zero()
     }
 
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// See [`estimate_rcond`](crate::linalg::estimate_rcond) for details on the estimator. The
+    /// result is close to zero for ill-conditioned matrices, and exactly zero for singular
+    /// matrices.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        assert!(
+            self.lu.is_square(),
+            "FullPivLU rcond: unable to estimate the condition number of a non-square matrix."
+        );
+
+        let l = self.l();
+        let u = self.u();
+
+        estimate_rcond(
+            self.lu.shape_generic().0,
+            |x| {
+                self.q.permute_rows(x);
+                *x = &l * (&u * &*x);
+                self.p.inv_permute_rows(x);
+                true
+            },
+            |x| {
+                self.p.permute_rows(x);
+                *x = u.ad_mul(&l.ad_mul(&*x));
+                self.q.inv_permute_rows(x);
+                true
+            },
+            |x| self.solve_mut(x),
+            |x| {
+                self.q.permute_rows(x);
+                let ok = u.ad_solve_upper_triangular_mut(x) && l.ad_solve_lower_triangular_mut(x);
+                self.p.inv_permute_rows(x);
+                ok
+            },
+        )
+    }
+
     /// Computes the determinant of the decomposed matrix.
     #[must_use]
     pub fn determinant(&self) -> T {
@@ -0,0 +1,619 @@
+//! Krylov subspace iterative solvers of linear systems, usable with matrix-free operators.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::{One, Zero};
+use simba::scalar::{ClosedAdd, ClosedMul, ComplexField};
+
+use crate::base::dimension::Dyn;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Scalar, Vector2};
+use crate::linalg::givens::GivensRotation;
+
+/// A linear map `x ↦ A * x` between vectors, which does not need to be stored as a matrix.
+///
+/// This is implemented for dense matrices, for closures wrapped in a [`FnOperator`], and for the
+/// sparse matrices of `nalgebra-sparse`. It is used by the iterative solvers
+/// [`conjugate_gradient`], [`minres`], and [`gmres`], both for the system matrix and for its
+/// preconditioner.
+pub trait LinearOperator<T> {
+    /// The number of rows of this operator, i.e., the length of its output vectors.
+    fn nrows(&self) -> usize;
+
+    /// The number of columns of this operator, i.e., the length of its input vectors.
+    fn ncols(&self) -> usize;
+
+    /// Computes `y = A * x`, where `A` is this operator.
+    ///
+    /// The previous content of `y` must be ignored. Its length is the number of rows of `A`.
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>);
+}
+
+impl<T, S> LinearOperator<T> for Matrix<T, Dyn, Dyn, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    S: Storage<T, Dyn, Dyn>,
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.shape().0
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.shape().1
+    }
+
+    #[inline]
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.gemv(T::one(), self, x, T::zero());
+    }
+}
+
+/// A matrix-free operator defined by a closure `f(x, y)` computing `y = A * x`.
+#[derive(Clone, Debug)]
+pub struct FnOperator<F> {
+    f: F,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<F> FnOperator<F> {
+    /// Creates the operator with `nrows` rows and `ncols` columns applied by the closure `f`.
+    pub fn new(nrows: usize, ncols: usize, f: F) -> Self {
+        Self { f, nrows, ncols }
+    }
+
+    /// Creates the square operator of dimension `dim` applied by the closure `f`.
+    pub fn square(dim: usize, f: F) -> Self {
+        Self::new(dim, dim, f)
+    }
+}
+
+impl<T, F> LinearOperator<T> for FnOperator<F>
+where
+    F: Fn(&DVector<T>, &mut DVector<T>),
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        (self.f)(x, y)
+    }
+}
+
+/// The identity operator, i.e., the absence of preconditioner.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct IdentityPreconditioner {
+    dim: usize,
+}
+
+impl IdentityPreconditioner {
+    /// Creates the identity operator of dimension `dim`.
+    pub fn new(dim: usize) -> Self {
+        Self { dim }
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for IdentityPreconditioner {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.dim
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.dim
+    }
+
+    #[inline]
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.copy_from(x);
+    }
+}
+
+/// The Jacobi preconditioner, which multiplies a vector by the inverse of the diagonal of a
+/// matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JacobiPreconditioner<T> {
+    inv_diagonal: DVector<T>,
+}
+
+impl<T: ComplexField> JacobiPreconditioner<T> {
+    /// Creates the Jacobi preconditioner of a matrix from its diagonal.
+    ///
+    /// The zero elements of the diagonal are replaced by ones.
+    pub fn new(diagonal: &DVector<T>) -> Self {
+        Self {
+            inv_diagonal: diagonal.map(|d| if d.is_zero() { T::one() } else { T::one() / d }),
+        }
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for JacobiPreconditioner<T> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.inv_diagonal.len()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.inv_diagonal.len()
+    }
+
+    #[inline]
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.copy_from(x);
+        y.component_mul_assign(&self.inv_diagonal);
+    }
+}
+
+/// The approximate solution of a linear system computed by an iterative solver, and its
+/// convergence diagnostics.
+#[derive(Clone, Debug)]
+pub struct KrylovSolution<T: ComplexField> {
+    /// The approximate solution `x` of `A * x = b`.
+    pub solution: DVector<T>,
+    /// Whether the convergence criterion was met before the maximum number of iterations.
+    pub converged: bool,
+    /// The number of iterations performed, i.e., of products by `A`, excluding those computing
+    /// the residuals.
+    pub niter: usize,
+    /// The norm of the residual `b - A * x`, computed after the last iteration.
+    pub residual_norm: T::RealField,
+    /// The estimate of the residual norm maintained by the solver, before the first iteration
+    /// and after each iteration.
+    pub residual_history: Vec<T::RealField>,
+}
+
+/// Computes `b - A * x`.
+fn residual<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    x: &DVector<T>,
+) -> DVector<T> {
+    let mut r = DVector::zeros(b.len());
+    a.apply(x, &mut r);
+    r.axpy(T::one(), b, -T::one());
+    r
+}
+
+/// Checks that the operator and the preconditioner are square with the dimension of `b`.
+fn check_dimensions<T, A, P>(solver: &str, a: &A, b: &DVector<T>, preconditioner: &P)
+where
+    A: LinearOperator<T> + ?Sized,
+    P: LinearOperator<T> + ?Sized,
+{
+    let n = b.len();
+    assert!(
+        a.nrows() == n && a.ncols() == n,
+        "{}: the operator must be square with the dimension of the right-hand side.",
+        solver
+    );
+    assert!(
+        preconditioner.nrows() == n && preconditioner.ncols() == n,
+        "{}: the preconditioner must be square with the dimension of the right-hand side.",
+        solver
+    );
+}
+
+/// Checks the dimensions of the initial guess, or creates a zero initial guess.
+fn initial_guess<T: ComplexField>(b: &DVector<T>, x0: Option<DVector<T>>) -> DVector<T> {
+    let x = x0.unwrap_or_else(|| DVector::zeros(b.len()));
+    assert_eq!(
+        x.len(),
+        b.len(),
+        "Krylov solver: the initial guess and the right-hand side must have the same length."
+    );
+    x
+}
+
+/// Whether the iteration `niter` exceeds the maximum number of iterations, where zero means
+/// no limit.
+fn limit_reached(niter: usize, max_niter: usize) -> bool {
+    max_niter != 0 && niter >= max_niter
+}
+
+fn finish<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    solution: DVector<T>,
+    converged: bool,
+    niter: usize,
+    residual_history: Vec<T::RealField>,
+) -> KrylovSolution<T> {
+    let residual_norm = residual(a, b, &solution).norm();
+
+    KrylovSolution {
+        solution,
+        converged,
+        niter,
+        residual_norm,
+        residual_history,
+    }
+}
+
+/// Solves the linear system `A * x = b` with the preconditioned conjugate gradient method,
+/// where `A` is Hermitian-definite-positive.
+///
+/// The iterations stop when `‖b - A * x‖ ≤ tolerance * ‖b‖`.
+///
+/// # Arguments
+///
+/// * `a`              − the Hermitian-definite-positive operator `A`.
+/// * `b`              − the right-hand side of the system.
+/// * `x0`             − the initial guess of the solution. Zero is used if it is `None`.
+/// * `preconditioner` − a Hermitian-definite-positive approximation of `A⁻¹`, or
+///   [`IdentityPreconditioner`].
+/// * `tolerance`      − the relative tolerance on the residual norm.
+/// * `max_niter`      − maximum number of iterations. If `max_niter == 0`, the iterations
+///   continue until convergence.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{dmatrix, dvector};
+/// # use nalgebra::linalg::{conjugate_gradient, IdentityPreconditioner};
+/// let a = dmatrix![4.0, 1.0; 1.0, 3.0];
+/// let b = dvector![1.0, 2.0];
+///
+/// let sol = conjugate_gradient(&a, &b, None, &IdentityPreconditioner::new(2), 1.0e-12, 10);
+/// assert!(sol.converged);
+/// assert_relative_eq!(&a * sol.solution, b, epsilon = 1.0e-10);
+/// ```
+pub fn conjugate_gradient<T, A, P>(
+    a: &A,
+    b: &DVector<T>,
+    x0: Option<DVector<T>>,
+    preconditioner: &P,
+    tolerance: T::RealField,
+    max_niter: usize,
+) -> KrylovSolution<T>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+    P: LinearOperator<T> + ?Sized,
+{
+    check_dimensions("Conjugate gradient", a, b, preconditioner);
+    let n = b.len();
+    let threshold = tolerance * b.norm();
+
+    let mut x = initial_guess(b, x0);
+    let mut r = residual(a, b, &x);
+    let mut z = DVector::zeros(n);
+    let mut ap = DVector::zeros(n);
+
+    let mut r_norm = r.norm();
+    let mut history = Vec::new();
+    history.push(r_norm.clone());
+    let mut niter = 0;
+
+    preconditioner.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut rz = r.dotc(&z);
+
+    while r_norm > threshold {
+        if limit_reached(niter, max_niter) {
+            return finish(a, b, x, false, niter, history);
+        }
+
+        a.apply(&p, &mut ap);
+        niter += 1;
+
+        let pap = p.dotc(&ap);
+        if pap.is_zero() {
+            // The search direction is zero, which only happens at the solution.
+            break;
+        }
+
+        let alpha = rz.clone() / pap;
+        x.axpy(alpha.clone(), &p, T::one());
+        r.axpy(-alpha, &ap, T::one());
+
+        r_norm = r.norm();
+        history.push(r_norm.clone());
+
+        preconditioner.apply(&r, &mut z);
+        let rz_new = r.dotc(&z);
+        let beta = rz_new.clone() / rz;
+        rz = rz_new;
+        p.axpy(T::one(), &z, beta);
+    }
+
+    let converged = r_norm <= threshold;
+    finish(a, b, x, converged, niter, history)
+}
+
+/// Solves the linear system `A * x = b` with the preconditioned minimal residual method (MINRES),
+/// where `A` is Hermitian but possibly indefinite.
+///
+/// MINRES minimizes the residual norm over successive Krylov subspaces. With a preconditioner
+/// `M⁻¹`, the residual is measured with the norm `‖r‖_M = sqrt(rᴴ * M⁻¹ * r)`, and the iterations
+/// stop when `‖b - A * x‖_M ≤ tolerance * ‖b‖_M`.
+///
+/// # Arguments
+///
+/// * `a`              − the Hermitian operator `A`.
+/// * `b`              − the right-hand side of the system.
+/// * `x0`             − the initial guess of the solution. Zero is used if it is `None`.
+/// * `preconditioner` − a Hermitian-definite-positive approximation `M⁻¹` of `A⁻¹`, or
+///   [`IdentityPreconditioner`].
+/// * `tolerance`      − the relative tolerance on the residual norm.
+/// * `max_niter`      − maximum number of iterations. If `max_niter == 0`, the iterations
+///   continue until convergence.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{dmatrix, dvector};
+/// # use nalgebra::linalg::{minres, IdentityPreconditioner};
+/// // A symmetric indefinite matrix.
+/// let a = dmatrix![1.0, 2.0, 0.0; 2.0, -1.0, 1.0; 0.0, 1.0, 3.0];
+/// let b = dvector![1.0, 0.0, 1.0];
+///
+/// let sol = minres(&a, &b, None, &IdentityPreconditioner::new(3), 1.0e-12, 0);
+/// assert!(sol.converged);
+/// assert_relative_eq!(&a * sol.solution, b, epsilon = 1.0e-10);
+/// ```
+pub fn minres<T, A, P>(
+    a: &A,
+    b: &DVector<T>,
+    x0: Option<DVector<T>>,
+    preconditioner: &P,
+    tolerance: T::RealField,
+    max_niter: usize,
+) -> KrylovSolution<T>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+    P: LinearOperator<T> + ?Sized,
+{
+    check_dimensions("MINRES", a, b, preconditioner);
+    let n = b.len();
+    let mut x = initial_guess(b, x0);
+
+    // The preconditioned norm of `b`.
+    let mut y = DVector::zeros(n);
+    preconditioner.apply(b, &mut y);
+    let threshold = tolerance * b.dotc(&y).real().sqrt();
+
+    // The Lanczos vectors are `M⁻¹ * r1` and `M⁻¹ * r2` normalized.
+    let mut r1 = residual(a, b, &x);
+    preconditioner.apply(&r1, &mut y);
+    let beta1 = r1.dotc(&y).real().sqrt();
+    let mut r2 = r1.clone();
+
+    let mut history = Vec::new();
+    history.push(beta1.clone());
+    let mut niter = 0;
+
+    let zero = T::RealField::zero;
+    let (mut beta, mut old_beta) = (beta1.clone(), zero());
+    let (mut dbar, mut epsilon) = (zero(), zero());
+    let mut phibar = beta1;
+    let (mut cs, mut sn) = (-T::RealField::one(), zero());
+    let mut w = DVector::zeros(n);
+    let mut w2 = DVector::zeros(n);
+    let mut v = DVector::zeros(n);
+
+    while phibar > threshold {
+        if limit_reached(niter, max_niter) || beta.is_zero() {
+            break;
+        }
+
+        // Lanczos step.
+        v.copy_from(&y);
+        v.unscale_mut(beta.clone());
+        a.apply(&v, &mut y);
+        niter += 1;
+
+        if niter >= 2 {
+            y.axpy(T::from_real(-beta.clone() / old_beta), &r1, T::one());
+        }
+
+        let alpha = v.dotc(&y).real();
+        y.axpy(T::from_real(-alpha.clone() / beta.clone()), &r2, T::one());
+        std::mem::swap(&mut r1, &mut r2);
+        r2.copy_from(&y);
+        preconditioner.apply(&r2, &mut y);
+        old_beta = beta;
+        beta = r2.dotc(&y).real().sqrt();
+
+        // Apply the previous rotation, and compute the new one to eliminate `beta` from the
+        // tridiagonal matrix.
+        let old_epsilon = epsilon;
+        let delta = cs.clone() * dbar.clone() + sn.clone() * alpha.clone();
+        let gbar = sn.clone() * dbar - cs.clone() * alpha;
+        epsilon = sn.clone() * beta.clone();
+        dbar = -cs.clone() * beta.clone();
+
+        let gamma = (gbar.clone() * gbar.clone() + beta.clone() * beta.clone()).sqrt();
+        if gamma.is_zero() {
+            // `A` is singular along the current direction.
+            break;
+        }
+
+        cs = gbar / gamma.clone();
+        sn = beta.clone() / gamma.clone();
+        let phi = cs.clone() * phibar.clone();
+        phibar *= sn.clone();
+
+        // Update the solution along the new direction `w`.
+        let w1 = std::mem::replace(&mut w2, w.clone());
+        w.copy_from(&v);
+        w.axpy(T::from_real(-old_epsilon), &w1, T::one());
+        w.axpy(T::from_real(-delta), &w2, T::one());
+        w.unscale_mut(gamma);
+        x.axpy(T::from_real(phi), &w, T::one());
+
+        history.push(phibar.clone());
+    }
+
+    let converged = phibar <= threshold;
+    finish(a, b, x, converged, niter, history)
+}
+
+/// Solves the linear system `A * x = b` with the restarted generalized minimal residual method
+/// (GMRES), where `A` is any square operator.
+///
+/// The preconditioner is applied on the right, i.e., GMRES is applied to `A * M⁻¹ * u = b` with
+/// `x = M⁻¹ * u`, so that the true residual norm is minimized. The iterations stop when
+/// `‖b - A * x‖ ≤ tolerance * ‖b‖`.
+///
+/// # Arguments
+///
+/// * `a`              − the operator `A`.
+/// * `b`              − the right-hand side of the system.
+/// * `x0`             − the initial guess of the solution. Zero is used if it is `None`.
+/// * `preconditioner` − an approximation `M⁻¹` of `A⁻¹`, or [`IdentityPreconditioner`].
+/// * `restart`        − the maximum dimension of the Krylov subspace, after which GMRES restarts
+///   from the current solution. Each iteration costs `O(restart * n)` operations.
+/// * `tolerance`      − the relative tolerance on the residual norm.
+/// * `max_niter`      − maximum number of iterations. If `max_niter == 0`, the iterations
+///   continue until convergence.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{dmatrix, dvector, DVector};
+/// # use nalgebra::linalg::{gmres, FnOperator, IdentityPreconditioner};
+/// // A matrix-free non-symmetric operator.
+/// let a = FnOperator::square(2, |x: &DVector<f64>, y: &mut DVector<f64>| {
+///     y[0] = 2.0 * x[0] + x[1];
+///     y[1] = -x[0] + 3.0 * x[1];
+/// });
+/// let b = dvector![1.0, 2.0];
+///
+/// let sol = gmres(&a, &b, None, &IdentityPreconditioner::new(2), 10, 1.0e-12, 0);
+/// assert!(sol.converged);
+/// assert_relative_eq!(dmatrix![2.0, 1.0; -1.0, 3.0] * sol.solution, b, epsilon = 1.0e-10);
+/// ```
+pub fn gmres<T, A, P>(
+    a: &A,
+    b: &DVector<T>,
+    x0: Option<DVector<T>>,
+    preconditioner: &P,
+    restart: usize,
+    tolerance: T::RealField,
+    max_niter: usize,
+) -> KrylovSolution<T>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+    P: LinearOperator<T> + ?Sized,
+{
+    assert!(restart > 0, "GMRES: the restart length must be positive.");
+    check_dimensions("GMRES", a, b, preconditioner);
+    let n = b.len();
+    let m = restart.min(n.max(1));
+    let threshold = tolerance * b.norm();
+
+    let mut x = initial_guess(b, x0);
+    let mut history = Vec::new();
+    let mut niter = 0;
+
+    // The Arnoldi basis, the Hessenberg matrix, and the right-hand side of the least-squares
+    // problem, which are triangularized by Givens rotations.
+    let mut basis = DMatrix::zeros(n, m + 1);
+    let mut h = DMatrix::zeros(m + 1, m);
+    let mut g = DVector::zeros(m + 1);
+    let mut rotations = Vec::with_capacity(m);
+    let mut v = DVector::zeros(n);
+    let mut z = DVector::zeros(n);
+    let mut w = DVector::zeros(n);
+
+    loop {
+        let r = residual(a, b, &x);
+        let beta = r.norm();
+
+        if history.is_empty() {
+            history.push(beta.clone());
+        }
+
+        if beta <= threshold {
+            return finish(a, b, x, true, niter, history);
+        }
+
+        if limit_reached(niter, max_niter) {
+            return finish(a, b, x, false, niter, history);
+        }
+
+        basis.column_mut(0).copy_from(&r.unscale(beta.clone()));
+        g.fill(T::zero());
+        g[0] = T::from_real(beta);
+        h.fill(T::zero());
+        rotations.clear();
+
+        let mut k = 0;
+        let mut estimate;
+
+        loop {
+            // Arnoldi step with modified Gram-Schmidt.
+            v.copy_from(&basis.column(k));
+            preconditioner.apply(&v, &mut z);
+            a.apply(&z, &mut w);
+            niter += 1;
+
+            for i in 0..=k {
+                let hik = basis.column(i).dotc(&w);
+                w.axpy(-hik.clone(), &basis.column(i), T::one());
+                h[(i, k)] = hik;
+            }
+
+            let w_norm = w.norm();
+            h[(k + 1, k)] = T::from_real(w_norm.clone());
+
+            // Triangularize the new column of the Hessenberg matrix.
+            for (i, rot) in rotations.iter().enumerate() {
+                let rot: &GivensRotation<T> = rot;
+                rot.rotate(&mut h.fixed_view_mut::<2, 1>(i, k));
+            }
+
+            let hk = Vector2::new(h[(k, k)].clone(), h[(k + 1, k)].clone());
+            let rot = match GivensRotation::cancel_y(&hk) {
+                Some((rot, norm)) => {
+                    h[(k, k)] = norm;
+                    h[(k + 1, k)] = T::zero();
+                    rot
+                }
+                None => GivensRotation::identity(),
+            };
+            rot.rotate(&mut g.fixed_rows_mut::<2>(k));
+            rotations.push(rot);
+
+            estimate = g[k + 1].clone().modulus();
+            history.push(estimate.clone());
+            k += 1;
+
+            if estimate <= threshold
+                || w_norm.is_zero()
+                || k == m
+                || limit_reached(niter, max_niter)
+            {
+                break;
+            }
+
+            basis.column_mut(k).copy_from(&w.unscale(w_norm));
+        }
+
+        // Solve the triangular least-squares problem, and update the solution.
+        let mut y = g.rows(0, k).into_owned();
+        if !h.view((0, 0), (k, k)).solve_upper_triangular_mut(&mut y) {
+            // The Hessenberg matrix is singular: `A` is singular along the Krylov subspace.
+            return finish(a, b, x, false, niter, history);
+        }
+
+        v.gemv(T::one(), &basis.columns(0, k), &y, T::zero());
+        preconditioner.apply(&v, &mut z);
+        x += &z;
+    }
+}
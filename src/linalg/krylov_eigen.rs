@@ -0,0 +1,752 @@
+//! Iterative eigensolvers computing a few eigenpairs of large, possibly matrix-free, operators.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::Zero;
+use num_complex::Complex;
+use simba::scalar::{ComplexField, RealField};
+use std::cmp::Ordering;
+
+use crate::base::{DMatrix, DVector, Unit};
+use crate::geometry::Reflection;
+use crate::linalg::{householder, LinearOperator, Schur, SymmetricEigen};
+
+/// Selects the eigenvalues computed by [`lanczos`] and [`arnoldi`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EigenvalueSelection {
+    /// The eigenvalues with the largest modulus.
+    LargestMagnitude,
+    /// The eigenvalues with the smallest modulus.
+    SmallestMagnitude,
+    /// The eigenvalues with the largest real part.
+    LargestReal,
+    /// The eigenvalues with the smallest real part.
+    SmallestReal,
+}
+
+impl EigenvalueSelection {
+    /// The sort key of an eigenvalue: the most wanted eigenvalues have the smallest keys.
+    fn key<T: RealField>(self, eigenvalue: &Complex<T>) -> T {
+        match self {
+            Self::LargestMagnitude => -eigenvalue.clone().modulus(),
+            Self::SmallestMagnitude => eigenvalue.clone().modulus(),
+            Self::LargestReal => -eigenvalue.re.clone(),
+            Self::SmallestReal => eigenvalue.re.clone(),
+        }
+    }
+
+    /// The indices of the given eigenvalues, from the most wanted to the least wanted.
+    ///
+    /// Pairs of complex conjugate eigenvalues are adjacent, with the positive imaginary part
+    /// first.
+    fn sort<T: RealField>(self, eigenvalues: &[Complex<T>]) -> Vec<usize> {
+        let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        let keys: Vec<T> = eigenvalues.iter().map(|e| self.key(e)).collect();
+        let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+
+        order.sort_by(|&i, &j| {
+            let (ei, ej) = (&eigenvalues[i], &eigenvalues[j]);
+            cmp(&keys[i], &keys[j])
+                .then_with(|| cmp(&ej.im.clone().abs(), &ei.im.clone().abs()))
+                .then_with(|| cmp(&ei.re, &ej.re))
+                .then_with(|| cmp(&ej.im, &ei.im))
+        });
+
+        order
+    }
+}
+
+/// The parameters of the iterative eigensolvers [`lanczos`] and [`arnoldi`], and of their
+/// shift-invert variants.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct KrylovEigenOptions<T> {
+    /// The number of eigenpairs to compute.
+    pub nev: usize,
+    /// The eigenvalues to compute. This is ignored in shift-invert mode, where the eigenvalues
+    /// closest to the shift are computed.
+    pub which: EigenvalueSelection,
+    /// The maximum number of Krylov vectors, i.e., the dimension of the Krylov subspace. It must
+    /// satisfy `nev < ncv <= dim` for [`lanczos`] and `nev + 2 <= ncv <= dim` for [`arnoldi`],
+    /// where `dim` is the dimension of the operator. If `ncv == 0`, then
+    /// `min(max(2 * nev + 1, 20), dim)` is used.
+    pub ncv: usize,
+    /// The relative tolerance on the residual norm of each eigenpair.
+    pub tolerance: T,
+    /// The maximum number of restarts. If `max_niter == 0`, the iterations continue until
+    /// convergence.
+    pub max_niter: usize,
+}
+
+impl<T: RealField> KrylovEigenOptions<T> {
+    /// The options computing the `nev` eigenvalues with the largest magnitude, with the default
+    /// number of Krylov vectors, the machine epsilon as tolerance, and at most 300 restarts.
+    pub fn new(nev: usize) -> Self {
+        Self {
+            nev,
+            which: EigenvalueSelection::LargestMagnitude,
+            ncv: 0,
+            tolerance: T::default_epsilon(),
+            max_niter: 300,
+        }
+    }
+
+    /// The number of Krylov vectors used for an operator of dimension `dim`.
+    fn ncv(&self, dim: usize) -> usize {
+        if self.ncv == 0 {
+            (2 * self.nev + 1).max(20).min(dim)
+        } else {
+            self.ncv
+        }
+    }
+}
+
+/// A few eigenpairs of a Hermitian operator, computed by [`lanczos`] or
+/// [`lanczos_shift_invert`].
+#[derive(Clone, Debug)]
+pub struct LanczosEigen<T: ComplexField> {
+    /// The computed eigenvalues, from the most wanted to the least wanted.
+    pub eigenvalues: DVector<T::RealField>,
+    /// The orthonormal eigenvectors, stored as the columns of this matrix.
+    pub eigenvectors: DMatrix<T>,
+    /// The estimates of the residual norms `‖A * v - θ * v‖` of each eigenpair `(θ, v)` of the
+    /// operator `A` used during the iterations.
+    pub residual_norms: DVector<T::RealField>,
+    /// Whether all the eigenpairs converged before the maximum number of restarts.
+    pub converged: bool,
+    /// The number of restarts performed.
+    pub niter: usize,
+}
+
+/// A few eigenpairs of a real non-symmetric operator, computed by [`arnoldi`] or
+/// [`arnoldi_shift_invert`].
+#[derive(Clone, Debug)]
+pub struct ArnoldiEigen<T: RealField> {
+    /// The computed eigenvalues, from the most wanted to the least wanted.
+    pub eigenvalues: DVector<Complex<T>>,
+    /// The unit eigenvectors, stored as the columns of this matrix.
+    pub eigenvectors: DMatrix<Complex<T>>,
+    /// The estimates of the residual norms `‖A * v - θ * v‖` of each eigenpair `(θ, v)` of the
+    /// operator `A` used during the iterations.
+    pub residual_norms: DVector<T>,
+    /// Whether all the eigenpairs converged before the maximum number of restarts.
+    pub converged: bool,
+    /// The number of restarts performed.
+    pub niter: usize,
+}
+
+/// A deterministic pseudo-random vector with entries in `[-0.5, 0.5)`, generated with SplitMix64.
+fn random_vector<T: ComplexField>(n: usize, seed: u64) -> DVector<T> {
+    DVector::from_fn(n, |i, _| {
+        let mut z = seed
+            .wrapping_mul(n as u64 + 1)
+            .wrapping_add(i as u64 + 1)
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        crate::convert((z >> 11) as f64 / (1u64 << 53) as f64 - 0.5)
+    })
+}
+
+/// Orthogonalizes `w` against the first `k` columns of `basis`, and returns the projection
+/// coefficients and whether `w` is numerically independent from these columns.
+///
+/// This uses iterated classical Gram-Schmidt, with the criterion of Daniel, Gragg, Kaufman, and
+/// Stewart deciding whether a second pass is needed.
+fn orthogonalize<T: ComplexField>(
+    basis: &DMatrix<T>,
+    k: usize,
+    w: &mut DVector<T>,
+) -> (DVector<T>, bool) {
+    let kappa: T::RealField = crate::convert(0.717);
+    let v = basis.columns(0, k);
+    let mut coeffs = DVector::zeros(k);
+    let mut norm = w.norm();
+
+    for _ in 0..2 {
+        let c = v.ad_mul(&*w);
+        w.gemv(-T::one(), &v, &c, T::one());
+        coeffs += c;
+
+        let new_norm = w.norm();
+        if new_norm > kappa.clone() * norm {
+            return (coeffs, true);
+        }
+        norm = new_norm;
+    }
+
+    (coeffs, false)
+}
+
+/// A unit vector orthogonal to the first `k` columns of `basis`, or zero if these columns span
+/// the whole space.
+fn new_direction<T: ComplexField>(basis: &DMatrix<T>, k: usize, seed: &mut u64) -> DVector<T> {
+    for _ in 0..3 {
+        *seed += 1;
+        let mut w = random_vector(basis.nrows(), *seed);
+
+        if orthogonalize(basis, k, &mut w).1 {
+            let norm = w.norm();
+            return w.unscale(norm);
+        }
+    }
+
+    DVector::zeros(basis.nrows())
+}
+
+/// Sets the column `k` of the Arnoldi basis to the normalized residual `f`, orthogonalized against
+/// the previous columns, or to a new direction if `f` is numerically zero.
+fn set_residual<T: ComplexField>(
+    basis: &mut DMatrix<T>,
+    h: &mut DMatrix<T>,
+    k: usize,
+    mut f: DVector<T>,
+    seed: &mut u64,
+) {
+    if orthogonalize(basis, k, &mut f).1 {
+        let norm = f.norm();
+        h[(k, k - 1)] = T::from_real(norm.clone());
+        basis.set_column(k, &f.unscale(norm));
+    } else {
+        // The basis spans an invariant subspace.
+        h[(k, k - 1)] = T::zero();
+        basis.set_column(k, &new_direction(basis, k, seed));
+    }
+}
+
+/// Extends the Arnoldi factorization `A * V = V * H + f * eₖᵀ` from `k` to `m` columns, where
+/// `m = h.ncols()`.
+///
+/// The factorization is stored as `A * basis[.., ..k] = basis[.., ..k + 1] * h[..k + 1, ..k]`.
+fn arnoldi_extend<T, A>(a: &A, basis: &mut DMatrix<T>, h: &mut DMatrix<T>, k: usize, seed: &mut u64)
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let n = basis.nrows();
+    let mut v = DVector::zeros(n);
+    let mut w = DVector::zeros(n);
+
+    for j in k..h.ncols() {
+        v.copy_from(&basis.column(j));
+        a.apply(&v, &mut w);
+
+        let coeffs = orthogonalize(basis, j + 1, &mut w).0;
+        h.column_mut(j).rows_mut(0, j + 1).copy_from(&coeffs);
+        set_residual(basis, h, j + 1, w.clone(), seed);
+    }
+}
+
+/// Checks the dimensions given to the iterative eigensolvers.
+fn check_dimensions(
+    solver: &str,
+    nrows: usize,
+    dim: usize,
+    nev: usize,
+    ncv: usize,
+    min_gap: usize,
+) {
+    assert!(nrows == dim, "{}: the operator must be square.", solver);
+    assert!(
+        nev > 0 && nev + min_gap <= ncv && ncv <= dim,
+        "{}: the dimensions must satisfy 0 < nev, nev + {} <= ncv <= dim.",
+        solver,
+        min_gap
+    );
+}
+
+/// Whether the residual norm of a Ritz pair is small enough, relatively to its Ritz value, or to
+/// the rounding errors of the projected matrix for Ritz values close to zero.
+fn ritz_converged<T: RealField>(
+    residual: &T,
+    eigenvalue_norm: T,
+    projected_norm: T,
+    tolerance: T,
+) -> bool {
+    *residual <= (tolerance * eigenvalue_norm).max(T::default_epsilon() * projected_norm)
+}
+
+/// Computes a few eigenpairs of a Hermitian operator with the thick-restart Lanczos method.
+///
+/// This is mathematically equivalent to the implicitly restarted Lanczos method. The Lanczos
+/// vectors are fully reorthogonalized. The eigenvalues are sorted from the most wanted to the
+/// least wanted.
+///
+/// To compute the eigenvalues closest to a given value, or the smallest eigenvalues of a
+/// positive-semidefinite operator, [`lanczos_shift_invert`] usually converges much faster.
+///
+/// See [`KrylovEigenOptions`] for the description of the parameters. The number of Lanczos
+/// vectors `ncv >= 2 * nev` is usually a good choice.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::linalg::{lanczos, EigenvalueSelection, KrylovEigenOptions};
+/// let a = DMatrix::from_fn(100, 100, |i, j| 1.0 / (1.0 + i as f64 + j as f64));
+/// let options = KrylovEigenOptions {
+///     which: EigenvalueSelection::LargestReal,
+///     tolerance: 1.0e-10,
+///     ..KrylovEigenOptions::new(3)
+/// };
+/// let eigen = lanczos(&a, options);
+/// assert!(eigen.converged);
+///
+/// let (lambda, v) = (eigen.eigenvalues[0], eigen.eigenvectors.column(0));
+/// assert_relative_eq!(&a * v, v * lambda, epsilon = 1.0e-8);
+/// ```
+pub fn lanczos<T, A>(a: &A, options: KrylovEigenOptions<T::RealField>) -> LanczosEigen<T>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let dim = a.ncols();
+    let m = options.ncv(dim);
+    let KrylovEigenOptions {
+        nev,
+        which,
+        tolerance,
+        max_niter,
+        ..
+    } = options;
+    check_dimensions("Lanczos", a.nrows(), dim, nev, m, 1);
+
+    let mut seed = 0;
+    let mut basis = DMatrix::zeros(dim, m + 1);
+    let mut h = DMatrix::zeros(m + 1, m);
+    basis.set_column(0, &new_direction(&basis, 0, &mut seed));
+
+    let mut k = 0;
+    let mut niter = 0;
+
+    loop {
+        arnoldi_extend(a, &mut basis, &mut h, k, &mut seed);
+
+        // The projected matrix is Hermitian up to rounding errors.
+        let projected = h.rows(0, m);
+        let eigen =
+            SymmetricEigen::new((&projected + projected.adjoint()).unscale(crate::convert(2.0)));
+        let ritz_values: Vec<_> = eigen
+            .eigenvalues
+            .iter()
+            .map(|e| Complex::new(e.clone(), T::RealField::zero()))
+            .collect();
+        let order = which.sort(&ritz_values);
+        let beta = h[(m, m - 1)].clone();
+        let h_norm = projected.norm();
+
+        let residuals: Vec<_> = order
+            .iter()
+            .map(|&i| (beta.clone() * eigen.eigenvectors[(m - 1, i)].clone()).modulus())
+            .collect();
+        let converged = (0..nev).all(|i| {
+            let theta = eigen.eigenvalues[order[i]].clone();
+            ritz_converged(
+                &residuals[i],
+                theta.abs(),
+                h_norm.clone(),
+                tolerance.clone(),
+            )
+        });
+
+        if converged || (max_niter != 0 && niter >= max_niter) {
+            let ritz_vectors = eigen.eigenvectors.select_columns(&order[..nev]);
+
+            return LanczosEigen {
+                eigenvalues: DVector::from_fn(nev, |i, _| eigen.eigenvalues[order[i]].clone()),
+                eigenvectors: basis.columns(0, m) * ritz_vectors,
+                residual_norms: DVector::from_fn(nev, |i, _| residuals[i].clone()),
+                converged,
+                niter,
+            };
+        }
+
+        // Restart with the `k` most wanted Ritz vectors, which satisfy
+        // `A * V * S = V * S * Θ + f * (eₘᵀ * S)`.
+        k = (nev + m) / 2;
+        let ritz_vectors = eigen.eigenvectors.select_columns(&order[..k]);
+        let kept = basis.columns(0, m) * &ritz_vectors;
+        let f = basis.column(m).into_owned();
+
+        basis.columns_mut(0, k).copy_from(&kept);
+        basis.set_column(k, &f);
+        h.fill(T::zero());
+
+        for i in 0..k {
+            h[(i, i)] = T::from_real(eigen.eigenvalues[order[i]].clone());
+            h[(k, i)] = beta.clone() * ritz_vectors[(m - 1, i)].clone();
+        }
+
+        niter += 1;
+    }
+}
+
+/// Computes the eigenpairs of a Hermitian operator `A` closest to `sigma`, with the thick-restart
+/// Lanczos method in shift-invert mode.
+///
+/// The Lanczos iterations compute the largest eigenvalues `θ` of `(A - sigma * I)⁻¹`, which are
+/// mapped to the eigenvalues `sigma + 1 / θ` of `A`. The operator `inverse` must apply
+/// `(A - sigma * I)⁻¹`, and is typically a closure wrapped in a
+/// [`FnOperator`](crate::linalg::FnOperator), which solves a linear system with any factorization
+/// of `A - sigma * I`, e.g., [`Cholesky`](crate::linalg::Cholesky), [`LU`](crate::linalg::LU), or
+/// [`BandedLU`](crate::linalg::BandedLU). The residual norms are those of the eigenpairs of
+/// `inverse`.
+///
+/// See [`KrylovEigenOptions`] for the description of the other parameters.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{DMatrix, DVector};
+/// # use nalgebra::linalg::{lanczos_shift_invert, FnOperator, KrylovEigenOptions};
+/// // The path graph Laplacian, whose smallest eigenvalue is zero.
+/// let n = 200;
+/// let laplacian = DMatrix::from_fn(n, n, |i, j| match (i, j) {
+///     _ if i == j && (i == 0 || i == n - 1) => 1.0,
+///     _ if i == j => 2.0,
+///     _ if i.abs_diff(j) == 1 => -1.0,
+///     _ => 0.0,
+/// });
+///
+/// let sigma = -1.0e-3;
+/// let shifted = &laplacian - DMatrix::identity(n, n) * sigma;
+/// let cholesky = shifted.cholesky().unwrap();
+/// let inverse = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+///     y.copy_from(x);
+///     cholesky.solve_mut(y);
+/// });
+///
+/// let options = KrylovEigenOptions {
+///     ncv: 12,
+///     tolerance: 1.0e-10,
+///     ..KrylovEigenOptions::new(4)
+/// };
+/// let eigen = lanczos_shift_invert(&inverse, sigma, options);
+/// assert!(eigen.converged);
+/// assert_relative_eq!(eigen.eigenvalues[0], 0.0, epsilon = 1.0e-8);
+///
+/// let (lambda, v) = (eigen.eigenvalues[3], eigen.eigenvectors.column(3));
+/// assert_relative_eq!(&laplacian * v, v * lambda, epsilon = 1.0e-8);
+/// ```
+pub fn lanczos_shift_invert<T, A>(
+    inverse: &A,
+    sigma: T::RealField,
+    options: KrylovEigenOptions<T::RealField>,
+) -> LanczosEigen<T>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let options = KrylovEigenOptions {
+        which: EigenvalueSelection::LargestMagnitude,
+        ..options
+    };
+    let mut eigen = lanczos(inverse, options);
+    eigen
+        .eigenvalues
+        .apply(|e| *e = sigma.clone() + e.clone().recip());
+    eigen
+}
+
+/// The unit eigenvector of the square matrix `h` associated to its eigenvalue `lambda`, computed
+/// with inverse iteration.
+fn ritz_vector<T: RealField>(h: &DMatrix<T>, lambda: &Complex<T>) -> DVector<Complex<T>> {
+    let m = h.nrows();
+    // Perturb the eigenvalue so that the shifted matrix is numerically invertible.
+    let perturbation = T::default_epsilon() * h.norm().max(T::one());
+    let shift = lambda.clone() + Complex::new(perturbation, T::zero());
+    let lu = DMatrix::from_fn(m, m, |i, j| {
+        let hij = Complex::new(h[(i, j)].clone(), T::zero());
+        if i == j {
+            hij - shift.clone()
+        } else {
+            hij
+        }
+    })
+    .lu();
+
+    let mut y: DVector<Complex<T>> = random_vector(m, 0);
+    let _ = y.normalize_mut();
+
+    for _ in 0..2 {
+        let mut z = y.clone();
+        if !lu.solve_mut(&mut z) || z.iter().any(|e| !e.clone().is_finite()) {
+            break;
+        }
+        let norm = z.norm();
+        y = z.unscale(norm);
+    }
+
+    y
+}
+
+/// Applies an implicit QR step with the shift `mu` to the Hessenberg matrix `h`, and accumulates
+/// the orthogonal transformation into `q`.
+///
+/// A real shift is applied with a single-shift step. A complex shift is applied together with its
+/// complex conjugate with a Francis double-shift step, so that all computations remain real. The
+/// bulge is chased with Householder reflections, and `h` remains exactly upper Hessenberg.
+fn implicit_qr_step<T: RealField>(h: &mut DMatrix<T>, q: &mut DMatrix<T>, mu: &Complex<T>) {
+    let n = h.nrows();
+    if n < 2 {
+        return;
+    }
+
+    let h00 = h[(0, 0)].clone();
+    let h10 = h[(1, 0)].clone();
+
+    // The first column of `H - μ * I`, or of `(H - μ * I) * (H - conj(μ) * I)` for a double shift.
+    let mut axis = if mu.im.is_zero() {
+        DVector::from_column_slice(&[h00 - mu.re.clone(), h10])
+    } else {
+        let h01 = h[(0, 1)].clone();
+        let h11 = h[(1, 1)].clone();
+        let h21 = if n > 2 { h[(2, 1)].clone() } else { T::zero() };
+        let tra = mu.re.clone() * crate::convert(2.0);
+        let det = mu.clone().modulus_squared();
+        let col = [
+            h00.clone() * h00.clone() + h01 * h10.clone() - tra.clone() * h00.clone() + det,
+            h10.clone() * (h00 + h11 - tra),
+            h10 * h21,
+        ];
+        DVector::from_column_slice(&col[..n.min(3)])
+    };
+
+    let size = axis.len();
+    let mut work = DVector::zeros(n);
+
+    for k in 0..n - 1 {
+        let rows = size.min(n - k);
+
+        if k > 0 {
+            // The bulge created by the previous reflection.
+            axis = DVector::from_fn(rows, |i, _| h[(k + i, k - 1)].clone());
+        }
+
+        let (norm, not_zero) = householder::reflection_axis_mut(&mut axis);
+
+        if not_zero {
+            if k > 0 {
+                h[(k, k - 1)] = norm;
+                h.view_mut((k + 1, k - 1), (rows - 1, 1)).fill(T::zero());
+            }
+
+            let refl = Reflection::new(Unit::new_unchecked(axis.clone()), T::zero());
+            let last = (k + rows + 1).min(n);
+
+            refl.reflect(&mut h.view_mut((k, k), (rows, n - k)));
+            refl.reflect_rows(
+                &mut h.view_mut((0, k), (last, rows)),
+                &mut work.rows_mut(0, last),
+            );
+            refl.reflect_rows(&mut q.view_mut((0, k), (n, rows)), &mut work);
+        }
+    }
+}
+
+/// Computes a few eigenpairs of a real non-symmetric operator with the implicitly restarted
+/// Arnoldi method.
+///
+/// The restarts use the unwanted Ritz values as exact shifts, with double shifts for pairs of
+/// complex conjugate Ritz values so that all computations remain real. The Arnoldi vectors are
+/// fully reorthogonalized. The eigenvalues are sorted from the most wanted to the least wanted,
+/// and pairs of complex conjugate eigenvalues are adjacent.
+///
+/// To compute the eigenvalues closest to a given value, [`arnoldi_shift_invert`] usually converges
+/// much faster.
+///
+/// See [`KrylovEigenOptions`] for the description of the parameters. The number of Arnoldi
+/// vectors `ncv >= 2 * nev + 1` is usually a good choice.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Complex, DMatrix};
+/// # use nalgebra::linalg::{arnoldi, KrylovEigenOptions};
+/// let a = DMatrix::from_fn(100, 100, |i, j| match j as isize - i as isize {
+///     0 => i as f64,
+///     1 => 2.0,
+///     -1 => -3.0,
+///     _ => 0.0,
+/// });
+/// let options = KrylovEigenOptions {
+///     tolerance: 1.0e-10,
+///     ..KrylovEigenOptions::new(2)
+/// };
+/// let eigen = arnoldi(&a, options);
+/// assert!(eigen.converged);
+///
+/// let (lambda, v) = (eigen.eigenvalues[0], eigen.eigenvectors.column(0));
+/// let av = a.map(Complex::from) * v;
+/// assert_relative_eq!(av, v * lambda, epsilon = 1.0e-7);
+/// ```
+pub fn arnoldi<T, A>(a: &A, options: KrylovEigenOptions<T>) -> ArnoldiEigen<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let dim = a.ncols();
+    let m = options.ncv(dim);
+    let KrylovEigenOptions {
+        nev,
+        which,
+        tolerance,
+        max_niter,
+        ..
+    } = options;
+    check_dimensions("Arnoldi", a.nrows(), dim, nev, m, 2);
+
+    let mut seed = 0;
+    let mut basis = DMatrix::zeros(dim, m + 1);
+    let mut h = DMatrix::zeros(m + 1, m);
+    basis.set_column(0, &new_direction(&basis, 0, &mut seed));
+
+    let mut k = 0;
+    let mut niter = 0;
+
+    loop {
+        arnoldi_extend(a, &mut basis, &mut h, k, &mut seed);
+
+        let projected = h.rows(0, m).into_owned();
+        let ritz_values = Schur::new(projected.clone()).complex_eigenvalues();
+        let order = which.sort(ritz_values.as_slice());
+        let beta = h[(m, m - 1)].clone();
+        let h_norm = projected.norm();
+
+        let ritz_vectors: Vec<_> = order[..nev]
+            .iter()
+            .map(|&i| ritz_vector(&projected, &ritz_values[i]))
+            .collect();
+        let residuals: Vec<_> = ritz_vectors
+            .iter()
+            .map(|y| beta.clone() * y[m - 1].clone().modulus())
+            .collect();
+        let converged = (0..nev).all(|i| {
+            let theta = ritz_values[order[i]].clone();
+            ritz_converged(
+                &residuals[i],
+                theta.modulus(),
+                h_norm.clone(),
+                tolerance.clone(),
+            )
+        });
+
+        if converged || (max_niter != 0 && niter >= max_niter) {
+            // Compute `V * Y` with a real matrix `V` and a complex matrix `Y`.
+            let y = DMatrix::from_fn(m, nev, |i, j| ritz_vectors[j][i].clone());
+            let re = basis.columns(0, m) * y.map(|e| e.re);
+            let im = basis.columns(0, m) * y.map(|e| e.im);
+
+            return ArnoldiEigen {
+                eigenvalues: DVector::from_fn(nev, |i, _| ritz_values[order[i]].clone()),
+                eigenvectors: re.zip_map(&im, Complex::new),
+                residual_norms: DVector::from_vec(residuals),
+                converged,
+                niter,
+            };
+        }
+
+        // Keep the `k` most wanted Ritz values, without splitting pairs of complex conjugate Ritz
+        // values between the kept and the shifted ones.
+        k = (nev + m) / 2;
+        if ritz_values[order[k - 1]].im > T::zero() {
+            if k + 1 < m {
+                k += 1;
+            } else {
+                k -= 1;
+            }
+        }
+
+        // Apply the unwanted Ritz values as exact shifts with implicit QR steps on the Hessenberg
+        // matrix. Pairs of complex conjugate shifts are applied together with a double shift.
+        let mut hq = projected;
+        let mut q = DMatrix::identity(m, m);
+
+        for &i in &order[k..] {
+            if ritz_values[i].im >= T::zero() {
+                implicit_qr_step(&mut hq, &mut q, &ritz_values[i]);
+            }
+        }
+
+        // The updated factorization is `A * V * Q[.., ..k] = V * Q[.., ..k] * H[..k, ..k] + f * eₖᵀ`
+        // with `f = V * Q[.., k] * H[k, k - 1] + v * β * Q[m - 1, k - 1]`.
+        let vq = basis.columns(0, m) * q.columns(0, k + 1);
+        let mut f = basis.column(m) * (beta * q[(m - 1, k - 1)].clone());
+        f.axpy(hq[(k, k - 1)].clone(), &vq.column(k), T::one());
+
+        basis.columns_mut(0, k).copy_from(&vq.columns(0, k));
+        h.fill(T::zero());
+
+        h.view_mut((0, 0), (k, k))
+            .copy_from(&hq.view((0, 0), (k, k)));
+
+        set_residual(&mut basis, &mut h, k, f, &mut seed);
+        niter += 1;
+    }
+}
+
+/// Computes the eigenpairs of a real non-symmetric operator `A` closest to `sigma`, with the
+/// implicitly restarted Arnoldi method in shift-invert mode.
+///
+/// The Arnoldi iterations compute the largest eigenvalues `θ` of `(A - sigma * I)⁻¹`, which are
+/// mapped to the eigenvalues `sigma + 1 / θ` of `A`. The operator `inverse` must apply
+/// `(A - sigma * I)⁻¹`, and is typically a closure wrapped in a
+/// [`FnOperator`](crate::linalg::FnOperator), which solves a linear system with any factorization
+/// of `A - sigma * I`, e.g., [`LU`](crate::linalg::LU) or [`BandedLU`](crate::linalg::BandedLU).
+/// The residual norms are those of the eigenpairs of `inverse`.
+///
+/// See [`KrylovEigenOptions`] for the description of the other parameters.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Complex, DMatrix, DVector};
+/// # use nalgebra::linalg::{arnoldi_shift_invert, FnOperator, KrylovEigenOptions};
+/// let n = 100;
+/// let a = DMatrix::from_fn(n, n, |i, j| match j as isize - i as isize {
+///     0 => i as f64,
+///     1 => 2.0,
+///     -1 => -3.0,
+///     _ => 0.0,
+/// });
+///
+/// let sigma = 50.2;
+/// let lu = (&a - DMatrix::identity(n, n) * sigma).lu();
+/// let inverse = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+///     y.copy_from(x);
+///     assert!(lu.solve_mut(y));
+/// });
+///
+/// let options = KrylovEigenOptions {
+///     ncv: 12,
+///     tolerance: 1.0e-10,
+///     ..KrylovEigenOptions::new(3)
+/// };
+/// let eigen = arnoldi_shift_invert(&inverse, sigma, options);
+/// assert!(eigen.converged);
+///
+/// let (lambda, v) = (eigen.eigenvalues[0], eigen.eigenvectors.column(0));
+/// let av = a.map(Complex::from) * v;
+/// assert_relative_eq!(av, v * lambda, epsilon = 1.0e-7);
+/// ```
+pub fn arnoldi_shift_invert<T, A>(
+    inverse: &A,
+    sigma: T,
+    options: KrylovEigenOptions<T>,
+) -> ArnoldiEigen<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let options = KrylovEigenOptions {
+        which: EigenvalueSelection::LargestMagnitude,
+        ..options
+    };
+    let mut eigen = arnoldi(inverse, options);
+    let sigma = Complex::new(sigma, T::zero());
+    eigen
+        .eigenvalues
+        .apply(|e| *e = sigma.clone() + e.clone().inv());
+    eigen
+}
@@ -0,0 +1,407 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::PermutationSequence;
+
+/// LDLᵀ decomposition of a symmetric, possibly indefinite, matrix with Bunch-Kaufman pivoting.
+///
+/// This computes `P * A * Pᵀ = L * D * Lᵀ` where `P` is a permutation matrix, `L` is
+/// lower-triangular with a diagonal filled with `1`, and `D` is block-diagonal with 1x1 and
+/// 2x2 symmetric blocks.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LDLT<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    // The strictly lower-triangular part contains `L`, the diagonal contains the diagonal of
+    // `D`, and the entry `(k, k + 1)` contains the off-diagonal element of the 2x2 block of `D`
+    // starting at `k` (and zero if there is none).
+    ldlt: OMatrix<T, D, D>,
+    p: PermutationSequence<D>,
+}
+
+impl<T: RealField, D: Dim> Copy for LDLT<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+    OMatrix<T, D, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<T: RealField, D: Dim> LDLT<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of `matrix`.
+    ///
+    /// The input matrix is assumed to be symmetric and only its lower-triangular part is read.
+    /// The decomposition succeeds even if `matrix` is singular.
+    pub fn new(mut matrix: OMatrix<T, D, D>) -> Self {
+        assert!(
+            matrix.is_square(),
+            "LDLT decomposition: unable to decompose a non-square matrix."
+        );
+
+        let dim = matrix.nrows();
+        let mut p = PermutationSequence::identity_generic(matrix.shape_generic().0);
+
+        // The trailing submatrix is kept fully symmetric so that pivoting is a plain
+        // exchange of rows and columns.
+        matrix.fill_upper_triangle_with_lower_triangle();
+
+        // Bunch-Kaufman pivoting constant: (1 + √17) / 8.
+        let alpha = (T::one() + crate::convert::<f64, T>(17.0).sqrt()) / crate::convert(8.0);
+
+        let mut k = 0;
+        while k < dim {
+            let absakk = matrix[(k, k)].clone().abs();
+            let (imax, colmax) = if k + 1 < dim {
+                let imax = matrix.view_range(k + 1.., k).iamax() + k + 1;
+                (imax, matrix[(imax, k)].clone().abs())
+            } else {
+                (k, T::zero())
+            };
+
+            let (kp, kstep) =
+                if absakk >= alpha.clone() * colmax.clone() {
+                    (k, 1)
+                } else {
+                    let rowmax = matrix.view_range(imax, k..).iter().enumerate().fold(
+                        T::zero(),
+                        |m, (j, e)| {
+                            if j + k == imax {
+                                m
+                            } else {
+                                m.max(e.clone().abs())
+                            }
+                        },
+                    );
+
+                    if absakk * rowmax.clone() >= alpha.clone() * colmax.clone() * colmax {
+                        (k, 1)
+                    } else if matrix[(imax, imax)].clone().abs() >= alpha.clone() * rowmax {
+                        (imax, 1)
+                    } else {
+                        (imax, 2)
+                    }
+                };
+
+            let kk = k + kstep - 1;
+            if kp != kk {
+                p.append_permutation(kk, kp);
+                matrix.swap_rows(kk, kp);
+                matrix.swap_columns(kk, kp);
+            }
+
+            if kstep == 1 {
+                let d = matrix[(k, k)].clone();
+
+                if !d.is_zero() {
+                    for j in k + 1..dim {
+                        let wj = matrix[(j, k)].clone() / d.clone();
+                        for i in k + 1..dim {
+                            let lik = matrix[(i, k)].clone();
+                            matrix[(i, j)] -= lik * wj.clone();
+                        }
+                    }
+
+                    for i in k + 1..dim {
+                        matrix[(i, k)] /= d.clone();
+                    }
+                }
+
+                matrix.view_range_mut(k, k + 1..).fill(T::zero());
+            } else {
+                let d11 = matrix[(k, k)].clone();
+                let d21 = matrix[(k + 1, k)].clone();
+                let d22 = matrix[(k + 1, k + 1)].clone();
+                let det = d11.clone() * d22.clone() - d21.clone() * d21.clone();
+
+                // Columns `k` and `k + 1` of `L` are obtained by multiplying the rows below the
+                // block by its inverse.
+                for i in k + 2..dim {
+                    let a = matrix[(i, k)].clone();
+                    let b = matrix[(i, k + 1)].clone();
+                    let w0 = (a.clone() * d22.clone() - b.clone() * d21.clone()) / det.clone();
+                    let w1 = (b.clone() * d11.clone() - a.clone() * d21.clone()) / det.clone();
+
+                    // Only the lower part is updated here, because the rows above `i` of
+                    // the columns `k` and `k + 1` already contain `L`.
+                    for j in i..dim {
+                        let aj = matrix[(j, k)].clone();
+                        let bj = matrix[(j, k + 1)].clone();
+                        matrix[(j, i)] -= aj * w0.clone() + bj * w1.clone();
+                    }
+
+                    matrix[(i, k)] = w0;
+                    matrix[(i, k + 1)] = w1;
+                }
+
+                for i in k + 2..dim {
+                    for j in i + 1..dim {
+                        matrix[(i, j)] = matrix[(j, i)].clone();
+                    }
+                }
+
+                matrix.view_range_mut(k..k + 2, k + 2..).fill(T::zero());
+                matrix[(k + 1, k)] = T::zero();
+                matrix[(k, k + 1)] = d21;
+            }
+
+            k += kstep;
+        }
+
+        LDLT { ldlt: matrix, p }
+    }
+
+    /// Returns `true` if a 2x2 block of `D` starts at the index `k`.
+    fn is_block2(&self, k: usize) -> bool {
+        k + 1 < self.ldlt.nrows() && !self.ldlt[(k, k + 1)].is_zero()
+    }
+
+    /// The lower-triangular matrix `L` with a unit diagonal of this decomposition.
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D> {
+        let mut l = self.ldlt.lower_triangle();
+        l.fill_diagonal(T::one());
+        l
+    }
+
+    /// The block-diagonal matrix `D` of this decomposition.
+    #[must_use]
+    pub fn d(&self) -> OMatrix<T, D, D> {
+        let (nrows, ncols) = self.ldlt.shape_generic();
+        let mut d = OMatrix::zeros_generic(nrows, ncols);
+
+        for k in 0..self.ldlt.nrows() {
+            d[(k, k)] = self.ldlt[(k, k)].clone();
+
+            if self.is_block2(k) {
+                d[(k, k + 1)] = self.ldlt[(k, k + 1)].clone();
+                d[(k + 1, k)] = self.ldlt[(k, k + 1)].clone();
+            }
+        }
+
+        d
+    }
+
+    /// The symmetric permutations `P` of this decomposition.
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// The permutations and factors of this decomposition: `(P, L, D)`.
+    pub fn unpack(self) -> (PermutationSequence<D>, OMatrix<T, D, D>, OMatrix<T, D, D>) {
+        let l = self.l();
+        let d = self.d();
+        (self.p, l, d)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.ldlt.nrows(),
+            b.nrows(),
+            "LDLT solve matrix dimension mismatch."
+        );
+
+        let dim = self.ldlt.nrows();
+
+        self.p.permute_rows(b);
+        let _ = self.ldlt.solve_lower_triangular_with_diag_mut(b, T::one());
+
+        // Solve with the block-diagonal factor.
+        let mut k = 0;
+        while k < dim {
+            if self.is_block2(k) {
+                let d11 = self.ldlt[(k, k)].clone();
+                let d21 = self.ldlt[(k, k + 1)].clone();
+                let d22 = self.ldlt[(k + 1, k + 1)].clone();
+                let det = d11.clone() * d22.clone() - d21.clone() * d21.clone();
+
+                if det.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    let b0 = b[(k, j)].clone();
+                    let b1 = b[(k + 1, j)].clone();
+                    b[(k, j)] = (b0.clone() * d22.clone() - b1.clone() * d21.clone()) / det.clone();
+                    b[(k + 1, j)] = (b1 * d11.clone() - b0 * d21.clone()) / det.clone();
+                }
+
+                k += 2;
+            } else {
+                let d = self.ldlt[(k, k)].clone();
+
+                if d.is_zero() {
+                    return false;
+                }
+
+                b.row_mut(k).unscale_mut(d);
+                k += 1;
+            }
+        }
+
+        // Solve with the transpose of the unit lower-triangular factor.
+        for j in 0..b.ncols() {
+            let mut bcol = b.column_mut(j);
+
+            for i in (0..dim.saturating_sub(1)).rev() {
+                let dot = self
+                    .ldlt
+                    .view_range(i + 1.., i)
+                    .dot(&bcol.rows_range(i + 1..));
+                bcol[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<OMatrix<T, D, D>> {
+        let (nrows, ncols) = self.ldlt.shape_generic();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let dim = self.ldlt.nrows();
+        let mut res = T::one();
+
+        let mut k = 0;
+        while k < dim {
+            if self.is_block2(k) {
+                let d21 = self.ldlt[(k, k + 1)].clone();
+                res *= self.ldlt[(k, k)].clone() * self.ldlt[(k + 1, k + 1)].clone()
+                    - d21.clone() * d21;
+                k += 2;
+            } else {
+                res *= self.ldlt[(k, k)].clone();
+                k += 1;
+            }
+        }
+
+        // The permutations are applied symmetrically, so they do not affect the sign.
+        res
+    }
+
+    /// The inertia of the decomposed matrix.
+    ///
+    /// Returns the number of positive, negative, and zero eigenvalues of the decomposed
+    /// matrix, in this order. By Sylvester's law of inertia, those are the same as the ones of
+    /// the block-diagonal factor `D`.
+    #[must_use]
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let dim = self.ldlt.nrows();
+        let (mut npos, mut nneg, mut nzero) = (0, 0, 0);
+        let mut count = |e: T| {
+            if e > T::zero() {
+                npos += 1;
+            } else if e < T::zero() {
+                nneg += 1;
+            } else {
+                nzero += 1;
+            }
+        };
+
+        let mut k = 0;
+        while k < dim {
+            if self.is_block2(k) {
+                let d11 = self.ldlt[(k, k)].clone();
+                let d21 = self.ldlt[(k, k + 1)].clone();
+                let d22 = self.ldlt[(k + 1, k + 1)].clone();
+                let det = d11.clone() * d22.clone() - d21.clone() * d21;
+
+                if det < T::zero() {
+                    // Eigenvalues of opposite signs.
+                    count(T::one());
+                    count(-T::one());
+                } else {
+                    // Eigenvalues of the same sign as the trace, one of them being zero if the
+                    // determinant is zero.
+                    let trace = d11 + d22;
+                    count(trace.clone());
+                    count(if det.is_zero() { T::zero() } else { trace });
+                }
+
+                k += 2;
+            } else {
+                count(self.ldlt[(k, k)].clone());
+                k += 1;
+            }
+        }
+
+        (npos, nneg, nzero)
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        !self.determinant().is_zero()
+    }
+}
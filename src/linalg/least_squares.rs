@@ -0,0 +1,91 @@
+use simba::scalar::ComplexField;
+use std::fmt;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::storage::Storage;
+
+/// The solution of the linear least-squares problem `min ‖self * x - b‖`.
+#[derive(Clone, Debug)]
+pub struct LeastSquaresSolution<T: ComplexField, C: Dim, C2: Dim>
+where
+    DefaultAllocator: Allocator<T, C, C2>,
+{
+    /// The solution `x` of the least-squares problem.
+    ///
+    /// Each column of `x` is the solution associated to the same column of `b`.
+    pub solution: OMatrix<T, C, C2>,
+    /// The norm of the residual `self * x - b`.
+    ///
+    /// This is the Frobenius norm if `b` has more than one column.
+    pub residual_norm: T::RealField,
+    /// The numerical rank of the decomposed matrix used to compute the solution.
+    pub rank: usize,
+}
+
+/// Possible errors produced by the linear least-squares solvers.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub enum LeastSquaresError {
+    /// The decomposed matrix does not have full column rank, which is not supported by the
+    /// solver.
+    RankDeficient,
+    /// The singular vectors `U` and `V` required by the solver have not been computed.
+    MissingSingularVectors,
+}
+
+impl fmt::Display for LeastSquaresError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeastSquaresError::RankDeficient => {
+                write!(f, "The decomposed matrix does not have full column rank")
+            }
+            LeastSquaresError::MissingSingularVectors => {
+                write!(f, "The singular vectors U and V have not been computed")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LeastSquaresError {}
+
+/// Solves `R₁₁ * y = (Qᴴ * b)₁` where `R₁₁` is the leading `rank x rank` block of the upper
+/// triangular factor of a householder-based QR decomposition, and `qtb` contains `Qᴴ * b`.
+///
+/// The diagonal elements of `R₁₁` must be non-zero. The remaining components of the solution
+/// are set to zero. Returns the solution and the norm of the residual.
+pub(crate) fn solve_householder_qr<T, R, C, R2, C2, S2>(
+    qr: &OMatrix<T, R, C>,
+    diag: &OVector<T, DimMinimum<R, C>>,
+    rank: usize,
+    qtb: &Matrix<T, R2, C2, S2>,
+) -> (OMatrix<T, C, C2>, T::RealField)
+where
+    T: ComplexField,
+    R: DimMin<C>,
+    C: Dim,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>> + Allocator<T, C, C2>,
+{
+    let mut x = OMatrix::zeros_generic(qr.shape_generic().1, qtb.shape_generic().1);
+    x.rows_mut(0, rank).copy_from(&qtb.rows(0, rank));
+
+    for k in 0..x.ncols() {
+        let mut x = x.column_mut(k);
+
+        for i in (0..rank).rev() {
+            let diag = diag[i].clone().modulus();
+            let coeff = x[i].clone().unscale(diag);
+            x[i] = coeff.clone();
+            x.rows_range_mut(..i)
+                .axpy(-coeff, &qr.view_range(..i, i), T::one());
+        }
+    }
+
+    let residual_norm = qtb.rows_range(rank..).norm();
+    (x, residual_norm)
+}
@@ -0,0 +1,189 @@
+//! This module provides the principal matrix logarithm (ln) function to square matrices.
+
+use num::Zero;
+use std::fmt;
+
+use crate::{
+    base::{
+        allocator::Allocator,
+        dimension::{Dim, DimDiff, DimMin, DimSub, U1},
+        DefaultAllocator,
+    },
+    convert,
+    linalg::schur::block_size_at,
+    linalg::sqrt::sqrt_quasi_triangular,
+    linalg::Schur,
+    ComplexField, OMatrix, RealField,
+};
+
+/// Abscissas and weights of the 8-point Gauss-Legendre quadrature rule on `[0, 1]`.
+///
+/// Applied to `log(I + X) = ∫₀¹ X (I + tX)⁻¹ dt`, they yield the diagonal `[8/8]` Padé approximant
+/// of the logarithm.
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019_855_071_751_231_856, 0.050_614_268_145_188_13),
+    (0.101_666_761_293_186_63, 0.111_190_517_226_687_24),
+    (0.237_233_795_041_835_5, 0.156_853_322_938_943_64),
+    (0.408_282_678_752_175_1, 0.181_341_891_689_181),
+    (0.591_717_321_247_825, 0.181_341_891_689_181),
+    (0.762_766_204_958_164_5, 0.156_853_322_938_943_64),
+    (0.898_333_238_706_813_4, 0.111_190_517_226_687_24),
+    (0.980_144_928_248_768_1, 0.050_614_268_145_188_13),
+];
+
+/// The maximum 1-norm of `T - I` for which the `[8/8]` Padé approximant is accurate to double
+/// precision.
+const PADE_8_THETA: f64 = 0.25;
+
+/// The maximum number of square roots taken before giving up.
+const MAX_SQRTS: usize = 64;
+
+/// Possible errors produced by the matrix logarithm, square root and real powers.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub enum MatrixFunctionError {
+    /// The matrix has a real eigenvalue that is negative, or a zero eigenvalue that the
+    /// principal matrix function does not support.
+    NonPositiveEigenvalue,
+    /// The inverse scaling and squaring iterations failed to bring the matrix close enough to
+    /// the identity.
+    NoConvergence,
+}
+
+impl fmt::Display for MatrixFunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixFunctionError::NonPositiveEigenvalue => {
+                write!(f, "Matrix has a non-positive real eigenvalue")
+            }
+            MatrixFunctionError::NoConvergence => {
+                write!(f, "Matrix logarithm failed to converge")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatrixFunctionError {}
+
+/// The 1-norm (maximum absolute column sum) of `m`.
+fn one_norm<T: ComplexField, D: Dim>(m: &OMatrix<T, D, D>) -> T::RealField
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    m.column_iter()
+        .map(|col| {
+            col.iter()
+                .fold(T::RealField::zero(), |a, b| a + b.clone().abs())
+        })
+        .fold(T::RealField::zero(), |a, b| a.max(b))
+}
+
+/// Computes the principal logarithm of the upper quasi-triangular matrix `t`, as output by the
+/// Schur decomposition.
+pub(crate) fn ln_quasi_triangular<T: ComplexField, D>(
+    mut t: OMatrix<T, D, D>,
+) -> Result<OMatrix<T, D, D>, MatrixFunctionError>
+where
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    let dim = t.nrows();
+    let (nrows, ncols) = t.shape_generic();
+
+    // Check that the principal logarithm exists.
+    let mut i = 0;
+    while i < dim {
+        let size = block_size_at(&t, i);
+
+        if size == 1 {
+            let eig = t[(i, i)].clone();
+
+            if eig.clone().imaginary().is_zero() && eig.real() <= T::RealField::zero() {
+                return Err(MatrixFunctionError::NonPositiveEigenvalue);
+            }
+        }
+
+        i += size;
+    }
+
+    // Inverse scaling: take square roots until `t` is close to the identity.
+    let ident = OMatrix::<T, D, D>::identity_generic(nrows, ncols);
+    let theta: T::RealField = convert(PADE_8_THETA);
+    let mut nsqrts = 0;
+
+    while one_norm(&(&t - &ident)) > theta {
+        if nsqrts == MAX_SQRTS {
+            return Err(MatrixFunctionError::NoConvergence);
+        }
+
+        t = sqrt_quasi_triangular(&t).ok_or(MatrixFunctionError::NonPositiveEigenvalue)?;
+        nsqrts += 1;
+    }
+
+    // Padé approximant of log(I + x), evaluated in partial fraction form.
+    let x = t - &ident;
+    let mut res = OMatrix::zeros_generic(nrows, ncols);
+
+    for (node, weight) in GAUSS_LEGENDRE_8.iter() {
+        let denom = &ident + &x * convert::<f64, T>(*node);
+        let term = denom
+            .lu()
+            .solve(&x)
+            .ok_or(MatrixFunctionError::NoConvergence)?;
+        res += term * convert::<f64, T>(*weight);
+    }
+
+    // Squaring: undo the square roots.
+    let mut scale = T::one();
+    for _ in 0..nsqrts {
+        scale *= convert::<f64, T>(2.0);
+    }
+
+    Ok(res * scale)
+}
+
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<(usize, usize), D>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D>,
+{
+    /// Computes the principal logarithm of this matrix.
+    ///
+    /// The principal logarithm is the unique logarithm whose eigenvalues have an imaginary part
+    /// lying in `]-π, π[`. It only exists if this matrix has no eigenvalue on the closed negative
+    /// real axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the logarithm cannot be computed. See [`Self::try_ln`] for a non-panicking
+    /// version.
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        self.try_ln()
+            .expect("Matrix logarithm: the matrix has no principal logarithm.")
+    }
+
+    /// Attempts to compute the principal logarithm of this matrix.
+    ///
+    /// This uses the inverse scaling and squaring method applied to the Schur form of this
+    /// matrix: square roots are taken until the triangular factor is close enough to the
+    /// identity, then a Padé approximant of `log(I + X)` is evaluated.
+    ///
+    /// Returns [`MatrixFunctionError::NonPositiveEigenvalue`] if this matrix has a real eigenvalue
+    /// that is negative or zero.
+    pub fn try_ln(&self) -> Result<Self, MatrixFunctionError> {
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let (q, t) = Schur::new(self.clone()).unpack();
+        let res = ln_quasi_triangular(t)?;
+
+        Ok(&q * res * q.adjoint())
+    }
+}
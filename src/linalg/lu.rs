@@ -2,13 +2,14 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, Vector};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::{ComplexField, Field};
 use std::mem;
 
+use crate::linalg::condition::estimate_rcond;
 use crate::linalg::PermutationSequence;
 
 /// LU decomposition with partial (row) pivoting.
@@ -91,33 +92,30 @@ where
     /// Computes the LU decomposition with partial (row) pivoting of `matrix`.
     pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
         let (nrows, ncols) = matrix.shape_generic();
-        let min_nrows_ncols = nrows.min(ncols);
+        let mut p = PermutationSequence::identity_generic(nrows.min(ncols));
 
-        let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
+        decompose(&mut matrix, &mut p);
 
-        if min_nrows_ncols.value() == 0 {
-            return LU { lu: matrix, p };
-        }
-
-        for i in 0..min_nrows_ncols.value() {
-            let piv = matrix.view_range(i.., i).icamax() + i;
-            let diag = matrix[(piv, i)].clone();
-
-            if diag.is_zero() {
-                // No non-zero entries on this column.
-                continue;
-            }
+        LU { lu: matrix, p }
+    }
 
-            if piv != i {
-                p.append_permutation(i, piv);
-                matrix.columns_range_mut(..i).swap_rows(i, piv);
-                gauss_step_swap(&mut matrix, diag, i, piv);
-            } else {
-                gauss_step(&mut matrix, diag, i);
-            }
-        }
+    /// Replaces this decomposition by the LU decomposition of `matrix`.
+    ///
+    /// This reuses the storage of `self` instead of allocating a new decomposition, which is
+    /// useful when many matrices of the same shape need to be factorized in a row.
+    ///
+    /// # Panics
+    /// Panics if `matrix` does not have the same shape as the decomposed matrix.
+    pub fn refactor<S2: Storage<T, R, C>>(&mut self, matrix: &Matrix<T, R, C, S2>) {
+        assert_eq!(
+            self.lu.shape(),
+            matrix.shape(),
+            "LU refactor: the matrix dimensions must match the decomposed matrix."
+        );
 
-        LU { lu: matrix, p }
+        self.lu.copy_from(matrix);
+        self.p.clear();
+        decompose(&mut self.lu, &mut self.p);
     }
 
     #[doc(hidden)]
@@ -312,6 +310,85 @@ where
         res * self.p.determinant()
     }
 
+    /// Given the LU decomposition of a matrix `M`, and two vectors `u` and `v`, performs a rank
+    /// one update such that we end up with the decomposition of `M + u * v.adjoint()`.
+    ///
+    /// This uses Bennett's algorithm which updates the factors in `O(n²)` operations while
+    /// keeping the row permutation of the original decomposition. Because no new pivoting is
+    /// performed, the update can be less accurate than a complete refactorization.
+    ///
+    /// Returns `false` if the updated matrix has a zero pivot with the current permutation. In
+    /// that case the decomposition is left in an invalid state and should be recomputed, e.g.,
+    /// with [`LU::refactor`].
+    ///
+    /// This copies `u` and `v` into temporary vectors. See [`Self::rank_one_update_mut`] for a
+    /// version that uses them as workspace instead.
+    pub fn rank_one_update<R2: Dim, S2, R3: Dim, S3>(
+        &mut self,
+        u: &Vector<T, R2, S2>,
+        v: &Vector<T, R3, S3>,
+    ) -> bool
+    where
+        S2: Storage<T, R2>,
+        S3: Storage<T, R3>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + SameNumberOfRows<R3, D>,
+        DefaultAllocator: Allocator<T, R2> + Allocator<T, R3>,
+    {
+        self.rank_one_update_mut(&mut u.clone_owned(), &mut v.clone_owned())
+    }
+
+    /// Performs the same rank one update as [`Self::rank_one_update`] without allocating, by
+    /// using `u` and `v` as workspace.
+    ///
+    /// The contents of `u` and `v` are unspecified after this call.
+    pub fn rank_one_update_mut<R2: Dim, S2, R3: Dim, S3>(
+        &mut self,
+        u: &mut Vector<T, R2, S2>,
+        v: &mut Vector<T, R3, S3>,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2>,
+        S3: StorageMut<T, R3>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + SameNumberOfRows<R3, D>,
+    {
+        let n = self.lu.nrows();
+        assert!(
+            u.len() == n && v.len() == n,
+            "LU rank one update: the vector dimensions must match the decomposed matrix."
+        );
+
+        // With `P * M = L * U`, we have `P * (M + u * vᴴ) = L * U + (P * u) * vᴴ`.
+        let x = u;
+        self.p.permute_rows(x);
+        let y = v;
+        y.conjugate_mut();
+
+        for i in 0..n {
+            self.lu[(i, i)] += x[i].clone() * y[i].clone();
+            let diag = self.lu[(i, i)].clone();
+
+            if diag.is_zero() {
+                return false;
+            }
+
+            y[i] /= diag;
+            let xi = x[i].clone();
+            let yi = y[i].clone();
+
+            for j in i + 1..n {
+                x[j] -= xi.clone() * self.lu[(j, i)].clone();
+                self.lu[(j, i)] += yi.clone() * x[j].clone();
+            }
+
+            for j in i + 1..n {
+                self.lu[(i, j)] += xi.clone() * y[j].clone();
+                y[j] -= yi.clone() * self.lu[(i, j)].clone();
+            }
+        }
+
+        true
+    }
+
     /// Indicates if the decomposed matrix is invertible.
     #[must_use]
     pub fn is_invertible(&self) -> bool {
@@ -328,6 +405,73 @@ where
 
         true
     }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// See [`estimate_rcond`](crate::linalg::estimate_rcond) for details on the estimator. The
+    /// result is close to zero for ill-conditioned matrices, and exactly zero for singular
+    /// matrices.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        assert!(
+            self.lu.is_square(),
+            "LU rcond: unable to estimate the condition number of a non-square matrix."
+        );
+
+        let l = self.l();
+        let u = self.u();
+
+        estimate_rcond(
+            self.lu.shape_generic().0,
+            |x| {
+                *x = &l * (&u * &*x);
+                self.p.inv_permute_rows(x);
+                true
+            },
+            |x| {
+                self.p.permute_rows(x);
+                *x = u.ad_mul(&l.ad_mul(&*x));
+                true
+            },
+            |x| self.solve_mut(x),
+            |x| {
+                let ok = u.ad_solve_upper_triangular_mut(x) && l.ad_solve_lower_triangular_mut(x);
+                self.p.inv_permute_rows(x);
+                ok
+            },
+        )
+    }
+}
+
+/// Overwrites `matrix` with its LU decomposition, and appends the row interchanges to `p`.
+fn decompose<T: ComplexField, R: Dim, C: Dim, D: Dim>(
+    matrix: &mut OMatrix<T, R, C>,
+    p: &mut PermutationSequence<D>,
+) where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), D>,
+{
+    let min_nrows_ncols = matrix.nrows().min(matrix.ncols());
+
+    for i in 0..min_nrows_ncols {
+        let piv = matrix.view_range(i.., i).icamax() + i;
+        let diag = matrix[(piv, i)].clone();
+
+        if diag.is_zero() {
+            // No non-zero entries on this column.
+            continue;
+        }
+
+        if piv != i {
+            p.append_permutation(i, piv);
+            matrix.columns_range_mut(..i).swap_rows(i, piv);
+            gauss_step_swap(matrix, diag, i, piv);
+        } else {
+            gauss_step(matrix, diag, i);
+        }
+    }
 }
 
 #[doc(hidden)]
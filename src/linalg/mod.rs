@@ -1,6 +1,8 @@
 //! [Reexported at the root of this crate.] Factorization of real matrices.
 
 pub mod balancing;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod banded;
 mod bidiagonal;
 mod cholesky;
 mod convolution;
@@ -9,45 +11,89 @@ mod determinant;
 // explicit float operations on `f32` and `f64`. We need to
 // get rid of these to allow exp to be used on a no-std context.
 mod col_piv_qr;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod complete_orthogonal;
+mod condition;
 mod decomposition;
+mod eigen;
 #[cfg(feature = "std")]
 mod exp;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod fft;
 mod full_piv_lu;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod krylov;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod krylov_eigen;
+mod ldlt;
+mod least_squares;
+mod log;
 mod lu;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod pca;
 mod permutation_sequence;
 mod pow;
 mod qr;
+mod qz;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod riccati;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
 mod svd2;
 mod svd3;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod svd_economy;
+mod svd_jacobi;
+mod sylvester;
 mod symmetric_eigen;
 mod symmetric_tridiagonal;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod toeplitz;
 mod udu;
+mod updatable_qr;
 
-//// TODO: Not complete enough for publishing.
-//// This handles only cases where each eigenvalue has multiplicity one.
-// mod eigen;
-
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::banded::*;
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::complete_orthogonal::*;
+pub use self::condition::*;
 pub use self::convolution::*;
+pub use self::eigen::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
 pub use self::hessenberg::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::krylov::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::krylov_eigen::*;
+pub use self::ldlt::*;
+pub use self::least_squares::*;
+pub use self::log::*;
 pub use self::lu::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::pca::*;
 pub use self::permutation_sequence::*;
 pub use self::pow::*;
 pub use self::qr::*;
+pub use self::qz::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::riccati::*;
 pub use self::schur::*;
 pub use self::svd::*;
+pub use self::sylvester::*;
 pub use self::symmetric_eigen::*;
 pub use self::symmetric_tridiagonal::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::toeplitz::*;
 pub use self::udu::*;
+pub use self::updatable_qr::*;
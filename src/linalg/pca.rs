@@ -0,0 +1,201 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::base::{DMatrix, DVector, Matrix, RowDVector};
+use crate::dimension::Dyn;
+use crate::storage::Storage;
+
+use crate::linalg::{SymmetricEigen, SVD};
+
+/// Principal component analysis of a set of observations.
+///
+/// The observations are the rows of a data matrix, and its columns are the variables. The
+/// principal components are the orthonormal directions along which the centered observations
+/// have the largest variances. Like `Matrix::covariance`, the variances are normalized by the
+/// number of observations.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize, T::RealField: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>, T::RealField: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct PCA<T: ComplexField> {
+    mean: RowDVector<T>,
+    components: DMatrix<T>,
+    explained_variance: DVector<T::RealField>,
+    total_variance: T::RealField,
+}
+
+impl<T: ComplexField> PCA<T> {
+    /// Computes the `n_components` first principal components of `data`, where each row is an
+    /// observation and each column is a variable.
+    ///
+    /// This uses the economy-size SVD of the centered data, which is more accurate than the
+    /// eigendecomposition of its covariance matrix.
+    pub fn new<S: Storage<T, Dyn, Dyn>>(
+        data: &Matrix<T, Dyn, Dyn, S>,
+        n_components: usize,
+    ) -> Self {
+        assert!(
+            !data.is_empty(),
+            "Cannot compute the PCA of an empty data matrix."
+        );
+        assert!(
+            n_components <= data.nrows().min(data.ncols()),
+            "PCA: the number of components must not exceed the number of observations or variables."
+        );
+
+        let mean = data.row_mean();
+        let mut centered = data.clone_owned();
+        for mut row in centered.row_iter_mut() {
+            row -= &mean;
+        }
+
+        // With `X = U * Σ * Vᴴ`, the covariance matrix is `V * Σ² / n * Vᴴ`.
+        let nobs: T::RealField = crate::convert(data.nrows() as f64);
+        let svd = SVD::new_economy(centered, false, true);
+        let variances = svd.singular_values.map(|s| s.clone() * s / nobs.clone());
+        let v_t = svd.v_t.unwrap();
+
+        Self {
+            mean,
+            components: v_t.rows(0, n_components).adjoint(),
+            explained_variance: variances.rows(0, n_components).into_owned(),
+            total_variance: variances.sum(),
+        }
+    }
+
+    /// Computes the `n_components` first principal components from the `mean` and the
+    /// `covariance` matrix of a set of observations, e.g., as computed by
+    /// `Matrix::weighted_covariance`.
+    ///
+    /// This uses the symmetric eigendecomposition of `covariance`, whose strictly
+    /// upper-triangular part is ignored.
+    pub fn from_covariance(
+        mean: RowDVector<T>,
+        covariance: DMatrix<T>,
+        n_components: usize,
+    ) -> Self {
+        assert!(
+            covariance.is_square() && covariance.nrows() == mean.len(),
+            "PCA: the covariance matrix must be square, with one row per variable."
+        );
+        assert!(
+            n_components <= mean.len(),
+            "PCA: the number of components must not exceed the number of variables."
+        );
+
+        let eig = SymmetricEigen::new(covariance);
+
+        // Sort the eigenvalues in descending order.
+        let mut order: Vec<usize> = (0..eig.eigenvalues.len()).collect();
+        order.sort_by(|&i, &j| {
+            eig.eigenvalues[j]
+                .partial_cmp(&eig.eigenvalues[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        order.truncate(n_components);
+
+        let nvars = mean.len();
+        Self {
+            components: DMatrix::from_fn(nvars, n_components, |i, j| {
+                eig.eigenvectors[(i, order[j])].clone()
+            }),
+            explained_variance: DVector::from_fn(n_components, |i, _| {
+                eig.eigenvalues[order[i]].clone()
+            }),
+            total_variance: eig.eigenvalues.sum(),
+            mean,
+        }
+    }
+
+    /// The number of principal components.
+    #[must_use]
+    pub fn n_components(&self) -> usize {
+        self.components.ncols()
+    }
+
+    /// The mean of the observations, which is subtracted before projecting them.
+    #[must_use]
+    pub fn mean(&self) -> &RowDVector<T> {
+        &self.mean
+    }
+
+    /// The principal components, as the orthonormal columns of a matrix with one row per variable.
+    #[must_use]
+    pub fn components(&self) -> &DMatrix<T> {
+        &self.components
+    }
+
+    /// The variance of the observations along each principal component, in descending order.
+    #[must_use]
+    pub fn explained_variance(&self) -> &DVector<T::RealField> {
+        &self.explained_variance
+    }
+
+    /// The fraction of the total variance of the observations explained by each principal
+    /// component.
+    #[must_use]
+    pub fn explained_variance_ratio(&self) -> DVector<T::RealField> {
+        if self.total_variance.is_zero() {
+            DVector::zeros(self.explained_variance.len())
+        } else {
+            self.explained_variance.unscale(self.total_variance.clone())
+        }
+    }
+
+    /// Projects the rows of `data` on the principal components.
+    ///
+    /// The result has one row per observation and one column per principal component.
+    #[must_use]
+    pub fn transform<S2: Storage<T, Dyn, Dyn>>(
+        &self,
+        data: &Matrix<T, Dyn, Dyn, S2>,
+    ) -> DMatrix<T> {
+        assert_eq!(
+            data.ncols(),
+            self.mean.len(),
+            "PCA: the data must have one column per variable."
+        );
+
+        let mut centered = data.clone_owned();
+        for mut row in centered.row_iter_mut() {
+            row -= &self.mean;
+        }
+
+        centered * &self.components
+    }
+
+    /// Maps the projections `scores` computed by `.transform` back to the space of the
+    /// observations.
+    ///
+    /// This reconstructs the observations exactly if all the principal components were kept.
+    #[must_use]
+    pub fn inverse_transform<S2: Storage<T, Dyn, Dyn>>(
+        &self,
+        scores: &Matrix<T, Dyn, Dyn, S2>,
+    ) -> DMatrix<T> {
+        assert_eq!(
+            scores.ncols(),
+            self.n_components(),
+            "PCA: the scores must have one column per principal component."
+        );
+
+        let mut res = scores * self.components.adjoint();
+        for mut row in res.row_iter_mut() {
+            row += &self.mean;
+        }
+
+        res
+    }
+}
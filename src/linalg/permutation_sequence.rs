@@ -77,6 +77,13 @@ where
         }
     }
 
+    /// Removes all the permutations from this sequence, turning it into the identity without
+    /// reallocating its storage.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
     /// Adds the interchange of the row (or column) `i` with the row (or column) `i2` to this
     /// sequence of permutations.
     #[inline]
@@ -5,6 +5,15 @@ use crate::{
     storage::{Storage, StorageMut},
     DefaultAllocator, DimMin, Matrix, OMatrix, Scalar,
 };
+#[cfg(feature = "std")]
+use crate::{
+    linalg::{
+        log::ln_quasi_triangular,
+        schur::{block2, block_size_at, is_zero_block, reorder_schur, zero_eigenvalue_threshold},
+        MatrixFunctionError, Schur,
+    },
+    ComplexField, DMatrix, DimDiff, DimSub, U1,
+};
 use num::{One, Zero};
 use simba::scalar::{ClosedAdd, ClosedMul};
 
@@ -69,3 +78,117 @@ where
         result
     }
 }
+
+/// Computes `Q * Tᵖ * Qᴴ` for `p > 0` from the Schur decomposition `Q * T * Qᴴ` of a matrix
+/// whose zero eigenvalues, i.e., the eigenvalues of `T` smaller than `zero_tol`, are all
+/// semisimple.
+#[cfg(feature = "std")]
+fn powf_singular<T: ComplexField>(
+    mut q: DMatrix<T>,
+    mut t: DMatrix<T>,
+    exp: T::RealField,
+    zero_tol: T::RealField,
+) -> Result<DMatrix<T>, MatrixFunctionError> {
+    let dim = t.nrows();
+
+    // Move the zero eigenvalues to the bottom-right corner, so that `T = [T₁₁ T₁₂; 0 N]`.
+    let k = reorder_schur(&mut t, &mut q, |block, size| {
+        !is_zero_block(block, size, &zero_tol)
+    })
+    .ok_or(MatrixFunctionError::NonPositiveEigenvalue)?;
+
+    // `N` is nilpotent, and vanishes if, and only if, the zero eigenvalue is semisimple.
+    if t.view_range(k.., k..).norm() > zero_tol {
+        return Err(MatrixFunctionError::NonPositiveEigenvalue);
+    }
+
+    let mut res = DMatrix::zeros(dim, dim);
+
+    if k > 0 {
+        // With `N = 0`, `Tᵖ = [T₁₁ᵖ T₁₁ᵖ⁻¹ * T₁₂; 0 0]`.
+        let t11 = t.view_range(..k, ..k).clone_owned();
+        let t12 = t.view_range(..k, k..).clone_owned();
+        let f11 = (ln_quasi_triangular(t11.clone())? * T::from_real(exp)).exp();
+        let f12 = &f11
+            * t11
+                .lu()
+                .solve(&t12)
+                .ok_or(MatrixFunctionError::NonPositiveEigenvalue)?;
+
+        res.view_range_mut(..k, ..k).copy_from(&f11);
+        res.view_range_mut(..k, k..).copy_from(&f12);
+    }
+
+    Ok(&q * res * q.adjoint())
+}
+
+#[cfg(feature = "std")]
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<(usize, usize), D>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, D, D>,
+{
+    /// Raises this matrix to the real power `exp`.
+    ///
+    /// This computes the principal power `exp(exp * ln(self))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has no principal logarithm. See [`Self::try_powf`] for a
+    /// non-panicking version.
+    #[must_use]
+    pub fn powf(&self, exp: T::RealField) -> Self {
+        self.try_powf(exp)
+            .expect("Matrix power: the matrix has no principal logarithm.")
+    }
+
+    /// Attempts to raise this matrix to the real power `exp`.
+    ///
+    /// The principal power `exp(exp * ln(self))` is computed on the triangular factor of the
+    /// Schur decomposition of this matrix. For integral exponents, prefer [`Self::pow`] which
+    /// is exact and also works for singular matrices.
+    ///
+    /// Zero eigenvalues are supported if `exp` is positive and they are semisimple, i.e., if
+    /// their algebraic and geometric multiplicities are equal. Because `λᵖ` is not Lipschitz
+    /// at zero, eigenvalues whose modulus is smaller than `sqrt(ε) * ‖self‖` are considered to
+    /// be zero in this case. Returns [`MatrixFunctionError::NonPositiveEigenvalue`] if this matrix
+    /// has a negative real eigenvalue, or a zero eigenvalue that does not satisfy these conditions.
+    pub fn try_powf(&self, exp: T::RealField) -> Result<Self, MatrixFunctionError> {
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let (q, t) = Schur::new(self.clone()).unpack();
+        let zero_tol = zero_eigenvalue_threshold(&t);
+        let dim = t.nrows();
+        let mut has_zero_eigenvalue = false;
+        let mut i = 0;
+
+        while i < dim {
+            let size = block_size_at(&t, i);
+            has_zero_eigenvalue |= is_zero_block(&block2(&t, i, i, size, size), size, &zero_tol);
+            i += size;
+        }
+
+        if has_zero_eigenvalue && exp > T::RealField::zero() {
+            let to_dynamic = |m: &Self| DMatrix::from_iterator(dim, dim, m.iter().cloned());
+            let res = powf_singular(to_dynamic(&q), to_dynamic(&t), exp, zero_tol)?;
+            let (nrows, ncols) = self.shape_generic();
+
+            return Ok(Self::from_fn_generic(nrows, ncols, |i, j| {
+                res[(i, j)].clone()
+            }));
+        }
+
+        let ln_t = ln_quasi_triangular(t)?;
+        let res = (ln_t * T::from_real(exp)).exp();
+
+        Ok(&q * res * q.adjoint())
+    }
+}
@@ -3,14 +3,17 @@ use num::Zero;
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit, Vector};
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
 
 use crate::geometry::Reflection;
+use crate::linalg::condition::estimate_rcond;
 use crate::linalg::householder;
+use crate::linalg::least_squares::{solve_householder_qr, LeastSquaresError, LeastSquaresSolution};
+use crate::linalg::UpdatableQR;
 use std::mem::MaybeUninit;
 
 /// The QR decomposition of a general matrix.
@@ -142,6 +145,91 @@ where
         (self.q(), self.unpack_r())
     }
 
+    /// Converts this decomposition into an [`UpdatableQR`], which stores the unitary factor `Q`
+    /// explicitly so that the decomposition can be updated with Givens rotations.
+    ///
+    /// The update methods of `QR`, like [`Self::rank_one_update`], perform this conversion and
+    /// return the resulting [`UpdatableQR`]. Keep using it instead of a `QR` for subsequent
+    /// updates so the cost of forming `Q` is only paid once.
+    pub fn into_updatable(self) -> UpdatableQR<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, R>,
+    {
+        UpdatableQR::from_qr(&self)
+    }
+
+    /// Computes the decomposition of `M + u * v.adjoint()`, where `M` is the decomposed matrix,
+    /// with [`UpdatableQR::rank_one_update`]. See [`Self::into_updatable`].
+    pub fn rank_one_update<R2: Dim, S2, R3: Dim, S3>(
+        self,
+        u: &Vector<T, R2, S2>,
+        v: &Vector<T, R3, S3>,
+    ) -> UpdatableQR<T, R, C>
+    where
+        S2: Storage<T, R2>,
+        S3: Storage<T, R3>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfRows<R3, C>,
+        DefaultAllocator: Allocator<T, R, R>,
+    {
+        let mut res = self.into_updatable();
+        res.rank_one_update(u, v);
+        res
+    }
+
+    /// Inserts the column `col` at the `j`th position with [`UpdatableQR::insert_column`]. See
+    /// [`Self::into_updatable`].
+    pub fn insert_column<R2: Dim, S2>(
+        self,
+        j: usize,
+        col: &Vector<T, R2, S2>,
+    ) -> UpdatableQR<T, R, DimSum<C, U1>>
+    where
+        C: DimAdd<U1>,
+        S2: Storage<T, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, DimSum<C, U1>>,
+    {
+        self.into_updatable().insert_column(j, col)
+    }
+
+    /// Removes the `j`th column with [`UpdatableQR::remove_column`]. See [`Self::into_updatable`].
+    pub fn remove_column(self, j: usize) -> UpdatableQR<T, R, DimDiff<C, U1>>
+    where
+        C: DimSub<U1>,
+        DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, DimDiff<C, U1>>,
+    {
+        self.into_updatable().remove_column(j)
+    }
+
+    /// Inserts the row `row` at the `i`th position with [`UpdatableQR::insert_row`]. See
+    /// [`Self::into_updatable`].
+    pub fn insert_row<C2: Dim, S2>(
+        self,
+        i: usize,
+        row: &Matrix<T, U1, C2, S2>,
+    ) -> UpdatableQR<T, DimSum<R, U1>, C>
+    where
+        R: DimAdd<U1>,
+        S2: Storage<T, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C2, C>,
+        DefaultAllocator: Allocator<T, R, R>
+            + Allocator<T, DimSum<R, U1>, DimSum<R, U1>>
+            + Allocator<T, DimSum<R, U1>, C>,
+    {
+        self.into_updatable().insert_row(i, row)
+    }
+
+    /// Removes the `i`th row with [`UpdatableQR::remove_row`]. See [`Self::into_updatable`].
+    pub fn remove_row(self, i: usize) -> UpdatableQR<T, DimDiff<R, U1>, C>
+    where
+        R: DimSub<U1>,
+        DefaultAllocator: Allocator<T, R, R>
+            + Allocator<T, DimDiff<R, U1>, DimDiff<R, U1>>
+            + Allocator<T, DimDiff<R, U1>, C>,
+    {
+        self.into_updatable().remove_row(i)
+    }
+
     #[doc(hidden)]
     pub fn qr_internal(&self) -> &OMatrix<T, R, C> {
         &self.qr
@@ -168,6 +256,65 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
+
+    /// Multiplies the provided matrix by the `Q` matrix of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let dim = self.diag.len();
+
+        for i in (0..dim).rev() {
+            let axis = self.qr.view_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum());
+        }
+    }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// The decomposed matrix must have at least as many rows as columns, and is assumed to have
+    /// full column rank. Returns [`LeastSquaresError::RankDeficient`] if the triangular factor
+    /// `R` is singular. Consider `ColPivQR::solve_least_squares` or `SVD::solve_least_squares`
+    /// for rank-deficient or underdetermined systems.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<LeastSquaresSolution<T, C, C2>, LeastSquaresError>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert_eq!(
+            self.qr.nrows(),
+            b.nrows(),
+            "QR least squares: matrix dimension mismatch."
+        );
+        assert!(
+            self.qr.nrows() >= self.qr.ncols(),
+            "QR least squares: unable to solve an underdetermined system."
+        );
+
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        if self.diag.iter().any(|e| e.is_zero()) {
+            return Err(LeastSquaresError::RankDeficient);
+        }
+
+        let rank = self.qr.ncols();
+        let (solution, residual_norm) = solve_householder_qr(&self.qr, &self.diag, rank, &qtb);
+
+        Ok(LeastSquaresSolution {
+            solution,
+            residual_norm,
+            rank,
+        })
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> QR<T, D, D>
@@ -292,6 +439,41 @@ where
         true
     }
 
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// See [`estimate_rcond`](crate::linalg::estimate_rcond) for details on the estimator. The
+    /// result is close to zero for ill-conditioned matrices, and exactly zero for singular
+    /// matrices.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField {
+        assert!(
+            self.qr.is_square(),
+            "QR rcond: unable to estimate the condition number of a non-square matrix."
+        );
+
+        let r = self.r();
+
+        estimate_rcond(
+            self.qr.shape_generic().0,
+            |x| {
+                *x = &r * &*x;
+                self.q_mul(x);
+                true
+            },
+            |x| {
+                self.q_tr_mul(x);
+                *x = r.ad_mul(&*x);
+                true
+            },
+            |x| self.solve_mut(x),
+            |x| {
+                let ok = r.ad_solve_upper_triangular_mut(x);
+                self.q_mul(x);
+                ok
+            },
+        )
+    }
+
     // /// Computes the determinant of the decomposed matrix.
     // pub fn determinant(&self) -> T {
     //     let dim = self.qr.nrows();
@@ -0,0 +1,747 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use num_complex::Complex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, DimMin, U1};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Vector2};
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::schur::{block_size_at, block_size_before};
+
+/// QZ decomposition of a pair of square real matrices.
+///
+/// Computes the matrices of left and right Schur vectors `VSL` and `VSR`, the
+/// upper-quasitriangular matrix `S` and the upper-triangular matrix `T` such that the
+/// decomposed matrix `a` equals `VSL * S * VSR.transpose()` and the decomposed matrix `b`
+/// equals `VSL * T * VSR.transpose()`.
+///
+/// The generalized eigenvalues of the pencil `(a, b)`, i.e., the values `λ` such that
+/// `a * x = λ * b * x` for some non-zero `x`, can be read from the diagonal blocks of `S` and `T`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct QZ<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    vsl: OMatrix<T, D, D>,
+    s: OMatrix<T, D, D>,
+    t: OMatrix<T, D, D>,
+    vsr: OMatrix<T, D, D>,
+}
+
+impl<T: RealField, D: Dim> Copy for QZ<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+    OMatrix<T, D, D>: Copy,
+{
+}
+
+impl<T: RealField, D: DimMin<D, Output = D>> QZ<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    /// Computes the QZ decomposition of the real square matrices `a` and `b`.
+    ///
+    /// Panics if the method did not converge.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Self {
+        Self::try_new(a, b, T::default_epsilon(), 0).expect("QZ decomposition: convergence failed.")
+    }
+
+    /// Attempts to compute the QZ decomposition of the real square matrices `a` and `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new(
+        a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            a.is_square() && b.is_square(),
+            "Unable to compute the qz decomposition of non-square matrices."
+        );
+        assert!(
+            a.shape_generic() == b.shape_generic(),
+            "Unable to compute the qz decomposition of two square matrices of different dimensions."
+        );
+
+        let mut qz = Self::hessenberg_triangular(a, b);
+        let dim = qz.s.nrows();
+
+        if dim < 2 {
+            return Some(qz);
+        }
+
+        let norm_s = qz.s.norm();
+        let norm_t = qz.t.norm();
+
+        let mut l = dim - 1;
+        let mut niter = 0;
+        let mut local_iter = 0;
+
+        while l > 0 {
+            let f = qz.find_small_subdiag_entry(l, eps.clone(), norm_s.clone());
+
+            if f > 0 {
+                qz.s[(f, f - 1)] = T::zero();
+            }
+
+            if f + 1 >= l {
+                // One or two eigenvalues converged.
+                if f + 1 == l {
+                    qz.split_off_two_rows(f, eps.clone(), norm_t.clone());
+                }
+
+                if f == 0 {
+                    break;
+                }
+
+                l = f - 1;
+                local_iter = 0;
+            } else if let Some(z) = qz.find_small_diag_entry(f, l - 1, eps.clone(), norm_t.clone())
+            {
+                // Infinite eigenvalue: push the zero of `T` down to `(l, l)`.
+                qz.push_down_zero(z, f, l);
+            } else {
+                qz.step(f, l, local_iter);
+                local_iter += 1;
+                niter += 1;
+
+                if niter == max_niter {
+                    return None;
+                }
+            }
+        }
+
+        Some(qz)
+    }
+
+    /// Reduces `a` to upper-Hessenberg form and `b` to upper-triangular form.
+    fn hessenberg_triangular(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Self {
+        let dim = a.nrows();
+        let (nrows, ncols) = a.shape_generic();
+
+        let qr = b.qr();
+        let mut vsl = qr.q();
+        let mut t = qr.r();
+        let mut s = vsl.transpose() * a;
+        let mut vsr = OMatrix::identity_generic(nrows, ncols);
+
+        for j in 0..dim.saturating_sub(2) {
+            for i in (j + 2..dim).rev() {
+                // Cancel s[(i, j)] by mixing the rows `i - 1` and `i`.
+                let v = Vector2::new(s[(i - 1, j)].clone(), s[(i, j)].clone());
+                if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                    rot.rotate(&mut s.fixed_rows_mut::<2>(i - 1));
+                    rot.rotate(&mut t.fixed_rows_mut::<2>(i - 1));
+                    rot.inverse()
+                        .rotate_rows(&mut vsl.fixed_columns_mut::<2>(i - 1));
+                }
+                s[(i, j)] = T::zero();
+
+                // Cancel the fill-in t[(i, i - 1)] by mixing the columns `i - 1` and `i`.
+                let v = Vector2::new(t[(i, i - 1)].clone(), t[(i, i)].clone());
+                if let Some((rot, _)) = GivensRotation::cancel_x(&v) {
+                    let rot = rot.inverse();
+                    rot.rotate_rows(&mut s.fixed_columns_mut::<2>(i - 1));
+                    rot.rotate_rows(&mut t.fixed_columns_mut::<2>(i - 1));
+                    rot.rotate_rows(&mut vsr.fixed_columns_mut::<2>(i - 1));
+                }
+                t[(i, i - 1)] = T::zero();
+            }
+        }
+
+        QZ { vsl, s, t, vsr }
+    }
+
+    /// Mixes the rows `i` and `i + 1` of `S` and `T` so that the second component of `v` is zeroed.
+    fn rotate_rows(&mut self, i: usize, v: Vector2<T>) -> Option<T> {
+        GivensRotation::cancel_y(&v).map(|(rot, r)| {
+            rot.rotate(&mut self.s.fixed_rows_mut::<2>(i));
+            rot.rotate(&mut self.t.fixed_rows_mut::<2>(i));
+            rot.inverse()
+                .rotate_rows(&mut self.vsl.fixed_columns_mut::<2>(i));
+            r
+        })
+    }
+
+    /// Mixes the columns `j` and `j + 1` of `S` and `T` so that the first component of `v` is
+    /// zeroed.
+    fn rotate_columns(&mut self, j: usize, v: Vector2<T>) {
+        if let Some((rot, _)) = GivensRotation::cancel_x(&v) {
+            let rot = rot.inverse();
+            rot.rotate_rows(&mut self.s.fixed_columns_mut::<2>(j));
+            rot.rotate_rows(&mut self.t.fixed_columns_mut::<2>(j));
+            rot.rotate_rows(&mut self.vsr.fixed_columns_mut::<2>(j));
+        }
+    }
+
+    /// Finds the largest `k <= l` such that `s[(k, k - 1)]` is negligible, or 0.
+    fn find_small_subdiag_entry(&self, l: usize, eps: T, norm_s: T) -> usize {
+        let mut k = l;
+
+        while k > 0 {
+            let mut scale = self.s[(k - 1, k - 1)].clone().abs() + self.s[(k, k)].clone().abs();
+            if scale.is_zero() {
+                scale = norm_s.clone();
+            }
+
+            if self.s[(k, k - 1)].clone().abs() <= eps.clone() * scale {
+                break;
+            }
+
+            k -= 1;
+        }
+
+        k
+    }
+
+    /// Finds the largest `k` in `f..=l` such that `t[(k, k)]` is negligible.
+    fn find_small_diag_entry(&self, f: usize, l: usize, eps: T, norm_t: T) -> Option<usize> {
+        (f..=l)
+            .rev()
+            .find(|&k| self.t[(k, k)].clone().abs() <= eps.clone() * norm_t.clone())
+    }
+
+    /// Chases the zero `t[(z, z)]` down to `t[(l, l)]`, and deflates the corresponding infinite
+    /// eigenvalue.
+    fn push_down_zero(&mut self, z: usize, f: usize, l: usize) {
+        self.t[(z, z)] = T::zero();
+
+        for k in z..l {
+            let v = Vector2::new(self.t[(k, k + 1)].clone(), self.t[(k + 1, k + 1)].clone());
+            let _ = self.rotate_rows(k, v);
+            self.t[(k + 1, k + 1)] = T::zero();
+
+            if k > f {
+                let v = Vector2::new(self.s[(k + 1, k - 1)].clone(), self.s[(k + 1, k)].clone());
+                self.rotate_columns(k - 1, v);
+                self.s[(k + 1, k - 1)] = T::zero();
+            }
+        }
+
+        let v = Vector2::new(self.s[(l, l - 1)].clone(), self.s[(l, l)].clone());
+        self.rotate_columns(l - 1, v);
+        self.s[(l, l - 1)] = T::zero();
+    }
+
+    /// Splits the 2x2 diagonal block starting at `i` if it has real eigenvalues.
+    fn split_off_two_rows(&mut self, i: usize, eps: T, norm_t: T) {
+        if self.s[(i + 1, i)].is_zero() {
+            return;
+        }
+
+        if let Some(z) = self.find_small_diag_entry(i, i + 1, eps, norm_t) {
+            self.push_down_zero(z, i, i + 1);
+            return;
+        }
+
+        // The 2x2 block of S * T⁻¹.
+        let (s00, s01, s10, s11) = (
+            self.s[(i, i)].clone(),
+            self.s[(i, i + 1)].clone(),
+            self.s[(i + 1, i)].clone(),
+            self.s[(i + 1, i + 1)].clone(),
+        );
+        let (t00, t01, t11) = (
+            self.t[(i, i)].clone(),
+            self.t[(i, i + 1)].clone(),
+            self.t[(i + 1, i + 1)].clone(),
+        );
+        let m00 = s00.clone() / t00.clone();
+        let m01 = (s01 - s00 * t01.clone() / t00.clone()) / t11.clone();
+        let m10 = s10.clone() / t00.clone();
+        let m11 = (s11 - s10 * t01 / t00) / t11;
+
+        let p = (m00 - m11) * crate::convert(0.5);
+        let q = p.clone() * p.clone() + m10.clone() * m01;
+
+        if q >= T::zero() {
+            // Real eigenvalues: rotate the rows onto an eigenvector of S * T⁻¹, then restore the
+            // triangular structure of T.
+            let z = q.sqrt();
+            let x = if p >= T::zero() { p + z } else { p - z };
+            let _ = self.rotate_rows(i, Vector2::new(x, m10));
+
+            let v = Vector2::new(self.t[(i + 1, i)].clone(), self.t[(i + 1, i + 1)].clone());
+            self.rotate_columns(i, v);
+
+            self.s[(i + 1, i)] = T::zero();
+            self.t[(i + 1, i)] = T::zero();
+        }
+    }
+
+    /// Performs one implicit double-shift QZ step on the active block `f..=l`.
+    fn step(&mut self, f: usize, l: usize, iter: usize) {
+        let s = &self.s;
+        let t = &self.t;
+        let (mut x, mut y, mut z);
+
+        if iter % 11 == 10 {
+            // Exceptional ad-hoc shift.
+            let (a11, a12, a21, a22, a32) = (
+                s[(f, f)].clone(),
+                s[(f, f + 1)].clone(),
+                s[(f + 1, f)].clone(),
+                s[(f + 1, f + 1)].clone(),
+                s[(f + 2, f + 1)].clone(),
+            );
+            let b12 = t[(f, f + 1)].clone();
+            let b11i = T::one() / t[(f, f)].clone();
+            let b22i = T::one() / t[(f + 1, f + 1)].clone();
+            let a87 = s[(l - 1, l - 2)].clone();
+            let a98 = s[(l, l - 1)].clone();
+            let b77i = T::one() / t[(l - 2, l - 2)].clone();
+            let b88i = T::one() / t[(l - 1, l - 1)].clone();
+
+            let ss = (a87 * b77i).abs() + (a98 * b88i).abs();
+            let lpl = ss.clone() * crate::convert(1.5);
+            let ll = ss.clone() * ss;
+
+            x = ll + a11.clone() * a11.clone() * b11i.clone() * b11i.clone()
+                - lpl.clone() * a11.clone() * b11i.clone()
+                + a12 * a21.clone() * b11i.clone() * b22i.clone()
+                - a11.clone()
+                    * a21.clone()
+                    * b12.clone()
+                    * b11i.clone()
+                    * b11i.clone()
+                    * b22i.clone();
+            y = a11 * a21.clone() * b11i.clone() * b11i.clone() - lpl * a21.clone() * b11i.clone()
+                + a21.clone() * a22 * b11i.clone() * b22i.clone()
+                - a21.clone() * a21.clone() * b12 * b11i.clone() * b11i.clone() * b22i.clone();
+            z = a21 * a32 * b11i * b22i;
+        } else {
+            // Standard double shift: (x, y, z) is proportional to the first column of
+            // (S T⁻¹ - λ₁ I)(S T⁻¹ - λ₂ I) where λ₁, λ₂ are the eigenvalues of the trailing
+            // 2x2 block of the pencil.
+            let (a11, a12, a21, a22, a32) = (
+                s[(f, f)].clone(),
+                s[(f, f + 1)].clone(),
+                s[(f + 1, f)].clone(),
+                s[(f + 1, f + 1)].clone(),
+                s[(f + 2, f + 1)].clone(),
+            );
+            let (a88, a89, a98, a99) = (
+                s[(l - 1, l - 1)].clone(),
+                s[(l - 1, l)].clone(),
+                s[(l, l - 1)].clone(),
+                s[(l, l)].clone(),
+            );
+            let (b11, b12, b22) = (
+                t[(f, f)].clone(),
+                t[(f, f + 1)].clone(),
+                t[(f + 1, f + 1)].clone(),
+            );
+            let (b88, b89, b99) = (
+                t[(l - 1, l - 1)].clone(),
+                t[(l - 1, l)].clone(),
+                t[(l, l)].clone(),
+            );
+
+            let c11 = a11 / b11.clone();
+            let c88 = a88 / b88.clone() - c11.clone();
+            let c99 = a99 / b99.clone() - c11.clone();
+            let c98 = a98 / b88;
+            let c89 = b89 / b99.clone();
+
+            x = (c88.clone() * c99.clone() - a89 / b99 * c98.clone()
+                + c98.clone() * c89.clone() * c11.clone())
+                * (b11.clone() / a21.clone())
+                + a12 / b22.clone()
+                - c11.clone() * (b12.clone() / b22.clone());
+            y = (a22 / b22.clone() - c11) - a21 / b11 * (b12 / b22.clone()) - c88 - c99 + c98 * c89;
+            z = a32 / b22;
+        }
+
+        for k in f..l - 1 {
+            // Left rotations cancelling the bulge in the column `k - 1` of S.
+            if let Some(r) = self.rotate_rows(k + 1, Vector2::new(y.clone(), z.clone())) {
+                y = r;
+            }
+            let _ = self.rotate_rows(k, Vector2::new(x.clone(), y.clone()));
+
+            if k > f {
+                self.s[(k + 1, k - 1)] = T::zero();
+                self.s[(k + 2, k - 1)] = T::zero();
+            }
+
+            // Right rotations restoring the triangular structure of T.
+            let v = Vector2::new(self.t[(k + 2, k)].clone(), self.t[(k + 2, k + 1)].clone());
+            self.rotate_columns(k, v);
+            let v = Vector2::new(
+                self.t[(k + 2, k + 1)].clone(),
+                self.t[(k + 2, k + 2)].clone(),
+            );
+            self.rotate_columns(k + 1, v);
+            self.t[(k + 2, k)] = T::zero();
+            self.t[(k + 2, k + 1)] = T::zero();
+
+            let v = Vector2::new(self.t[(k + 1, k)].clone(), self.t[(k + 1, k + 1)].clone());
+            self.rotate_columns(k, v);
+            self.t[(k + 1, k)] = T::zero();
+
+            x = self.s[(k + 1, k)].clone();
+            y = self.s[(k + 2, k)].clone();
+
+            if k + 2 < l {
+                z = self.s[(k + 3, k)].clone();
+            }
+        }
+
+        // Cancel the last bulge element s[(l, l - 2)].
+        let _ = self.rotate_rows(l - 1, Vector2::new(x, y));
+        self.s[(l, l - 2)] = T::zero();
+
+        let v = Vector2::new(self.t[(l, l - 1)].clone(), self.t[(l, l)].clone());
+        self.rotate_columns(l - 1, v);
+        self.t[(l, l - 1)] = T::zero();
+    }
+
+    /// Retrieves the left and right matrices of Schur vectors `VSL` and `VSR`, the
+    /// upper-quasitriangular matrix `S` and upper-triangular matrix `T` such that the
+    /// decomposed matrix `a` equals `VSL * S * VSR.transpose()` and the decomposed matrix `b`
+    /// equals `VSL * T * VSR.transpose()`.
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+    ) {
+        (self.vsl, self.s, self.t, self.vsr)
+    }
+
+    /// Outputs the generalized eigenvalues as pairs `(alpha, beta)`.
+    ///
+    /// Each generalized eigenvalue equals `alpha / beta`, where `beta` is non-negative. An
+    /// infinite eigenvalue has a `beta` equal to zero. Pairs of complex conjugate eigenvalues
+    /// are stored contiguously, the one with a positive imaginary part first.
+    #[must_use]
+    pub fn raw_eigenvalues(&self) -> OVector<(Complex<T>, T), D>
+    where
+        DefaultAllocator: Allocator<(Complex<T>, T), D>,
+    {
+        let dim = self.s.nrows();
+        let mut out = Matrix::from_element_generic(
+            self.s.shape_generic().0,
+            Const::<1>,
+            (Complex::zero(), T::zero()),
+        );
+
+        let mut i = 0;
+        while i < dim {
+            if i + 1 < dim && !self.s[(i + 1, i)].is_zero() {
+                // Roots of det(S - λT) = a λ² + b λ + c for the 2x2 diagonal block.
+                let (s00, s01, s10, s11) = (
+                    self.s[(i, i)].clone(),
+                    self.s[(i, i + 1)].clone(),
+                    self.s[(i + 1, i)].clone(),
+                    self.s[(i + 1, i + 1)].clone(),
+                );
+                let (t00, t01, t11) = (
+                    self.t[(i, i)].clone(),
+                    self.t[(i, i + 1)].clone(),
+                    self.t[(i + 1, i + 1)].clone(),
+                );
+
+                let a = t00.clone() * t11.clone();
+                let b = t01 * s10.clone() - s00.clone() * t11 - s11.clone() * t00;
+                let c = s00 * s11 - s01 * s10;
+
+                let two_a = a.clone() * crate::convert(2.0);
+                let re = -b.clone() / two_a.clone();
+                let discr = b.clone() * b - a.clone() * c * crate::convert(4.0);
+                let beta = a.abs().sqrt();
+
+                let (l0, l1) = if discr < T::zero() {
+                    let im = (-discr).sqrt() / two_a.abs();
+                    (Complex::new(re.clone(), im.clone()), Complex::new(re, -im))
+                } else {
+                    let d = discr.sqrt() / two_a;
+                    (
+                        Complex::new(re.clone() + d.clone(), T::zero()),
+                        Complex::new(re - d, T::zero()),
+                    )
+                };
+
+                out[i] = (l0 * beta.clone(), beta.clone());
+                out[i + 1] = (l1 * beta.clone(), beta);
+                i += 2;
+            } else {
+                let (mut alpha, mut beta) = (self.s[(i, i)].clone(), self.t[(i, i)].clone());
+                if beta < T::zero() {
+                    alpha = -alpha;
+                    beta = -beta;
+                }
+
+                out[i] = (Complex::new(alpha, T::zero()), beta);
+                i += 1;
+            }
+        }
+
+        out
+    }
+}
+
+/// Generalized eigendecomposition of a pair of real square matrices.
+///
+/// Computes the generalized eigenvalues `λ` of the pencil `(a, b)` together with the right
+/// eigenvectors `x` and the left eigenvectors `y` such that `a * x = λ * b * x` and
+/// `yᴴ * a = λ * yᴴ * b`. The eigenvectors are computed by back-substitution on the
+/// quasi-triangular pencil `(S, T)` of the QZ decomposition of `(a, b)`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<(Complex<T>, T), D>,
+         OVector<(Complex<T>, T), D>: Serialize,
+         OMatrix<Complex<T>, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<(Complex<T>, T), D>,
+         OVector<(Complex<T>, T), D>: Deserialize<'de>,
+         OMatrix<Complex<T>, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedEigen<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<(Complex<T>, T), D>,
+{
+    /// The unsorted generalized eigenvalues, as pairs `(alpha, beta)`.
+    ///
+    /// See [`QZ::raw_eigenvalues`] for their meaning and ordering.
+    pub eigenvalues: OVector<(Complex<T>, T), D>,
+
+    /// The left generalized eigenvectors of the decomposed pencil.
+    ///
+    /// The `i`-th column has a unit norm and is associated to the `i`-th eigenvalue.
+    pub left_eigenvectors: OMatrix<Complex<T>, D, D>,
+
+    /// The right generalized eigenvectors of the decomposed pencil.
+    ///
+    /// The `i`-th column has a unit norm and is associated to the `i`-th eigenvalue.
+    pub right_eigenvectors: OMatrix<Complex<T>, D, D>,
+}
+
+impl<T: RealField, D: Dim> Copy for GeneralizedEigen<T, D>
+where
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<(Complex<T>, T), D>,
+    OMatrix<Complex<T>, D, D>: Copy,
+    OVector<(Complex<T>, T), D>: Copy,
+{
+}
+
+impl<T: RealField, D: DimMin<D, Output = D>> GeneralizedEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<Complex<T>, D, D>
+        + Allocator<Complex<T>, D>
+        + Allocator<(Complex<T>, T), D>,
+{
+    /// Computes the generalized eigendecomposition of the real square matrices `a` and `b`.
+    ///
+    /// Panics if the method did not converge.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Self {
+        Self::try_new(a, b, T::default_epsilon(), 0)
+            .expect("Generalized eigendecomposition: convergence failed.")
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the real square matrices `a`
+    /// and `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the
+    ///   algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let qz = QZ::try_new(a, b, eps, max_niter)?;
+        let eigenvalues = qz.raw_eigenvalues();
+        let (vsl, s, t, vsr) = qz.unpack();
+
+        let dim = s.nrows();
+        let (nrows, ncols) = s.shape_generic();
+        let sc: OMatrix<Complex<T>, D, D> = s.map(Complex::from_real);
+        let tc: OMatrix<Complex<T>, D, D> = t.map(Complex::from_real);
+        let vslc: OMatrix<Complex<T>, D, D> = vsl.map(Complex::from_real);
+        let vsrc: OMatrix<Complex<T>, D, D> = vsr.map(Complex::from_real);
+
+        // Perturbation used in place of the (near) zero pivots appearing with repeated
+        // eigenvalues, as done by LAPACK's `tgevc`.
+        let ulp = T::default_epsilon();
+        let (s_amax, t_amax) = (s.amax(), t.amax());
+
+        let mut left_eigenvectors = OMatrix::zeros_generic(nrows, ncols);
+        let mut right_eigenvectors = OMatrix::zeros_generic(nrows, ncols);
+        let mut m: OMatrix<Complex<T>, D, D> = OMatrix::zeros_generic(nrows, ncols);
+        let mut x: OVector<Complex<T>, D> = OVector::zeros_generic(nrows, U1);
+
+        let mut k = 0;
+        while k < dim {
+            let size = block_size_at(&s, k);
+            let end = k + size;
+
+            for l in 0..size {
+                if l == 1 {
+                    // The second eigenvectors of a 2x2 block are the conjugates of the first ones.
+                    let first = left_eigenvectors.column(k).map(|e: Complex<T>| e.conj());
+                    left_eigenvectors.column_mut(k + 1).copy_from(&first);
+                    let first = right_eigenvectors.column(k).map(|e: Complex<T>| e.conj());
+                    right_eigenvectors.column_mut(k + 1).copy_from(&first);
+                    continue;
+                }
+
+                // The pencil `M = β S - α T` is singular for the eigenvalue `λ = α / β`.
+                let (alpha, beta) = eigenvalues[k].clone();
+                let mut smin = ulp.clone()
+                    * (beta.clone().abs() * s_amax.clone()
+                        + alpha.clone().norm1() * t_amax.clone());
+                if smin.is_zero() {
+                    smin = ulp.clone();
+                }
+
+                m.copy_from(&tc);
+                m *= -alpha;
+                m.zip_apply(&sc, |e, s| *e += s.scale(beta.clone()));
+
+                // Right eigenvector: back-substitution on the blocks of `M y = 0`, starting from
+                // a null vector of the diagonal block.
+                x.fill(Complex::zero());
+                if size == 1 {
+                    x[k] = Complex::from_real(T::one());
+                } else {
+                    x[k] = -m[(k + 1, k + 1)].clone();
+                    x[k + 1] = m[(k + 1, k)].clone();
+                }
+
+                let mut i_end = k;
+                while i_end > 0 {
+                    let p = block_size_before(&s, i_end);
+                    let i = i_end - p;
+
+                    let r0 = -m
+                        .view_range(i, i_end..end)
+                        .tr_dot(&x.rows_range(i_end..end));
+                    if p == 1 {
+                        let d = perturbed_pivot(m[(i, i)].clone(), &smin);
+                        x[i] = r0 / d;
+                    } else {
+                        let r1 = -m
+                            .view_range(i + 1, i_end..end)
+                            .tr_dot(&x.rows_range(i_end..end));
+                        let a = m[(i, i)].clone();
+                        let b = m[(i, i + 1)].clone();
+                        let c = m[(i + 1, i)].clone();
+                        let d = m[(i + 1, i + 1)].clone();
+                        let det =
+                            perturbed_pivot(a.clone() * d.clone() - b.clone() * c.clone(), &smin);
+
+                        x[i] = (d * r0.clone() - b * r1.clone()) / det.clone();
+                        x[i + 1] = (a * r1 - c * r0) / det;
+                    }
+
+                    i_end = i;
+                }
+
+                let mut v = right_eigenvectors.column_mut(k);
+                v.gemv(
+                    Complex::from_real(T::one()),
+                    &vsrc.columns_range(..end),
+                    &x.rows_range(..end),
+                    Complex::zero(),
+                );
+                let _ = v.normalize_mut();
+
+                // Left eigenvector: forward substitution on the blocks of `zᵀ M = 0`, starting
+                // from a left null vector of the diagonal block. Then `y = VSL * conj(z)`.
+                x.fill(Complex::zero());
+                if size == 1 {
+                    x[k] = Complex::from_real(T::one());
+                } else {
+                    x[k] = m[(k + 1, k)].clone();
+                    x[k + 1] = -m[(k, k)].clone();
+                }
+
+                let mut j = end;
+                while j < dim {
+                    let q = block_size_at(&s, j);
+
+                    let r0 = -m.view_range(k..j, j).dot(&x.rows_range(k..j));
+                    if q == 1 {
+                        let d = perturbed_pivot(m[(j, j)].clone(), &smin);
+                        x[j] = r0 / d;
+                    } else {
+                        let r1 = -m.view_range(k..j, j + 1).dot(&x.rows_range(k..j));
+                        let a = m[(j, j)].clone();
+                        let b = m[(j, j + 1)].clone();
+                        let c = m[(j + 1, j)].clone();
+                        let d = m[(j + 1, j + 1)].clone();
+                        let det =
+                            perturbed_pivot(a.clone() * d.clone() - b.clone() * c.clone(), &smin);
+
+                        x[j] = (d * r0.clone() - c * r1.clone()) / det.clone();
+                        x[j + 1] = (a * r1 - b * r0) / det;
+                    }
+
+                    j += q;
+                }
+
+                x.conjugate_mut();
+                let mut v = left_eigenvectors.column_mut(k);
+                v.gemv(
+                    Complex::from_real(T::one()),
+                    &vslc.columns_range(k..),
+                    &x.rows_range(k..),
+                    Complex::zero(),
+                );
+                let _ = v.normalize_mut();
+            }
+
+            k = end;
+        }
+
+        Some(GeneralizedEigen {
+            eigenvalues,
+            left_eigenvectors,
+            right_eigenvectors,
+        })
+    }
+}
+
+/// Replaces the pivot `d` by `smin` if its magnitude is smaller than `smin`.
+fn perturbed_pivot<T: RealField>(d: Complex<T>, smin: &T) -> Complex<T> {
+    if d.clone().norm1() < *smin {
+        Complex::from_real(smin.clone())
+    } else {
+        d
+    }
+}
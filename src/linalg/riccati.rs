@@ -0,0 +1,543 @@
+//! Solvers of the continuous and discrete algebraic Riccati equations.
+
+use approx::AbsDiffEq;
+use std::fmt;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, DefaultAllocator, Matrix, Matrix2, OMatrix, Vector2};
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::schur::{
+    apply_on_the_left, apply_on_the_right, block2, block_size_at, block_size_before, reorder_schur,
+    triangularizing_rotations,
+};
+use crate::linalg::{Schur, QZ};
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+/// The maximum number of QR or QZ iterations performed per eigenvalue of the Hamiltonian matrix
+/// or symplectic pencil, as done by LAPACK.
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// Possible errors produced by the algebraic Riccati equation solvers.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub enum RiccatiError {
+    /// The input weight matrix `R` is singular.
+    SingularInputWeight,
+    /// The equation has no stabilizing solution, e.g., because the system is not stabilizable
+    /// or not detectable.
+    NoStabilizingSolution,
+    /// The Schur or QZ decomposition did not converge, e.g., because an input contains
+    /// non-finite values.
+    NoConvergence,
+}
+
+impl fmt::Display for RiccatiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RiccatiError::SingularInputWeight => {
+                write!(f, "The input weight matrix is singular")
+            }
+            RiccatiError::NoStabilizingSolution => {
+                write!(f, "The Riccati equation has no stabilizing solution")
+            }
+            RiccatiError::NoConvergence => {
+                write!(f, "The Schur or QZ decomposition did not converge")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RiccatiError {}
+
+/// The stabilizing solution of an algebraic Riccati equation.
+#[derive(Clone, Debug)]
+pub struct RiccatiSolution<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// The stabilizing solution `X` of the Riccati equation.
+    pub solution: OMatrix<T, D, D>,
+    /// The Frobenius norm of the left-hand side of the Riccati equation evaluated at `X`.
+    ///
+    /// This should be close to zero, unless the equation is ill-conditioned.
+    pub residual_norm: T::RealField,
+}
+
+/// Re-triangularizes the 2x2 diagonal block starting at `i` of the upper-triangular matrix `t`
+/// of the pencil `(s, t)` by rotating its rows, and accumulates the rotation into `q`.
+fn retriangularize_block<T: ComplexField>(
+    s: &mut DMatrix<T>,
+    t: &mut DMatrix<T>,
+    q: &mut DMatrix<T>,
+    i: usize,
+) {
+    let g = Vector2::new(t[(i, i)].clone(), t[(i + 1, i)].clone());
+
+    if let Some((rot, _)) = GivensRotation::cancel_y(&g) {
+        rot.rotate(&mut s.fixed_rows_mut::<2>(i));
+        rot.rotate(&mut t.fixed_rows_mut::<2>(i));
+        rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<2>(i));
+    }
+
+    t[(i + 1, i)] = T::zero();
+}
+
+/// Swaps the adjacent diagonal blocks of sizes `p` and `q` starting at the index `k` of the
+/// generalized Schur form `(s, t)`, and accumulates the unitary transformations into the left
+/// and right Schur vectors `vsl` and `vsr`.
+///
+/// Returns `false`, leaving all the matrices untouched, if the two blocks have a common
+/// generalized eigenvalue or if the swap would not be numerically stable.
+fn swap_pencil_blocks<T: ComplexField>(
+    s: &mut DMatrix<T>,
+    t: &mut DMatrix<T>,
+    vsl: &mut DMatrix<T>,
+    vsr: &mut DMatrix<T>,
+    k: usize,
+    p: usize,
+    q: usize,
+) -> bool {
+    let m = p + q;
+    let pq = p * q;
+    let ws = s.view((k, k), (m, m)).clone_owned();
+    let wt = t.view((k, k), (m, m)).clone_owned();
+
+    // Solve the generalized Sylvester equation `S₁₁ * R - L * S₂₂ = -S₁₂`,
+    // `T₁₁ * R - L * T₂₂ = -T₁₂` in Kronecker form, for the unknowns `vec(R)` and `vec(L)`.
+    let mut sys = DMatrix::zeros(2 * pq, 2 * pq);
+    let mut rhs = DVector::zeros(2 * pq);
+
+    for (offset, w) in [(0, &ws), (pq, &wt)] {
+        for c in 0..q {
+            for l in 0..p {
+                let row = offset + l + c * p;
+                rhs[row] = -w[(l, p + c)].clone();
+
+                for i in 0..p {
+                    sys[(row, i + c * p)] += w[(l, i)].clone();
+                }
+                for i in 0..q {
+                    sys[(row, pq + l + i * p)] -= w[(p + i, p + c)].clone();
+                }
+            }
+        }
+    }
+
+    let sol = match sys.full_piv_lu().solve(&rhs) {
+        Some(sol) => sol,
+        None => return false,
+    };
+
+    // The columns of `[R; I]` and `[L; I]` span the right and left deflating subspaces
+    // associated to the eigenvalues of `(S₂₂, T₂₂)`.
+    let mut right = DMatrix::zeros(m, q);
+    let mut left = DMatrix::zeros(m, q);
+    for c in 0..q {
+        for l in 0..p {
+            right[(l, c)] = sol[l + c * p].clone();
+            left[(l, c)] = sol[pq + l + c * p].clone();
+        }
+    }
+    right.view_mut((p, 0), (q, q)).fill_with_identity();
+    left.view_mut((p, 0), (q, q)).fill_with_identity();
+
+    let z = triangularizing_rotations(right);
+    let mut u = triangularizing_rotations(left);
+    let mut new_s = u.ad_mul(&ws) * &z;
+    let mut new_t = u.ad_mul(&wt) * &z;
+
+    for (i, size) in [(0, q), (q, p)] {
+        if size == 2 {
+            retriangularize_block(&mut new_s, &mut new_t, &mut u, i);
+        }
+    }
+
+    // Reject the swap if the transformed pencil does not come out as block upper triangular.
+    let threshold = T::RealField::default_epsilon() * crate::convert::<f64, T::RealField>(10.0);
+    if new_s.view((q, 0), (p, q)).norm() > threshold.clone() * ws.norm()
+        || new_t.view((q, 0), (p, q)).norm() > threshold * wt.norm()
+    {
+        return false;
+    }
+
+    for mat in [&mut *s, &mut *t] {
+        apply_on_the_left(mat, &u, k);
+        apply_on_the_right(mat, &z, k);
+    }
+    apply_on_the_right(vsl, &u, k);
+    apply_on_the_right(vsr, &z, k);
+
+    s.view_mut((k + q, k), (p, q)).fill(T::zero());
+    t.view_mut((k + q, k), (p, q)).fill(T::zero());
+    for (i, size) in [(k, q), (k + q, p)] {
+        if size == 2 {
+            t[(i + 1, i)] = T::zero();
+        }
+    }
+
+    true
+}
+
+/// Reorders the generalized Schur decomposition `(VSL * S * VSRᴴ, VSL * T * VSRᴴ)` so that the
+/// diagonal blocks of the pencil `(S, T)` for which `select` returns `true` come first.
+///
+/// Returns the number of selected eigenvalues, or `None` if the reordering failed.
+fn reorder_qz<T: ComplexField>(
+    s: &mut DMatrix<T>,
+    t: &mut DMatrix<T>,
+    vsl: &mut DMatrix<T>,
+    vsr: &mut DMatrix<T>,
+    select: impl Fn(&Matrix2<T>, &Matrix2<T>, usize) -> bool,
+) -> Option<usize> {
+    let dim = s.nrows();
+    let mut k = 0;
+    let mut i = 0;
+
+    while i < dim {
+        let p = block_size_at(s, i);
+
+        if select(&block2(s, i, i, p, p), &block2(t, i, i, p, p), p) {
+            // Bubble the selected block up to the position `k`.
+            let mut j = i;
+            while j > k {
+                let q = block_size_before(s, j);
+
+                if !swap_pencil_blocks(s, t, vsl, vsr, j - q, q, p) {
+                    return None;
+                }
+
+                j -= q;
+            }
+
+            k += p;
+        }
+
+        i += p;
+    }
+
+    Some(k)
+}
+
+/// The maximum total number of iterations of the Schur or QZ decomposition of a matrix or
+/// pencil of dimension `dim`.
+fn max_niter(dim: usize) -> usize {
+    MAX_ITERATIONS_PER_EIGENVALUE * dim.max(10)
+}
+
+/// Computes `X = U₂₁ * U₁₁⁻¹` from the basis `[U₁₁; U₂₁]` of the stable invariant subspace of
+/// the Hamiltonian or symplectic matrix, and symmetrizes the result.
+fn stable_subspace_solution<T: ComplexField>(
+    u: &DMatrix<T>,
+    n: usize,
+) -> Result<DMatrix<T>, RiccatiError> {
+    let u11 = u.view_range(..n, ..n);
+    let u21 = u.view_range(n.., ..n);
+
+    // `X * U₁₁ = U₂₁` is equivalent to `U₁₁ᴴ * Xᴴ = U₂₁ᴴ`. If the system is not stabilizable or
+    // not detectable, `U₁₁` is singular, though rarely exactly because of rounding errors.
+    let lu = u11.adjoint().lu();
+
+    if lu.rcond() <= T::RealField::default_epsilon() {
+        return Err(RiccatiError::NoStabilizingSolution);
+    }
+
+    let xh = lu
+        .solve(&u21.adjoint())
+        .ok_or(RiccatiError::NoStabilizingSolution)?;
+
+    Ok((&xh + xh.adjoint()) * T::from_subset(&0.5))
+}
+
+/// Returns the norm of the residual of a Riccati equation, or an error if it exceeds `ε^(1/4)`
+/// relative to `scale`, the sum of the norms of the terms of the equation.
+///
+/// The residual of ill-conditioned equations can be much larger than `ε` relative to `scale`,
+/// but a residual this large means that the computed subspace did not yield a solution, e.g.,
+/// because the equation is too close to having no stabilizing solution.
+fn check_residual<T: RealField>(residual_norm: T, scale: T) -> Result<T, RiccatiError> {
+    if residual_norm <= T::default_epsilon().sqrt().sqrt() * scale {
+        Ok(residual_norm)
+    } else {
+        Err(RiccatiError::NoStabilizingSolution)
+    }
+}
+
+fn to_dynamic<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>>(
+    m: &Matrix<T, R, C, S>,
+) -> DMatrix<T> {
+    DMatrix::from_iterator(m.nrows(), m.ncols(), m.iter().cloned())
+}
+
+fn from_dynamic<T: ComplexField, D: Dim>(m: &DMatrix<T>, dim: D) -> OMatrix<T, D, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    OMatrix::from_fn_generic(dim, dim, |i, j| m[(i, j)].clone())
+}
+
+/// Computes the stabilizing solution of the continuous algebraic Riccati equation and its
+/// residual norm.
+fn solve_care<T: ComplexField>(
+    a: DMatrix<T>,
+    b: DMatrix<T>,
+    q: DMatrix<T>,
+    r: DMatrix<T>,
+) -> Result<(DMatrix<T>, T::RealField), RiccatiError> {
+    let n = a.nrows();
+
+    let g = &b
+        * r.lu()
+            .solve(&b.adjoint())
+            .ok_or(RiccatiError::SingularInputWeight)?;
+
+    let mut x = DMatrix::zeros(n, n);
+
+    if n > 0 {
+        // The Hamiltonian matrix `[A -G; -Q -Aᴴ]`.
+        let mut h = DMatrix::zeros(2 * n, 2 * n);
+        h.view_mut((0, 0), (n, n)).copy_from(&a);
+        h.view_mut((0, n), (n, n)).copy_from(&-&g);
+        h.view_mut((n, 0), (n, n)).copy_from(&-&q);
+        h.view_mut((n, n), (n, n)).copy_from(&-a.adjoint());
+
+        let (mut u, mut t) = Schur::try_new(h, T::RealField::default_epsilon(), max_niter(2 * n))
+            .ok_or(RiccatiError::NoConvergence)?
+            .unpack();
+        let stable = reorder_schur(&mut t, &mut u, |block, size| {
+            // The real part of the eigenvalues of the block.
+            let re = if size == 1 {
+                block[(0, 0)].clone().real()
+            } else {
+                block.trace().real()
+            };
+            re < T::RealField::zero()
+        });
+
+        if stable != Some(n) {
+            return Err(RiccatiError::NoStabilizingSolution);
+        }
+
+        x = stable_subspace_solution(&u, n)?;
+    }
+
+    // `X` is hermitian, so `Aᴴ * X` is the adjoint of `X * A`.
+    let xa = &x * &a;
+    let xgx = &x * &g * &x;
+    let residual = xa.adjoint() + &xa - &xgx + &q;
+    let scale = xa.norm() * crate::convert(2.0) + xgx.norm() + q.norm();
+    let residual_norm = check_residual(residual.norm(), scale)?;
+
+    Ok((x, residual_norm))
+}
+
+/// The real matrix `[Re(M) -Im(M); Im(M) Re(M)]` representing the complex square matrix `M`.
+fn real_embedding<T: ComplexField>(m: &DMatrix<T>) -> DMatrix<T::RealField> {
+    let n = m.nrows();
+
+    DMatrix::from_fn(2 * n, 2 * n, |i, j| {
+        let e = m[(i % n, j % n)].clone();
+        match (i < n, j < n) {
+            (true, false) => -e.imaginary(),
+            (false, true) => e.imaginary(),
+            _ => e.real(),
+        }
+    })
+}
+
+/// Computes the stabilizing solution of the real discrete algebraic Riccati equation from the
+/// stable deflating subspace of the symplectic pencil `[A 0; -Q I] - λ [I G; 0 Aᵀ]`.
+///
+/// Unlike the symplectic matrix `[I G; 0 Aᵀ]⁻¹ * [A 0; -Q I]`, the pencil is well defined even
+/// if `A` is singular, in which case it has both zero and infinite generalized eigenvalues.
+fn stable_deflating_solution<T: RealField>(
+    a: &DMatrix<T>,
+    g: &DMatrix<T>,
+    q: &DMatrix<T>,
+) -> Result<DMatrix<T>, RiccatiError> {
+    let n = a.nrows();
+
+    let mut m = DMatrix::zeros(2 * n, 2 * n);
+    m.view_mut((0, 0), (n, n)).copy_from(a);
+    m.view_mut((n, 0), (n, n)).copy_from(&-q);
+    m.view_mut((n, n), (n, n)).fill_with_identity();
+
+    let mut l = DMatrix::identity(2 * n, 2 * n);
+    l.view_mut((0, n), (n, n)).copy_from(g);
+    l.view_mut((n, n), (n, n)).copy_from(&a.transpose());
+
+    let (mut vsl, mut s, mut t, mut vsr) =
+        QZ::try_new(m, l, T::default_epsilon(), max_niter(2 * n))
+            .ok_or(RiccatiError::NoConvergence)?
+            .unpack();
+    let stable = reorder_qz(&mut s, &mut t, &mut vsl, &mut vsr, |s, t, size| {
+        // Compare the squared modulus of the eigenvalues of the block to 1, without dividing
+        // by the possibly zero diagonal of `T`.
+        if size == 1 {
+            s[(0, 0)].clone().abs() < t[(0, 0)].clone().abs()
+        } else {
+            s.determinant().abs() < t.determinant().abs()
+        }
+    });
+
+    if stable != Some(n) {
+        return Err(RiccatiError::NoStabilizingSolution);
+    }
+
+    stable_subspace_solution(&vsr, n)
+}
+
+/// Computes the stabilizing solution of the discrete algebraic Riccati equation and its
+/// residual norm.
+fn solve_dare<T: ComplexField>(
+    a: DMatrix<T>,
+    b: DMatrix<T>,
+    q: DMatrix<T>,
+    r: DMatrix<T>,
+) -> Result<(DMatrix<T>, T::RealField), RiccatiError> {
+    let n = a.nrows();
+
+    let g = &b
+        * r.clone()
+            .lu()
+            .solve(&b.adjoint())
+            .ok_or(RiccatiError::SingularInputWeight)?;
+
+    let mut x = DMatrix::zeros(n, n);
+
+    if n > 0 {
+        let is_real = [&a, &g, &q]
+            .iter()
+            .all(|m| m.iter().all(|e| e.clone().imaginary().is_zero()));
+
+        x = if is_real {
+            let real = |m: &DMatrix<T>| m.map(|e| e.real());
+            stable_deflating_solution(&real(&a), &real(&g), &real(&q))?.map(T::from_real)
+        } else {
+            // The real embedding of the solution of the complex equation is the solution of
+            // the equation on the real embeddings of `A`, `G` and `Q`.
+            let embedded = stable_deflating_solution(
+                &real_embedding(&a),
+                &real_embedding(&g),
+                &real_embedding(&q),
+            )?;
+            let i = T::from_real(-T::RealField::one()).sqrt();
+            embedded.view((0, 0), (n, n)).map(T::from_real)
+                + embedded.view((n, 0), (n, n)).map(T::from_real) * i
+        };
+    }
+
+    let bh_x = b.ad_mul(&x);
+    let gain = (r + &bh_x * &b)
+        .lu()
+        .solve(&(&bh_x * &a))
+        .ok_or(RiccatiError::NoStabilizingSolution)?;
+    let ahx = a.ad_mul(&x);
+    let ahxa = &ahx * &a;
+    let ahxbk = &ahx * &b * gain;
+    let residual = &ahxa - &ahxbk - &x + &q;
+    let scale = ahxa.norm() + ahxbk.norm() + x.norm() + q.norm();
+    let residual_norm = check_residual(residual.norm(), scale)?;
+
+    Ok((x, residual_norm))
+}
+
+fn check_dimensions<T, D: Dim, M: Dim>(
+    a: &Matrix<T, D, D, impl Storage<T, D, D>>,
+    b: &Matrix<T, D, M, impl Storage<T, D, M>>,
+    q: &Matrix<T, D, D, impl Storage<T, D, D>>,
+    r: &Matrix<T, M, M, impl Storage<T, M, M>>,
+) {
+    let (n, m) = b.shape();
+    assert!(
+        a.shape() == (n, n) && q.shape() == (n, n) && r.shape() == (m, m),
+        "Riccati equation: dimension mismatch."
+    );
+}
+
+/// Solves the continuous algebraic Riccati equation `Aᴴ * X + X * A - X * B * R⁻¹ * Bᴴ * X + Q = 0`,
+/// where `X` is the unknown to be determined.
+///
+/// This computes the stabilizing solution, i.e., the one such that `A - B * R⁻¹ * Bᴴ * X` has
+/// all its eigenvalues in the open left half-plane, from the ordered Schur decomposition of the
+/// associated Hamiltonian matrix. The optimal LQR gain is then `K = R⁻¹ * Bᴴ * X`.
+///
+/// For real matrices, `Aᴴ` is the transpose of `A`. Usually, `Q` is hermitian positive
+/// semi-definite and `R` is hermitian positive-definite.
+///
+/// Returns [`RiccatiError::NoStabilizingSolution`] if the stable invariant subspace does not
+/// yield a solution, or if the residual of the computed solution is not small relative to the
+/// terms of the equation. Returns [`RiccatiError::NoConvergence`] if the Schur decomposition
+/// did not converge.
+pub fn solve_continuous_riccati<T, D, M, SA, SB, SQ, SR>(
+    a: &Matrix<T, D, D, SA>,
+    b: &Matrix<T, D, M, SB>,
+    q: &Matrix<T, D, D, SQ>,
+    r: &Matrix<T, M, M, SR>,
+) -> Result<RiccatiSolution<T, D>, RiccatiError>
+where
+    T: ComplexField,
+    D: Dim,
+    M: Dim,
+    SA: Storage<T, D, D>,
+    SB: Storage<T, D, M>,
+    SQ: Storage<T, D, D>,
+    SR: Storage<T, M, M>,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    check_dimensions(a, b, q, r);
+
+    let (x, residual_norm) =
+        solve_care(to_dynamic(a), to_dynamic(b), to_dynamic(q), to_dynamic(r))?;
+
+    Ok(RiccatiSolution {
+        solution: from_dynamic(&x, a.shape_generic().0),
+        residual_norm,
+    })
+}
+
+/// Solves the discrete algebraic Riccati equation
+/// `Aᴴ * X * A - X - Aᴴ * X * B * (R + Bᴴ * X * B)⁻¹ * Bᴴ * X * A + Q = 0`, where `X` is the
+/// unknown to be determined.
+///
+/// This computes the stabilizing solution, i.e., the one such that
+/// `A - B * (R + Bᴴ * X * B)⁻¹ * Bᴴ * X * A` has all its eigenvalues inside of the unit disk,
+/// from the ordered QZ decomposition of the symplectic pencil associated to the equation.
+/// The optimal LQR gain is then `K = (R + Bᴴ * X * B)⁻¹ * Bᴴ * X * A`.
+///
+/// For real matrices, `Aᴴ` is the transpose of `A`. Usually, `Q` is hermitian positive
+/// semi-definite and `R` is hermitian positive-definite. The state matrix `A` may be singular.
+///
+/// Returns [`RiccatiError::NoStabilizingSolution`] if the stable deflating subspace does not
+/// yield a solution, or if the residual of the computed solution is not small relative to the
+/// terms of the equation. Returns [`RiccatiError::NoConvergence`] if the QZ decomposition
+/// did not converge.
+pub fn solve_discrete_riccati<T, D, M, SA, SB, SQ, SR>(
+    a: &Matrix<T, D, D, SA>,
+    b: &Matrix<T, D, M, SB>,
+    q: &Matrix<T, D, D, SQ>,
+    r: &Matrix<T, M, M, SR>,
+) -> Result<RiccatiSolution<T, D>, RiccatiError>
+where
+    T: ComplexField,
+    D: Dim,
+    M: Dim,
+    SA: Storage<T, D, D>,
+    SB: Storage<T, D, M>,
+    SQ: Storage<T, D, D>,
+    SR: Storage<T, M, M>,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    check_dimensions(a, b, q, r);
+
+    let (x, residual_norm) =
+        solve_dare(to_dynamic(a), to_dynamic(b), to_dynamic(q), to_dynamic(r))?;
+
+    Ok(RiccatiSolution {
+        solution: from_dynamic(&x, a.shape_generic().0),
+        residual_norm,
+    })
+}
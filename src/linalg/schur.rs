@@ -10,7 +10,12 @@ use std::cmp;
 use crate::allocator::Allocator;
 use crate::base::dimension::{Const, Dim, DimDiff, DimSub, Dyn, U1, U2};
 use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, OMatrix, OVector, SquareMatrix, Unit, Vector2, Vector3};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
+use crate::base::{
+    DefaultAllocator, Matrix2, Matrix4, OMatrix, OVector, SquareMatrix, Unit, Vector2, Vector3,
+    Vector4,
+};
 
 use crate::geometry::Reflection;
 use crate::linalg::givens::GivensRotation;
@@ -130,10 +135,13 @@ where
 
         // Implicit double-shift QR method.
         let mut niter = 0;
+        // Number of iterations since the last deflation.
+        let mut its = 0;
         let (mut start, mut end) = Self::delimit_subproblem(&mut t, eps.clone(), dim.value() - 1);
 
         while end != start {
             let subdim = end - start + 1;
+            let prev_end = end;
 
             if subdim > 2 {
                 let m = end - 1;
@@ -150,8 +158,17 @@ where
                 let hnm = t[(n, m)].clone();
                 let hmn = t[(m, n)].clone();
 
-                let tra = hnn.clone() + hmm.clone();
-                let det = hnn * hmm - hnm * hmn;
+                let (tra, det) = if its > 0 && its % 10 == 0 {
+                    // Exceptional shift, as done by LAPACK, to break the cycles the standard
+                    // shifts can fall into.
+                    let s = hnm.abs() + t[(m, m - 1)].clone().abs();
+                    let h = T::from_real(s.clone() * crate::convert(0.75)) + hnn;
+                    let tra = h.clone() + h.clone();
+                    let det = h.clone() * h + T::from_real(s.clone() * s * crate::convert(0.4375));
+                    (tra, det)
+                } else {
+                    (hnn.clone() + hmm.clone(), hnn * hmm - hnm * hmn)
+                };
 
                 let mut axis = Vector3::new(
                     h11.clone() * h11.clone() + h12 * h21.clone() - tra.clone() * h11.clone() + det,
@@ -256,6 +273,12 @@ where
             start = sub.0;
             end = sub.1;
 
+            if end == prev_end {
+                its += 1;
+            } else {
+                its = 0;
+            }
+
             niter += 1;
             if niter == max_niter {
                 return None;
@@ -272,7 +295,7 @@ where
         let dim = t.nrows();
         let mut m = 0;
 
-        while m < dim - 1 {
+        while m + 1 < dim {
             let n = m + 1;
 
             if t[(n, m)].is_zero() {
@@ -284,7 +307,7 @@ where
             }
         }
 
-        if m == dim - 1 {
+        if m + 1 == dim {
             out[m] = t[(m, m)].clone();
         }
 
@@ -300,7 +323,7 @@ where
         let dim = t.nrows();
         let mut m = 0;
 
-        while m < dim - 1 {
+        while m + 1 < dim {
             let n = m + 1;
 
             if t[(n, m)].is_zero() {
@@ -332,7 +355,7 @@ where
             }
         }
 
-        if m == dim - 1 {
+        if m + 1 == dim {
             out[m] = MaybeUninit::new(NumComplex::new(t[(m, m)].clone(), T::zero()));
         }
     }
@@ -505,6 +528,239 @@ fn compute_2x2_basis<T: ComplexField, S: Storage<T, U2, U2>>(
     }
 }
 
+/// Size (1 or 2) of the diagonal block of the quasi-triangular `t` starting at `i`.
+pub(crate) fn block_size_at<T: ComplexField, D: Dim>(t: &OMatrix<T, D, D>, i: usize) -> usize
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    if i + 1 < t.nrows() && !t[(i + 1, i)].is_zero() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Size (1 or 2) of the diagonal block of the quasi-triangular `t` ending right before `end`.
+pub(crate) fn block_size_before<T: ComplexField, D: Dim>(t: &OMatrix<T, D, D>, end: usize) -> usize
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    if end >= 2 && !t[(end - 1, end - 2)].is_zero() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Solves the small Sylvester equation `a * x + x * b = r` where `x` and `r` are `p × q`
+/// matrices stored in the top-left corner of a `Matrix2`, `a` is `p × p` and `b` is `q × q`.
+pub(crate) fn solve_small_sylvester<T: ComplexField>(
+    a: &Matrix2<T>,
+    b: &Matrix2<T>,
+    r: &Matrix2<T>,
+    p: usize,
+    q: usize,
+) -> Option<Matrix2<T>> {
+    // Solve the Kronecker form `(I ⊗ a + bᵀ ⊗ I) vec(x) = vec(r)`, padded to 4x4.
+    let mut k = Matrix4::<T>::zeros();
+    let mut rhs = Vector4::<T>::zeros();
+
+    for c in 0..q {
+        for l in 0..p {
+            let row = l + c * p;
+            rhs[row] = r[(l, c)].clone();
+
+            for m in 0..p {
+                k[(row, m + c * p)] += a[(l, m)].clone();
+            }
+            for m in 0..q {
+                k[(row, l + m * p)] += b[(m, c)].clone();
+            }
+        }
+    }
+
+    for i in p * q..4 {
+        k[(i, i)] = T::one();
+    }
+
+    let sol = k.lu().solve(&rhs)?;
+    let mut x = Matrix2::<T>::zeros();
+
+    for c in 0..q {
+        for l in 0..p {
+            x[(l, c)] = sol[l + c * p].clone();
+        }
+    }
+
+    Some(x)
+}
+
+/// Threshold `sqrt(ε) * ‖t‖` below which an eigenvalue of the quasi-triangular `t` may be
+/// considered to be zero.
+pub(crate) fn zero_eigenvalue_threshold<T: ComplexField, D: Dim>(
+    t: &OMatrix<T, D, D>,
+) -> T::RealField
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    T::RealField::default_epsilon().sqrt() * t.norm()
+}
+
+/// Returns `true` if the eigenvalues of the 1x1 or 2x2 diagonal block `block` of a
+/// quasi-triangular matrix are all smaller than `zero_tol`.
+pub(crate) fn is_zero_block<T: ComplexField>(
+    block: &Matrix2<T>,
+    size: usize,
+    zero_tol: &T::RealField,
+) -> bool {
+    if size == 1 {
+        block[(0, 0)].clone().modulus() <= zero_tol.clone()
+    } else {
+        // Both complex conjugate eigenvalues have a squared modulus equal to the determinant.
+        block.determinant().modulus() <= zero_tol.clone() * zero_tol.clone()
+    }
+}
+
+/// Copies the `p × q` block of `m` starting at `(i, j)` into the top-left corner of a 2x2 matrix.
+pub(crate) fn block2<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>>(
+    m: &Matrix<T, R, C, S>,
+    i: usize,
+    j: usize,
+    p: usize,
+    q: usize,
+) -> Matrix2<T> {
+    let mut res = Matrix2::zeros();
+    res.view_mut((0, 0), (p, q))
+        .copy_from(&m.view((i, j), (p, q)));
+    res
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+/// Swaps the adjacent diagonal blocks of sizes `p` and `q` starting at the index `k` of the
+/// upper quasi-triangular matrix `t`, and accumulates the unitary transformation into `u`.
+///
+/// Returns `false`, leaving `t` and `u` untouched, if the two blocks have a common eigenvalue
+/// or if the swap would not be numerically stable.
+fn swap_blocks<T: ComplexField>(
+    t: &mut DMatrix<T>,
+    u: &mut DMatrix<T>,
+    k: usize,
+    p: usize,
+    q: usize,
+) -> bool {
+    let m = p + q;
+    let t11 = block2(t, k, k, p, p);
+    let t22 = block2(t, k + p, k + p, q, q);
+    let t12 = block2(t, k, k + p, p, q);
+
+    // The columns of `[-X; I]`, with `T₁₁ * X - X * T₂₂ = T₁₂`, span the invariant subspace
+    // associated to the eigenvalues of `T₂₂`.
+    let x = match solve_small_sylvester(&t11, &-t22, &t12, p, q) {
+        Some(x) => x,
+        None => return false,
+    };
+
+    let mut v = DMatrix::zeros(m, q);
+    v.view_mut((0, 0), (p, q))
+        .copy_from(&(-x).view((0, 0), (p, q)));
+    v.view_mut((p, 0), (q, q)).fill_with_identity();
+
+    // The unitary matrix whose first `q` columns span the same subspace as `[-X; I]`.
+    let z = triangularizing_rotations(v);
+
+    // Reject the swap if the transformed block does not come out as block upper triangular.
+    let window = t.view((k, k), (m, m));
+    let swapped = z.ad_mul(&window) * &z;
+    let threshold =
+        T::RealField::default_epsilon() * crate::convert::<f64, T::RealField>(10.0) * window.norm();
+
+    if swapped.view((q, 0), (p, q)).norm() > threshold {
+        return false;
+    }
+
+    apply_on_the_left(t, &z, k);
+    apply_on_the_right(t, &z, k);
+    apply_on_the_right(u, &z, k);
+
+    t.view_mut((k + q, k), (p, q)).fill(T::zero());
+    true
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+/// Computes the unitary matrix `Z`, as a product of Givens rotations, such that `Zᴴ * v` is
+/// upper triangular.
+pub(crate) fn triangularizing_rotations<T: ComplexField>(mut v: DMatrix<T>) -> DMatrix<T> {
+    let (m, q) = v.shape();
+    let mut z = DMatrix::identity(m, m);
+
+    for c in 0..q {
+        for r in (c + 1..m).rev() {
+            let g = Vector2::new(v[(r - 1, c)].clone(), v[(r, c)].clone());
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&g) {
+                rot.rotate(&mut v.fixed_rows_mut::<2>(r - 1));
+                rot.inverse()
+                    .rotate_rows(&mut z.fixed_columns_mut::<2>(r - 1));
+            }
+        }
+    }
+
+    z
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+/// Replaces the rows `k..k + z.nrows()` of `m` by their product with `zᴴ`.
+pub(crate) fn apply_on_the_left<T: ComplexField>(m: &mut DMatrix<T>, z: &DMatrix<T>, k: usize) {
+    let rows = z.ad_mul(&m.rows(k, z.nrows()));
+    m.rows_mut(k, z.nrows()).copy_from(&rows);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+/// Replaces the columns `k..k + z.nrows()` of `m` by their product with `z`.
+pub(crate) fn apply_on_the_right<T: ComplexField>(m: &mut DMatrix<T>, z: &DMatrix<T>, k: usize) {
+    let columns = m.columns(k, z.nrows()) * z;
+    m.columns_mut(k, z.nrows()).copy_from(&columns);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+/// Reorders the Schur decomposition `U * T * Uᴴ` so that the diagonal blocks of `T` for which
+/// `select` returns `true` come first.
+///
+/// Returns the number of selected eigenvalues, or `None` if the reordering failed.
+pub(crate) fn reorder_schur<T: ComplexField>(
+    t: &mut DMatrix<T>,
+    u: &mut DMatrix<T>,
+    select: impl Fn(&Matrix2<T>, usize) -> bool,
+) -> Option<usize> {
+    let dim = t.nrows();
+    let mut k = 0;
+    let mut i = 0;
+
+    while i < dim {
+        let p = block_size_at(t, i);
+
+        if select(&block2(t, i, i, p, p), p) {
+            // Bubble the selected block up to the position `k`.
+            let mut j = i;
+            while j > k {
+                let q = block_size_before(t, j);
+
+                if !swap_blocks(t, u, j - q, q, p) {
+                    return None;
+                }
+
+                j -= q;
+            }
+
+            k += p;
+        }
+
+        i += p;
+    }
+
+    Some(k)
+}
+
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
 where
     D: DimSub<U1>, // For Hessenberg.
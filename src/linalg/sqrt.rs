@@ -0,0 +1,163 @@
+//! This module provides the principal square root (sqrt) function to square matrices.
+
+use num::Zero;
+
+use crate::{
+    base::{
+        allocator::Allocator,
+        dimension::{Dim, DimDiff, DimSub, U1},
+        DefaultAllocator,
+    },
+    convert,
+    linalg::schur::{
+        block2, block_size_at, block_size_before, is_zero_block, solve_small_sylvester,
+        zero_eigenvalue_threshold,
+    },
+    linalg::{MatrixFunctionError, Schur},
+    ComplexField, Matrix2, OMatrix,
+};
+
+/// Computes the principal square root of a 1x1 or 2x2 diagonal block of a quasi-triangular
+/// matrix.
+///
+/// A negative real 1x1 block smaller than `zero_tol` is considered to be a zero eigenvalue
+/// perturbed by rounding errors. Returns `None` if the block has any other negative real
+/// eigenvalue.
+fn sqrt_diagonal_block<T: ComplexField>(
+    block: &Matrix2<T>,
+    size: usize,
+    zero_tol: T::RealField,
+) -> Option<Matrix2<T>> {
+    if size == 1 {
+        let t = block[(0, 0)].clone();
+
+        if t.clone().imaginary().is_zero() && t.clone().real() < T::RealField::zero() {
+            if is_zero_block(block, size, &zero_tol) {
+                return Some(Matrix2::zeros());
+            }
+
+            return None;
+        }
+
+        let mut res = Matrix2::<T>::zeros();
+        res[(0, 0)] = t.sqrt();
+        Some(res)
+    } else {
+        // For a 2x2 matrix B without eigenvalues on the closed negative real axis,
+        // sqrt(B) = (B + s I) / sqrt(tr(B) + 2 s) with s = sqrt(det(B)).
+        let s = block.determinant().sqrt();
+        let tau = (block.trace() + s.clone() * convert(2.0)).sqrt();
+
+        if tau.is_zero() {
+            return None;
+        }
+
+        Some((block + Matrix2::identity() * s) / tau)
+    }
+}
+
+/// Computes the principal square root of the upper quasi-triangular matrix `t`, as output by
+/// the Schur decomposition.
+///
+/// Zero eigenvalues are supported as long as they are semisimple. Returns `None` if `t` has a
+/// negative real eigenvalue larger than [`zero_eigenvalue_threshold`], or a defective zero
+/// eigenvalue.
+pub(crate) fn sqrt_quasi_triangular<T: ComplexField, D: Dim>(
+    t: &OMatrix<T, D, D>,
+) -> Option<OMatrix<T, D, D>>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let (nrows, ncols) = t.shape_generic();
+    let dim = t.nrows();
+    let zero_tol = zero_eigenvalue_threshold(t);
+    let mut u = OMatrix::zeros_generic(nrows, ncols);
+
+    // Process the block columns from left to right, and each block column from bottom to top.
+    let mut j = 0;
+    while j < dim {
+        let q = block_size_at(t, j);
+
+        let ujj = sqrt_diagonal_block(&block2(t, j, j, q, q), q, zero_tol.clone())?;
+        u.view_mut((j, j), (q, q))
+            .copy_from(&ujj.view((0, 0), (q, q)));
+
+        let mut end = j;
+        while end > 0 {
+            let p = block_size_before(t, end);
+            let i = end - p;
+
+            let uii = block2(&u, i, i, p, p);
+            let mut r = block2(t, i, j, p, q);
+            r.view_mut((0, 0), (p, q)).gemm(
+                -T::one(),
+                &u.view_range(i..end, end..j),
+                &u.view_range(end..j, j..j + q),
+                T::one(),
+            );
+
+            let uij = if p == 1 && q == 1 && uii[(0, 0)].is_zero() && ujj[(0, 0)].is_zero() {
+                // The coupling between two zero eigenvalues must vanish for the square root to
+                // exist, in which case the corresponding entry of the root is left to zero.
+                if r[(0, 0)].clone().modulus() > zero_tol {
+                    return None;
+                }
+                r.fill(T::zero());
+                r
+            } else {
+                solve_small_sylvester(&uii, &ujj, &r, p, q)?
+            };
+            u.view_mut((i, j), (p, q))
+                .copy_from(&uij.view((0, 0), (p, q)));
+
+            end = i;
+        }
+
+        j += q;
+    }
+
+    Some(u)
+}
+
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimSub<U1>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D>,
+{
+    /// Computes the principal square root of this matrix.
+    ///
+    /// The principal square root is the unique square root whose eigenvalues all have a positive
+    /// real part. It exists if this matrix has no eigenvalue on the closed negative real axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the square root cannot be computed. See [`Self::try_sqrt`] for a non-panicking
+    /// version.
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        self.try_sqrt()
+            .expect("Matrix square root: the matrix has no principal square root.")
+    }
+
+    /// Attempts to compute the principal square root of this matrix.
+    ///
+    /// This computes the Schur decomposition `Q * T * Q.adjoint()` of this matrix, then the
+    /// square root of the quasi-triangular factor `T` by block back-substitution.
+    ///
+    /// Returns [`MatrixFunctionError::NonPositiveEigenvalue`] if this matrix has a negative real
+    /// eigenvalue, or a zero eigenvalue that is defective, i.e., whose algebraic multiplicity
+    /// exceeds its geometric multiplicity.
+    pub fn try_sqrt(&self) -> Result<Self, MatrixFunctionError> {
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let (q, t) = Schur::new(self.clone()).unpack();
+        let u = sqrt_quasi_triangular(&t).ok_or(MatrixFunctionError::NonPositiveEigenvalue)?;
+
+        Ok(&q * u * q.adjoint())
+    }
+}
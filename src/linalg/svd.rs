@@ -15,7 +15,7 @@ use simba::scalar::{ComplexField, RealField};
 
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::symmetric_eigen;
-use crate::linalg::Bidiagonal;
+use crate::linalg::{Bidiagonal, LeastSquaresError, LeastSquaresSolution};
 
 /// Singular Value Decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
@@ -642,6 +642,59 @@ where
         }
     }
 
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `self` is the
+    /// decomposed matrix and `x` the unknown.
+    ///
+    /// This computes the minimum-norm solution, and works for overdetermined, underdetermined,
+    /// and rank-deficient systems. Any singular value smaller than
+    /// `max(nrows, ncols) * T::RealField::default_epsilon() * max_singular_value` is assumed to
+    /// be zero. Use [`Self::solve`] for a custom tolerance.
+    /// Returns [`LeastSquaresError::MissingSingularVectors`] if the singular vectors `U` and `V`
+    /// have not been computed.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<LeastSquaresSolution<T, C, C2>, LeastSquaresError>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, C, C2> + Allocator<T, DimMinimum<R, C>, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        match (&self.u, &self.v_t) {
+            (Some(u), Some(v_t)) => {
+                let max_dim = u.nrows().max(v_t.ncols());
+                let eps = self.singular_values.max()
+                    * T::RealField::default_epsilon()
+                    * crate::convert(max_dim as f64);
+
+                // Project `b` onto the range of the decomposed matrix.
+                let mut ut_b = u.ad_mul(b);
+                for (i, val) in self.singular_values.iter().enumerate() {
+                    if *val <= eps {
+                        ut_b.row_mut(i).fill(T::zero());
+                    }
+                }
+
+                let mut residual_norm_squared = T::RealField::zero();
+                for j in 0..b.ncols() {
+                    for i in 0..b.nrows() {
+                        let r = b[(i, j)].clone() - u.row(i).tr_dot(&ut_b.column(j));
+                        residual_norm_squared += r.modulus_squared();
+                    }
+                }
+
+                Ok(LeastSquaresSolution {
+                    solution: self
+                        .solve(b, eps.clone())
+                        .map_err(|_| LeastSquaresError::MissingSingularVectors)?,
+                    residual_norm: residual_norm_squared.sqrt(),
+                    rank: self.rank(eps),
+                })
+            }
+            _ => Err(LeastSquaresError::MissingSingularVectors),
+        }
+    }
+
     /// converts SVD results to Polar decomposition form of the original Matrix: `A = P' * U`.
     ///
     /// The polar decomposition used here is Left Polar Decomposition (or Reverse Polar Decomposition)
@@ -820,6 +873,24 @@ where
     {
         SVD::new_unordered(self.clone_owned(), true, true).pseudo_inverse(eps)
     }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// This computes the minimum-norm solution using the singular value decomposition of this
+    /// matrix, and works for overdetermined, underdetermined, and rank-deficient systems. See
+    /// [`SVD::solve_least_squares`] for details on the rank tolerance.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<LeastSquaresSolution<T, C, C2>, LeastSquaresError>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, C, C2> + Allocator<T, DimMinimum<R, C>, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        SVD::new_unordered(self.clone_owned(), true, true).solve_least_squares(b)
+    }
 }
 
 impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
@@ -844,6 +915,22 @@ where
     pub fn singular_values(&self) -> OVector<T::RealField, DimMinimum<R, C>> {
         SVD::new(self.clone_owned(), false, false).singular_values
     }
+
+    /// Computes the condition number of this matrix in the 2-norm.
+    ///
+    /// This is the ratio between the largest and the smallest singular values. It is infinite
+    /// (or NaN for the zero matrix) if this matrix is rank-deficient. Consider the `rcond`
+    /// estimators of the `LU`, `FullPivLU`, `QR`, or `Cholesky` decompositions for a cheaper
+    /// approximation of the condition number in the 1-norm.
+    #[must_use]
+    pub fn condition_number(&self) -> T::RealField {
+        if self.is_empty() {
+            return T::RealField::one();
+        }
+
+        let singular_values = self.singular_values();
+        singular_values[0].clone() / singular_values[singular_values.len() - 1].clone()
+    }
 }
 
 // Explicit formulae inspired from the paper "Computing the Singular Values of 2-by-2 Complex
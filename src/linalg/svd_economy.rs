@@ -0,0 +1,113 @@
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use crate::base::DMatrix;
+#[cfg(feature = "rand")]
+use crate::base::Matrix;
+use crate::dimension::Dyn;
+#[cfg(feature = "rand")]
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::{QR, SVD};
+
+impl<T: ComplexField> SVD<T, Dyn, Dyn> {
+    /// Computes the economy-size Singular Value Decomposition of a dynamically-sized matrix.
+    ///
+    /// The matrix is first reduced to its `min(m, n) × min(m, n)` triangular factor by a QR
+    /// decomposition, and only this factor is bidiagonalized. This is much faster than
+    /// [`SVD::new`] for very tall (or very wide) matrices, and no intermediate matrix has more
+    /// than `min(m, n)` columns (or rows). The singular values are sorted in descending order.
+    pub fn new_economy(matrix: DMatrix<T>, compute_u: bool, compute_v: bool) -> Self {
+        let (nrows, ncols) = matrix.shape();
+
+        if nrows < ncols {
+            // Decompose the adjoint instead: `Aᴴ = V * Σ * Uᴴ`.
+            let svd = Self::new_economy(matrix.adjoint(), compute_v, compute_u);
+
+            return Self {
+                u: svd.v_t.map(|v_t| v_t.adjoint()),
+                v_t: svd.u.map(|u| u.adjoint()),
+                singular_values: svd.singular_values,
+            };
+        }
+
+        let qr = QR::new(matrix);
+        let svd = SVD::new(qr.r(), compute_u, compute_v);
+
+        // `U = Q * U_r`, where `U_r` is padded with zeros to apply the Householder reflections of
+        // `Q` in-place.
+        let u = svd.u.map(|u_r| {
+            let mut u = DMatrix::zeros(nrows, ncols);
+            u.rows_mut(0, ncols).copy_from(&u_r);
+            qr.q_mul(&mut u);
+            u
+        });
+
+        Self {
+            u,
+            v_t: svd.v_t,
+            singular_values: svd.singular_values,
+        }
+    }
+
+    /// Computes an approximation of the `k` largest singular values of `matrix`, and of their
+    /// singular vectors, with a randomized range finder.
+    ///
+    /// This only requires a few products of `matrix` with thin matrices, making it suitable to
+    /// principal component analysis on large datasets. The singular values are sorted in
+    /// descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`           − the number of singular values to compute.
+    /// * `oversample`  − the number of additional random samples used to approximate the range of
+    ///   `matrix`. A value around `10` is usually sufficient.
+    /// * `power_iters` − the number of power iterations applied to the samples. Each iteration
+    ///   improves the accuracy when the singular values decay slowly.
+    /// * `rng`         − the random number generator used to sample the Gaussian test matrix.
+    #[cfg(feature = "rand")]
+    pub fn new_truncated<S, G>(
+        matrix: &Matrix<T, Dyn, Dyn, S>,
+        k: usize,
+        oversample: usize,
+        power_iters: usize,
+        rng: &mut G,
+    ) -> Self
+    where
+        S: Storage<T, Dyn, Dyn>,
+        G: Rng + ?Sized,
+    {
+        let (nrows, ncols) = matrix.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+        assert!(
+            k > 0 && k <= min_nrows_ncols,
+            "Truncated SVD: the rank must be between 1 and the smallest matrix dimension."
+        );
+        let nsamples = (k + oversample).min(min_nrows_ncols);
+
+        // Orthonormal basis of the range of `A * Ω`, where `Ω` is a Gaussian test matrix.
+        let omega = DMatrix::from_fn(ncols, nsamples, |_, _| {
+            T::from_real(crate::convert(
+                rng.sample::<f64, _>(rand_distr::StandardNormal),
+            ))
+        });
+        let mut q = QR::new(matrix * omega).q();
+
+        // Power iterations on `A * Aᴴ`, re-orthonormalized at each step for numerical stability.
+        for _ in 0..power_iters {
+            let z = QR::new(matrix.ad_mul(&q)).q();
+            q = QR::new(matrix * z).q();
+        }
+
+        // Decompose the small projection `B = Qᴴ * A`, and lift its left-singular vectors.
+        let svd = SVD::new(q.ad_mul(matrix), true, true);
+        let u = svd.u.map(|u_b| q * u_b.columns(0, k));
+
+        Self {
+            u,
+            v_t: svd.v_t.map(|v_t| v_t.rows(0, k).into_owned()),
+            singular_values: svd.singular_values.rows(0, k).into_owned(),
+        }
+    }
+}
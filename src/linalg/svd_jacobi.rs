@@ -0,0 +1,267 @@
+use approx::AbsDiffEq;
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix, OVector};
+use crate::dimension::{Dim, DimMin, DimMinimum, U1};
+use simba::scalar::ComplexField;
+
+use crate::linalg::SVD;
+
+/// Applies the plane rotation `[c s; -s c]` to the columns `p` and `q` of `m`, after the column
+/// `q` has been multiplied by the unit scalar `phase`.
+fn rotate_columns<T: ComplexField, R: Dim, C: Dim>(
+    m: &mut OMatrix<T, R, C>,
+    p: usize,
+    q: usize,
+    c: T::RealField,
+    s: T::RealField,
+    phase: &T,
+) where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    for k in 0..m.nrows() {
+        let x = m[(k, p)].clone();
+        let y = m[(k, q)].clone() * phase.clone();
+        m[(k, p)] = x.clone().scale(c.clone()) - y.clone().scale(s.clone());
+        m[(k, q)] = x.scale(s.clone()) + y.scale(c.clone());
+    }
+}
+
+/// Orthogonalizes the columns of `w` with cyclic one-sided Jacobi rotations, and accumulates
+/// the rotations into `v`. The columns of `w` and `v` are then sorted by decreasing norm.
+///
+/// Returns `false` if the columns are not orthogonal after `max_niter` sweeps.
+fn jacobi_orthogonalize<T: ComplexField, R: Dim, C: Dim>(
+    w: &mut OMatrix<T, R, C>,
+    mut v: Option<&mut OMatrix<T, C, C>>,
+    eps: T::RealField,
+    max_niter: usize,
+) -> bool
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, C, C>,
+{
+    let n = w.ncols();
+    let two: T::RealField = crate::convert(2.0);
+    let mut niter = 0;
+
+    loop {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = w.column(p).norm_squared();
+                let beta = w.column(q).norm_squared();
+                let gamma = w.column(p).dotc(&w.column(q));
+                let gamma_norm = gamma.clone().modulus();
+
+                // The columns are already orthogonal relatively to their norms.
+                if gamma_norm.is_zero()
+                    || gamma_norm <= eps.clone() * (alpha.clone() * beta.clone()).sqrt()
+                {
+                    continue;
+                }
+
+                rotated = true;
+
+                // Diagonalize the 2x2 Gram matrix `[α γ; γ* β]` after removing the phase of `γ`.
+                let phase = gamma.unscale(gamma_norm.clone()).conjugate();
+                let zeta = (beta - alpha) / (gamma_norm * two.clone());
+                let t = T::RealField::one()
+                    / (zeta.clone().abs()
+                        + (T::RealField::one() + zeta.clone() * zeta.clone()).sqrt());
+                let t = if zeta < T::RealField::zero() { -t } else { t };
+                let c = T::RealField::one() / (T::RealField::one() + t.clone() * t.clone()).sqrt();
+                let s = c.clone() * t;
+
+                rotate_columns(w, p, q, c.clone(), s.clone(), &phase);
+
+                if let Some(v) = v.as_deref_mut() {
+                    rotate_columns(v, p, q, c, s, &phase);
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+
+        niter += 1;
+        if niter == max_niter {
+            return false;
+        }
+    }
+
+    // Selection sort of the columns by decreasing norm.
+    for i in 0..n {
+        let mut imax = i;
+        let mut max = w.column(i).norm_squared();
+
+        for j in i + 1..n {
+            let norm = w.column(j).norm_squared();
+            if norm > max {
+                imax = j;
+                max = norm;
+            }
+        }
+
+        if imax != i {
+            w.swap_columns(i, imax);
+
+            if let Some(v) = v.as_deref_mut() {
+                v.swap_columns(i, imax);
+            }
+        }
+    }
+
+    true
+}
+
+/// Normalizes the orthogonal columns of `w`, which are sorted by decreasing norm.
+///
+/// The zero columns are replaced by unit vectors orthogonal to all the other columns.
+fn normalize_columns<T: ComplexField, R: Dim, C: Dim>(w: &mut OMatrix<T, R, C>)
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, R>,
+{
+    let (nrows, ncols) = (w.nrows(), w.ncols());
+
+    for j in 0..ncols {
+        let norm = w.column(j).norm();
+
+        if !norm.is_zero() {
+            w.column_mut(j).unscale_mut(norm);
+            continue;
+        }
+
+        // Pick the canonical basis vector with the largest component orthogonal to the
+        // previous columns.
+        let mut best = OVector::zeros_generic(w.shape_generic().0, U1);
+        let mut best_norm = T::RealField::zero();
+
+        for k in 0..nrows {
+            let mut e = OVector::zeros_generic(w.shape_generic().0, U1);
+            e[k] = T::one();
+
+            // Two passes of Gram-Schmidt for numerical stability.
+            for _ in 0..2 {
+                for l in 0..j {
+                    let proj = w.column(l).dotc(&e);
+                    e.axpy(-proj, &w.column(l), T::one());
+                }
+            }
+
+            let e_norm = e.norm();
+            if e_norm > best_norm {
+                best = e;
+                best_norm = e_norm;
+            }
+        }
+
+        w.set_column(j, &best.unscale(best_norm));
+    }
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, C, R>
+        + Allocator<T, R, R>
+        + Allocator<T, C, C>
+        + Allocator<T, R>
+        + Allocator<T, C>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, R, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimMinimum<R, C>>,
+{
+    /// Computes the Singular Value Decomposition of `matrix` using one-sided Jacobi rotations.
+    ///
+    /// This is slower than [`SVD::new`], but it computes the small singular values, and their
+    /// singular vectors, with a high relative accuracy instead of an accuracy relative to the
+    /// largest singular value. This makes it well-suited for small matrices whose tiny singular
+    /// values matter. The singular values are sorted in descending order.
+    pub fn new_jacobi(matrix: OMatrix<T, R, C>, compute_u: bool, compute_v: bool) -> Self {
+        let eps = T::RealField::default_epsilon()
+            * crate::convert(matrix.nrows().max(matrix.ncols()) as f64);
+        Self::try_new_jacobi(matrix, compute_u, compute_v, eps, 0).unwrap()
+    }
+
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using one-sided Jacobi
+    /// rotations.
+    ///
+    /// The singular values are sorted in descending order. See [`SVD::new_jacobi`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_u` − set this to `true` to enable the computation of left-singular vectors.
+    /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
+    /// * `eps`       − tolerance on the cosine of the angle between two columns below which they
+    ///   are considered orthogonal.
+    /// * `max_niter` − maximum number of sweeps performed by the algorithm. If this number of
+    ///   sweeps is exceeded, `None` is returned. If `niter == 0`, then the algorithm continues
+    ///   indefinitely until convergence.
+    pub fn try_new_jacobi(
+        matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            !matrix.is_empty(),
+            "Cannot compute the SVD of an empty matrix."
+        );
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        if nrows.value() >= ncols.value() {
+            // Orthogonalize the columns: `A * V = U * Σ`.
+            let mut w = matrix;
+            let mut v = compute_v.then(|| OMatrix::identity_generic(ncols, ncols));
+
+            if !jacobi_orthogonalize(&mut w, v.as_mut(), eps, max_niter) {
+                return None;
+            }
+
+            let singular_values =
+                OVector::from_fn_generic(min_nrows_ncols, U1, |j, _| w.column(j).norm());
+            normalize_columns(&mut w);
+
+            Some(Self {
+                u: compute_u.then(|| {
+                    OMatrix::from_fn_generic(nrows, min_nrows_ncols, |i, j| w[(i, j)].clone())
+                }),
+                v_t: v.map(|v| {
+                    OMatrix::from_fn_generic(min_nrows_ncols, ncols, |i, j| {
+                        v[(j, i)].clone().conjugate()
+                    })
+                }),
+                singular_values,
+            })
+        } else {
+            // Orthogonalize the columns of the adjoint: `Aᴴ * U = V * Σ`.
+            let mut w = matrix.adjoint();
+            let mut u = compute_u.then(|| OMatrix::identity_generic(nrows, nrows));
+
+            if !jacobi_orthogonalize(&mut w, u.as_mut(), eps, max_niter) {
+                return None;
+            }
+
+            let singular_values =
+                OVector::from_fn_generic(min_nrows_ncols, U1, |j, _| w.column(j).norm());
+            normalize_columns(&mut w);
+
+            Some(Self {
+                u: u.map(|u| {
+                    OMatrix::from_fn_generic(nrows, min_nrows_ncols, |i, j| u[(i, j)].clone())
+                }),
+                v_t: compute_v.then(|| {
+                    OMatrix::from_fn_generic(min_nrows_ncols, ncols, |i, j| {
+                        w[(j, i)].clone().conjugate()
+                    })
+                }),
+                singular_values,
+            })
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! Solvers of the Sylvester and Lyapunov matrix equations.
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, Matrix2, Matrix4, OMatrix, OVector, Vector4};
+use crate::linalg::schur::{block2, block_size_at, block_size_before, solve_small_sylvester};
+use crate::linalg::Schur;
+use simba::scalar::ComplexField;
+
+/// Solves the small Stein equation `a * x * b - x = r` where `x` and `r` are `p × q` matrices
+/// stored in the top-left corner of a `Matrix2`, `a` is `p × p` and `b` is `q × q`.
+fn solve_small_stein<T: ComplexField>(
+    a: &Matrix2<T>,
+    b: &Matrix2<T>,
+    r: &Matrix2<T>,
+    p: usize,
+    q: usize,
+) -> Option<Matrix2<T>> {
+    // Solve the Kronecker form `(bᵀ ⊗ a - I) vec(x) = vec(r)`, padded to 4x4.
+    let mut k = Matrix4::<T>::zeros();
+    let mut rhs = Vector4::<T>::zeros();
+
+    for c in 0..q {
+        for l in 0..p {
+            let row = l + c * p;
+            rhs[row] = r[(l, c)].clone();
+            k[(row, row)] = -T::one();
+
+            for n in 0..q {
+                for m in 0..p {
+                    k[(row, m + n * p)] += a[(l, m)].clone() * b[(n, c)].clone();
+                }
+            }
+        }
+    }
+
+    for i in p * q..4 {
+        k[(i, i)] = T::one();
+    }
+
+    let sol = k.lu().solve(&rhs)?;
+    let mut x = Matrix2::<T>::zeros();
+
+    for c in 0..q {
+        for l in 0..p {
+            x[(l, c)] = sol[l + c * p].clone();
+        }
+    }
+
+    Some(x)
+}
+
+/// Overwrites `y` with the solution of `t * Y + Y * s = y` (or `t * Y * s - Y = y` if
+/// `discrete` is `true`), where `t` and `s` are upper quasi-triangular.
+///
+/// Returns `false` if the equation is singular.
+fn solve_quasi_triangular<T: ComplexField, R: Dim, C: Dim>(
+    t: &OMatrix<T, R, R>,
+    s: &OMatrix<T, C, C>,
+    y: &mut OMatrix<T, R, C>,
+    discrete: bool,
+) -> bool
+where
+    DefaultAllocator:
+        Allocator<T, R, R> + Allocator<T, C, C> + Allocator<T, R, C> + Allocator<T, R>,
+{
+    let (nrows, ncols) = (y.nrows(), y.ncols());
+    let mut w = OVector::zeros_generic(y.shape_generic().0, U1);
+
+    // Process the block columns from left to right, and each block column from bottom to top.
+    let mut j = 0;
+    while j < ncols {
+        let q = block_size_at(s, j);
+        let sjj = block2(s, j, j, q, q);
+
+        // Move the contributions of the block columns already solved to the right-hand side.
+        for c in j..j + q {
+            if discrete {
+                w.gemv(
+                    T::one(),
+                    &y.columns_range(..j),
+                    &s.view_range(..j, c),
+                    T::zero(),
+                );
+                y.column_mut(c).gemv(-T::one(), t, &w, T::one());
+            } else {
+                let (solved, mut rhs) = y.columns_range_pair_mut(..j, c);
+                rhs.gemv(-T::one(), &solved, &s.view_range(..j, c), T::one());
+            }
+        }
+
+        let mut end = nrows;
+        while end > 0 {
+            let p = block_size_before(t, end);
+            let i = end - p;
+
+            let tii = block2(t, i, i, p, p);
+            let mut h = Matrix2::zeros();
+            h.view_mut((0, 0), (p, q)).gemm(
+                T::one(),
+                &t.view_range(i..end, end..),
+                &y.view_range(end.., j..j + q),
+                T::zero(),
+            );
+
+            let mut r = block2(y, i, j, p, q);
+            let yij = if discrete {
+                r -= h * &sjj;
+                solve_small_stein(&tii, &sjj, &r, p, q)
+            } else {
+                r -= h;
+                solve_small_sylvester(&tii, &sjj, &r, p, q)
+            };
+
+            match yij {
+                Some(yij) => y
+                    .view_mut((i, j), (p, q))
+                    .copy_from(&yij.view((0, 0), (p, q))),
+                None => return false,
+            }
+
+            end = i;
+        }
+
+        j += q;
+    }
+
+    true
+}
+
+/// Solves the Sylvester equation `A * X + X * B = C` (or `A * X * B - X = C` if `discrete` is
+/// `true`) with the Bartels-Stewart algorithm.
+fn bartels_stewart<T, R, C, SA, SB, SC>(
+    a: &Matrix<T, R, R, SA>,
+    b: &Matrix<T, C, C, SB>,
+    c: &Matrix<T, R, C, SC>,
+    discrete: bool,
+) -> Option<OMatrix<T, R, C>>
+where
+    T: ComplexField,
+    R: DimSub<U1>,
+    C: DimSub<U1>,
+    SA: Storage<T, R, R>,
+    SB: Storage<T, C, C>,
+    SC: Storage<T, R, C>,
+    DefaultAllocator: Allocator<T, R, R>
+        + Allocator<T, R, DimDiff<R, U1>>
+        + Allocator<T, DimDiff<R, U1>>
+        + Allocator<T, R>
+        + Allocator<T, C, C>
+        + Allocator<T, C, DimDiff<C, U1>>
+        + Allocator<T, DimDiff<C, U1>>
+        + Allocator<T, C>
+        + Allocator<T, R, C>,
+{
+    assert!(
+        a.is_square() && b.is_square(),
+        "Sylvester equation: the coefficient matrices must be square."
+    );
+    assert_eq!(
+        c.shape(),
+        (a.nrows(), b.nrows()),
+        "Sylvester equation: dimension mismatch."
+    );
+
+    if c.is_empty() {
+        return Some(c.clone_owned());
+    }
+
+    // With `A = U * T * Uᴴ` and `B = V * S * Vᴴ`, the equation becomes quasi-triangular in
+    // `Y = Uᴴ * X * V`.
+    let (u, t) = Schur::new(a.clone_owned()).unpack();
+    let (v, s) = Schur::new(b.clone_owned()).unpack();
+
+    let mut y = u.ad_mul(&(c * &v));
+
+    if solve_quasi_triangular(&t, &s, &mut y, discrete) {
+        Some(u * y * v.adjoint())
+    } else {
+        None
+    }
+}
+
+/// Solves the Sylvester equation `A * X + X * B = C`, where `X` is the unknown to be determined.
+///
+/// This uses the Bartels-Stewart algorithm. Returns `None` if the equation does not have a
+/// unique solution, i.e., if `A` and `-B` have a common eigenvalue.
+#[must_use]
+pub fn solve_sylvester<T, R, C, SA, SB, SC>(
+    a: &Matrix<T, R, R, SA>,
+    b: &Matrix<T, C, C, SB>,
+    c: &Matrix<T, R, C, SC>,
+) -> Option<OMatrix<T, R, C>>
+where
+    T: ComplexField,
+    R: DimSub<U1>,
+    C: DimSub<U1>,
+    SA: Storage<T, R, R>,
+    SB: Storage<T, C, C>,
+    SC: Storage<T, R, C>,
+    DefaultAllocator: Allocator<T, R, R>
+        + Allocator<T, R, DimDiff<R, U1>>
+        + Allocator<T, DimDiff<R, U1>>
+        + Allocator<T, R>
+        + Allocator<T, C, C>
+        + Allocator<T, C, DimDiff<C, U1>>
+        + Allocator<T, DimDiff<C, U1>>
+        + Allocator<T, C>
+        + Allocator<T, R, C>,
+{
+    bartels_stewart(a, b, c, false)
+}
+
+/// Solves the discrete Sylvester (or Stein) equation `A * X * B - X = C`, where `X` is the
+/// unknown to be determined.
+///
+/// Returns `None` if the equation does not have a unique solution, i.e., if the product of an
+/// eigenvalue of `A` and an eigenvalue of `B` is equal to one.
+#[must_use]
+pub fn solve_discrete_sylvester<T, R, C, SA, SB, SC>(
+    a: &Matrix<T, R, R, SA>,
+    b: &Matrix<T, C, C, SB>,
+    c: &Matrix<T, R, C, SC>,
+) -> Option<OMatrix<T, R, C>>
+where
+    T: ComplexField,
+    R: DimSub<U1>,
+    C: DimSub<U1>,
+    SA: Storage<T, R, R>,
+    SB: Storage<T, C, C>,
+    SC: Storage<T, R, C>,
+    DefaultAllocator: Allocator<T, R, R>
+        + Allocator<T, R, DimDiff<R, U1>>
+        + Allocator<T, DimDiff<R, U1>>
+        + Allocator<T, R>
+        + Allocator<T, C, C>
+        + Allocator<T, C, DimDiff<C, U1>>
+        + Allocator<T, DimDiff<C, U1>>
+        + Allocator<T, C>
+        + Allocator<T, R, C>,
+{
+    bartels_stewart(a, b, c, true)
+}
+
+/// Solves the continuous Lyapunov equation `A * X + X * Aᴴ + Q = 0`, where `X` is the unknown
+/// to be determined.
+///
+/// For real matrices, `Aᴴ` is the transpose of `A`. Returns `None` if the equation does not
+/// have a unique solution, i.e., if `A` and `-Aᴴ` have a common eigenvalue.
+#[must_use]
+pub fn solve_continuous_lyapunov<T, D, SA, SQ>(
+    a: &Matrix<T, D, D, SA>,
+    q: &Matrix<T, D, D, SQ>,
+) -> Option<OMatrix<T, D, D>>
+where
+    T: ComplexField,
+    D: DimSub<U1>,
+    SA: Storage<T, D, D>,
+    SQ: Storage<T, D, D>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D>,
+{
+    bartels_stewart(a, &a.adjoint(), &-q, false)
+}
+
+/// Solves the discrete Lyapunov equation `A * X * Aᴴ - X + Q = 0`, where `X` is the unknown to
+/// be determined.
+///
+/// For real matrices, `Aᴴ` is the transpose of `A`. Returns `None` if the equation does not
+/// have a unique solution, i.e., if the product of two eigenvalues of `A` is equal to one.
+#[must_use]
+pub fn solve_discrete_lyapunov<T, D, SA, SQ>(
+    a: &Matrix<T, D, D, SA>,
+    q: &Matrix<T, D, D, SQ>,
+) -> Option<OMatrix<T, D, D>>
+where
+    T: ComplexField,
+    D: DimSub<U1>,
+    SA: Storage<T, D, D>,
+    SQ: Storage<T, D, D>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D>,
+{
+    bartels_stewart(a, &a.adjoint(), &-q, true)
+}
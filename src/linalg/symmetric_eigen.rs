@@ -11,7 +11,7 @@ use crate::storage::Storage;
 use simba::scalar::ComplexField;
 
 use crate::linalg::givens::GivensRotation;
-use crate::linalg::SymmetricTridiagonal;
+use crate::linalg::{Cholesky, SymmetricTridiagonal};
 
 /// Eigendecomposition of a symmetric matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
@@ -86,6 +86,76 @@ where
         })
     }
 
+    /// Computes the eigendecomposition of the symmetric-definite generalized eigenproblem
+    /// `k * x = λ * m * x`.
+    ///
+    /// The matrix `m` must be positive-definite. The problem is reduced to a standard
+    /// eigenproblem using the Cholesky decomposition of `m`. The returned eigenvectors are
+    /// `m`-orthonormal, i.e., `eigenvectors.adjoint() * m * eigenvectors` is the identity.
+    /// Note that [`Self::recompose`] does not yield `k` for such a decomposition.
+    ///
+    /// Only the lower-triangular parts (including their diagonals) of `k` and `m` are read.
+    /// Returns `None` if `m` is not positive-definite.
+    pub fn new_generalized(k: OMatrix<T, D, D>, m: OMatrix<T, D, D>) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        Self::try_new_generalized(k, m, T::RealField::default_epsilon(), 0)
+    }
+
+    /// Computes the eigendecomposition of the symmetric-definite generalized eigenproblem
+    /// `k * x = λ * m * x` with user-specified convergence parameters.
+    ///
+    /// Only the lower-triangular parts (including their diagonals) of `k` and `m` are read.
+    /// Returns `None` if `m` is not positive-definite or if the eigendecomposition did not
+    /// converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new_generalized(
+        k: OMatrix<T, D, D>,
+        m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        assert!(
+            k.is_square() && k.shape() == m.shape(),
+            "Unable to compute the generalized eigendecomposition of matrices with different or non-square shapes."
+        );
+
+        let chol = Cholesky::new(m)?;
+        let l = chol.l_dirty();
+
+        // Rebuild the full hermitian matrix from its lower-triangular part.
+        let mut c = k;
+        for j in 0..c.ncols() {
+            for i in 0..j {
+                c[(i, j)] = c[(j, i)].clone().conjugate();
+            }
+        }
+
+        // c = L⁻¹ * k * L⁻ᴴ
+        l.solve_lower_triangular_unchecked_mut(&mut c);
+        c.adjoint_mut();
+        l.solve_lower_triangular_unchecked_mut(&mut c);
+
+        let mut eig = Self::try_new(c, eps, max_niter)?;
+
+        // Map the eigenvectors back to the original problem: x = L⁻ᴴ * y.
+        l.ad_solve_lower_triangular_unchecked_mut(&mut eig.eigenvectors);
+
+        Some(eig)
+    }
+
     fn do_decompose(
         mut matrix: OMatrix<T, D, D>,
         eigenvectors: bool,
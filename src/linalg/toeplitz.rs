@@ -0,0 +1,395 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Mul;
+
+use num_complex::Complex;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DVector, DefaultAllocator, Matrix, OMatrix};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+use crate::RealField;
+
+/// A square Toeplitz matrix, whose element `(i, j)` only depends on `i - j`.
+///
+/// It is stored as its first column and its first row. Linear systems involving a Toeplitz
+/// matrix of dimension `n` are solved in `O(n²)` operations with the Levinson recursion.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToeplitzMatrix<T: ComplexField> {
+    column: DVector<T>,
+    row: DVector<T>,
+}
+
+impl<T: ComplexField> ToeplitzMatrix<T> {
+    /// Creates a Toeplitz matrix from its first column and its first row.
+    ///
+    /// The first element of `row` is ignored and replaced by the first element of `column`.
+    pub fn new(column: DVector<T>, mut row: DVector<T>) -> Self {
+        assert_eq!(
+            column.len(),
+            row.len(),
+            "Toeplitz matrix: the first row and column must have the same length."
+        );
+
+        if !column.is_empty() {
+            row[0] = column[0].clone();
+        }
+
+        Self { column, row }
+    }
+
+    /// Creates a Hermitian Toeplitz matrix from its first column.
+    pub fn hermitian(column: DVector<T>) -> Self {
+        let row = column.map(|e| e.conjugate());
+        Self::new(column, row)
+    }
+
+    /// Creates a Toeplitz matrix from the first column and the first row of the square matrix
+    /// `m`.
+    ///
+    /// The other elements of `m` are ignored.
+    pub fn from_dense<R: Dim, C: Dim, S: Storage<T, R, C>>(m: &Matrix<T, R, C, S>) -> Self {
+        assert!(m.is_square(), "Toeplitz matrix: the matrix must be square.");
+        let n = m.nrows();
+
+        Self {
+            column: DVector::from_fn(n, |i, _| m[(i, 0)].clone()),
+            row: DVector::from_fn(n, |j, _| m[(0, j)].clone()),
+        }
+    }
+
+    /// The dense matrix equal to `self`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let n = self.nrows();
+        DMatrix::from_fn(n, n, |i, j| self.coeff(i as isize - j as isize))
+    }
+
+    /// The number of rows and columns of this matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.column.len()
+    }
+
+    /// The first column of this matrix.
+    #[must_use]
+    pub fn column(&self) -> &DVector<T> {
+        &self.column
+    }
+
+    /// The first row of this matrix.
+    #[must_use]
+    pub fn row(&self) -> &DVector<T> {
+        &self.row
+    }
+
+    /// The elements `(i, j)` of this matrix with `i - j = k`.
+    fn coeff(&self, k: isize) -> T {
+        if k >= 0 {
+            self.column[k as usize].clone()
+        } else {
+            self.row[(-k) as usize].clone()
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// This uses the Levinson recursion, which requires every leading principal submatrix of
+    /// `self` to be invertible, and returns `None` otherwise. This is always the case for
+    /// Hermitian-definite-positive matrices, e.g., autocorrelation matrices.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// Returns `false` if the Levinson recursion fails, in which case `b` is left unchanged. See
+    /// [`ToeplitzMatrix::solve`] for details.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.nrows();
+        assert_eq!(b.nrows(), n, "Toeplitz solve: dimensions mismatch.");
+
+        if n == 0 {
+            return true;
+        }
+
+        let t0 = self.column[0].clone();
+        if t0.is_zero() {
+            return false;
+        }
+
+        // The solutions `x` of the leading subsystems of size `k`, and the first and last
+        // columns `f` and `g` of the inverses of the leading submatrices of size `k`.
+        let mut x = DMatrix::zeros(n, b.ncols());
+        let mut f = DVector::zeros(n);
+        let mut g = DVector::zeros(n);
+
+        f[0] = T::one() / t0.clone();
+        g[0] = f[0].clone();
+        for c in 0..b.ncols() {
+            x[(0, c)] = b[(0, c)].clone() / t0.clone();
+        }
+
+        for k in 1..n {
+            // The last element of `T_{k+1} * [f; 0]` and the first element of
+            // `T_{k+1} * [0; g]`.
+            let mut eps_f = T::zero();
+            let mut eps_g = T::zero();
+            for i in 0..k {
+                eps_f += self.column[k - i].clone() * f[i].clone();
+                eps_g += self.row[i + 1].clone() * g[i].clone();
+            }
+
+            let denom = T::one() - eps_f.clone() * eps_g.clone();
+            if denom.is_zero() {
+                return false;
+            }
+
+            for i in (0..=k).rev() {
+                let f_i = if i < k { f[i].clone() } else { T::zero() };
+                let g_i = if i > 0 { g[i - 1].clone() } else { T::zero() };
+
+                f[i] = (f_i.clone() - eps_f.clone() * g_i.clone()) / denom.clone();
+                g[i] = (g_i - eps_g.clone() * f_i) / denom.clone();
+            }
+
+            for c in 0..b.ncols() {
+                let mut eps_x = T::zero();
+                for i in 0..k {
+                    eps_x += self.column[k - i].clone() * x[(i, c)].clone();
+                }
+
+                let coeff = b[(k, c)].clone() - eps_x;
+                for i in 0..=k {
+                    x[(i, c)] += coeff.clone() * g[i].clone();
+                }
+            }
+        }
+
+        for (b, x) in b.iter_mut().zip(x.iter()) {
+            *b = x.clone();
+        }
+
+        true
+    }
+}
+
+impl<T: ComplexField> From<ToeplitzMatrix<T>> for DMatrix<T> {
+    fn from(m: ToeplitzMatrix<T>) -> Self {
+        m.to_dense()
+    }
+}
+
+impl<'a, T, R2, C2, S2> Mul<&'a Matrix<T, R2, C2, S2>> for &'a ToeplitzMatrix<T>
+where
+    T: ComplexField,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, R2, C2>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    fn mul(self, rhs: &'a Matrix<T, R2, C2, S2>) -> Self::Output {
+        let n = self.nrows();
+        assert_eq!(rhs.nrows(), n, "Matrix multiplication dimensions mismatch.");
+
+        let (nrows, ncols) = rhs.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            let mut res = T::zero();
+            for k in 0..n {
+                res += self.coeff(i as isize - k as isize) * rhs[(k, j)].clone();
+            }
+            res
+        })
+    }
+}
+
+/// A real square circulant matrix, whose columns are the successive cyclic shifts of its first
+/// column.
+///
+/// Circulant matrices are diagonalized by the discrete Fourier transform, so their products
+/// and linear systems of dimension `n` take `O(n log(n))` operations.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CirculantMatrix<T: RealField> {
+    column: DVector<T>,
+}
+
+impl<T: RealField> CirculantMatrix<T> {
+    /// Creates a circulant matrix from its first column.
+    ///
+    /// Its element `(i, j)` is `column[(i - j) mod n]`.
+    pub fn new(column: DVector<T>) -> Self {
+        Self { column }
+    }
+
+    /// Creates a circulant matrix from the first column of the square matrix `m`.
+    ///
+    /// The other elements of `m` are ignored.
+    pub fn from_dense<R: Dim, C: Dim, S: Storage<T, R, C>>(m: &Matrix<T, R, C, S>) -> Self {
+        assert!(
+            m.is_square(),
+            "Circulant matrix: the matrix must be square."
+        );
+        Self::new(DVector::from_fn(m.nrows(), |i, _| m[(i, 0)].clone()))
+    }
+
+    /// The dense matrix equal to `self`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let n = self.nrows();
+        DMatrix::from_fn(n, n, |i, j| self.column[(n + i - j) % n].clone())
+    }
+
+    /// The number of rows and columns of this matrix.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.column.len()
+    }
+
+    /// The first column of this matrix.
+    #[must_use]
+    pub fn column(&self) -> &DVector<T> {
+        &self.column
+    }
+
+    /// The eigenvalues of this matrix, i.e., the discrete Fourier transform of its first
+    /// column.
+    ///
+    /// The eigenvector associated to the `k`-th eigenvalue is `v[j] = exp(2πi * j * k / n)`.
+    #[must_use]
+    pub fn eigenvalues(&self) -> DVector<Complex<T>> {
+        self.column.map(|e| Complex::new(e, T::zero())).fft()
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` has an eigenvalue `λ` with `|λ| <= n * eps * max|λ|`, i.e., is
+    /// numerically singular.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// Returns `false` if `self` is numerically singular (see [`Self::solve`]), in which case `b`
+    /// is left unchanged.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.nrows();
+        assert_eq!(b.nrows(), n, "Circulant solve: dimensions mismatch.");
+
+        if n == 0 {
+            return true;
+        }
+
+        // The eigenvalues computed with the FFT have rounding errors of the order of
+        // `n * eps * max|λ|`, so exact zeros are not detected by comparing them to zero.
+        let eigenvalues = self.column.rfft();
+        let max = eigenvalues
+            .iter()
+            .map(|e| e.clone().modulus())
+            .fold(T::zero(), |a, b| a.max(b));
+        let threshold = T::default_epsilon() * crate::convert::<f64, T>(n as f64) * max;
+
+        if eigenvalues.iter().any(|e| e.clone().modulus() <= threshold) {
+            return false;
+        }
+
+        for mut col in b.column_iter_mut() {
+            let x = col.rfft().component_div(&eigenvalues).irfft(n);
+            for (c, x) in col.iter_mut().zip(x.iter()) {
+                *c = x.clone();
+            }
+        }
+
+        true
+    }
+}
+
+impl<T: RealField> From<CirculantMatrix<T>> for DMatrix<T> {
+    fn from(m: CirculantMatrix<T>) -> Self {
+        m.to_dense()
+    }
+}
+
+impl<'a, T, R2, C2, S2> Mul<&'a Matrix<T, R2, C2, S2>> for &'a CirculantMatrix<T>
+where
+    T: RealField,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, R2, C2>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    fn mul(self, rhs: &'a Matrix<T, R2, C2, S2>) -> Self::Output {
+        let n = self.nrows();
+        assert_eq!(rhs.nrows(), n, "Matrix multiplication dimensions mismatch.");
+
+        let eigenvalues = self.column.rfft();
+        let mut res = rhs.clone_owned();
+
+        for mut col in res.column_iter_mut() {
+            let y = col.rfft().component_mul(&eigenvalues).irfft(n);
+            for (c, y) in col.iter_mut().zip(y.iter()) {
+                *c = y.clone();
+            }
+        }
+
+        res
+    }
+}
@@ -0,0 +1,394 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+use std::cmp::Ordering;
+
+use crate::allocator::Allocator;
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, Vector, Vector2};
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimAdd, DimDiff, DimMin, DimSub, DimSum, U1};
+use crate::storage::Storage;
+
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::{LeastSquaresError, LeastSquaresSolution, QR};
+
+/// The QR decomposition of a general matrix, with an explicit unitary factor so that it can be
+/// updated efficiently.
+///
+/// This computes `A = Q * R` where `Q` is a square unitary matrix and `R` is an upper trapezoidal
+/// matrix with the same dimensions as `A`. Contrary to [`QR`], which stores `Q` implicitly as a
+/// sequence of householder reflections, both factors are stored explicitly. This allows rank-one
+/// updates as well as row and column insertions or removals with `O(n²)` Givens rotations instead
+/// of a `O(n³)` refactorization.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, R> +
+                           Allocator<T, R, C>,
+         OMatrix<T, R, R>: Serialize,
+         OMatrix<T, R, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, R> +
+                           Allocator<T, R, C>,
+         OMatrix<T, R, R>: Deserialize<'de>,
+         OMatrix<T, R, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct UpdatableQR<T: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, C>,
+{
+    q: OMatrix<T, R, R>,
+    r: OMatrix<T, R, C>,
+}
+
+impl<T: ComplexField, R: Dim, C: Dim> Copy for UpdatableQR<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, C>,
+    OMatrix<T, R, R>: Copy,
+    OMatrix<T, R, C>: Copy,
+{
+}
+
+impl<T: ComplexField, R: Dim, C: Dim> UpdatableQR<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, C>,
+{
+    /// Computes the QR decomposition of `matrix` with an explicit unitary factor.
+    pub fn new(matrix: OMatrix<T, R, C>) -> Self
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, <R as DimMin<C>>::Output>,
+    {
+        Self::from_qr(&QR::new(matrix))
+    }
+
+    /// Converts a householder-based QR decomposition into an updatable one.
+    pub fn from_qr(qr: &QR<T, R, C>) -> Self
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, <R as DimMin<C>>::Output>,
+    {
+        let nrows = qr.qr_internal().shape_generic().0;
+        let mut q = OMatrix::identity_generic(nrows, nrows);
+        qr.q_mul(&mut q);
+
+        let mut r = qr.qr_internal().upper_triangle();
+        for (i, d) in qr.diag_internal().iter().enumerate() {
+            r[(i, i)] = T::from_real(d.clone().modulus());
+        }
+
+        UpdatableQR { q, r }
+    }
+
+    /// The square unitary matrix `Q` of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> &OMatrix<T, R, R> {
+        &self.q
+    }
+
+    /// The upper trapezoidal matrix `R` of this decomposition.
+    #[must_use]
+    pub fn r(&self) -> &OMatrix<T, R, C> {
+        &self.r
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(Q, R)`.
+    pub fn unpack(self) -> (OMatrix<T, R, R>, OMatrix<T, R, C>) {
+        (self.q, self.r)
+    }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// The decomposed matrix must have at least as many rows as columns, and is assumed to have
+    /// full column rank. Returns [`LeastSquaresError::RankDeficient`] if the triangular factor `R`
+    /// is singular.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<LeastSquaresSolution<T, C, C2>, LeastSquaresError>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<T, R, C2> + Allocator<T, C, C2>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        assert_eq!(
+            nrows.value(),
+            b.nrows(),
+            "UpdatableQR least squares: matrix dimension mismatch."
+        );
+        assert!(
+            nrows.value() >= ncols.value(),
+            "UpdatableQR least squares: unable to solve an underdetermined system."
+        );
+
+        let n = ncols.value();
+        let qtb: OMatrix<T, R, C2> = self.q.ad_mul(b);
+        let mut solution = qtb.rows_generic(0, ncols).into_owned();
+
+        if !self
+            .r
+            .rows_generic(0, ncols)
+            .solve_upper_triangular_mut(&mut solution)
+        {
+            return Err(LeastSquaresError::RankDeficient);
+        }
+
+        Ok(LeastSquaresSolution {
+            solution,
+            residual_norm: qtb.rows_range(n..).norm(),
+            rank: n,
+        })
+    }
+
+    /// Given the QR decomposition of a matrix `M`, and two vectors `u` and `v`, performs a rank
+    /// one update such that we end up with the decomposition of `M + u * v.adjoint()`.
+    pub fn rank_one_update<R2: Dim, S2, R3: Dim, S3>(
+        &mut self,
+        u: &Vector<T, R2, S2>,
+        v: &Vector<T, R3, S3>,
+    ) where
+        S2: Storage<T, R2>,
+        S3: Storage<T, R3>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfRows<R3, C>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        assert_eq!(
+            nrows.value(),
+            u.nrows(),
+            "Rank one update: u has the wrong size."
+        );
+        assert_eq!(
+            ncols.value(),
+            v.nrows(),
+            "Rank one update: v has the wrong size."
+        );
+
+        let m = nrows.value();
+        if m == 0 {
+            return;
+        }
+
+        let mut w: OMatrix<T, R, U1> = self.q.ad_mul(u);
+
+        // Reduce `w` to a multiple of `e₀`, which turns `R` into an upper Hessenberg matrix.
+        for k in (1..m).rev() {
+            if let Some((rot, _)) = GivensRotation::cancel_y(&w.fixed_rows::<2>(k - 1)) {
+                rot.rotate(&mut w.fixed_rows_mut::<2>(k - 1));
+                self.apply_left(&rot, k - 1);
+            }
+        }
+
+        // `w[0] * e₀ * v.adjoint()` only affects the first row of `R`.
+        for (j, vj) in v.iter().enumerate() {
+            self.r[(0, j)] += w[0].clone() * vj.clone().conjugate();
+        }
+
+        self.cancel_subdiagonal(0);
+    }
+
+    /// Updates the decomposition such that we get the decomposition of a matrix with the given
+    /// column `col` inserted at the `j`th position.
+    #[must_use]
+    pub fn insert_column<R2: Dim, S2>(
+        &self,
+        j: usize,
+        col: &Vector<T, R2, S2>,
+    ) -> UpdatableQR<T, R, DimSum<C, U1>>
+    where
+        C: DimAdd<U1>,
+        S2: Storage<T, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<T, R, DimSum<C, U1>> + Allocator<T, R>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        let m = nrows.value();
+        assert_eq!(
+            m,
+            col.nrows(),
+            "Column insertion: the column has the wrong size."
+        );
+        assert!(
+            j <= ncols.value(),
+            "Column insertion: j needs to be within the bound of the new matrix."
+        );
+
+        let qtcol: OMatrix<T, R, U1> = self.q.ad_mul(col);
+        let r = Matrix::from_fn_generic(nrows, ncols.add(Const::<1>), |a, b| {
+            if b < j {
+                self.r[(a, b)].clone()
+            } else if b == j {
+                qtcol[a].clone()
+            } else {
+                self.r[(a, b - 1)].clone()
+            }
+        });
+
+        let mut res = UpdatableQR {
+            q: self.q.clone(),
+            r,
+        };
+
+        // Cancel the new column below the diagonal from the bottom up. Because the following
+        // columns are shifted to the right, this does not introduce any fill-in.
+        for k in (j + 1..m).rev() {
+            let v = Vector2::new(res.r[(k - 1, j)].clone(), res.r[(k, j)].clone());
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                res.apply_left(&rot, k - 1);
+                res.r[(k, j)] = T::zero();
+            }
+        }
+
+        res
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// its `j`th column removed.
+    #[must_use]
+    pub fn remove_column(&self, j: usize) -> UpdatableQR<T, R, DimDiff<C, U1>>
+    where
+        C: DimSub<U1>,
+        DefaultAllocator: Allocator<T, R, DimDiff<C, U1>>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        assert!(
+            j < ncols.value(),
+            "Column removal: j needs to be within the bound of the matrix."
+        );
+
+        let r = Matrix::from_fn_generic(nrows, ncols.sub(Const::<1>), |a, b| {
+            if b < j {
+                self.r[(a, b)].clone()
+            } else {
+                self.r[(a, b + 1)].clone()
+            }
+        });
+
+        let mut res = UpdatableQR {
+            q: self.q.clone(),
+            r,
+        };
+
+        // The columns after `j` now have one non-zero subdiagonal element.
+        res.cancel_subdiagonal(j);
+        res
+    }
+
+    /// Updates the decomposition such that we get the decomposition of a matrix with the given
+    /// row `row` inserted at the `i`th position.
+    #[must_use]
+    pub fn insert_row<C2: Dim, S2>(
+        &self,
+        i: usize,
+        row: &Matrix<T, U1, C2, S2>,
+    ) -> UpdatableQR<T, DimSum<R, U1>, C>
+    where
+        R: DimAdd<U1>,
+        S2: Storage<T, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C2, C>,
+        DefaultAllocator:
+            Allocator<T, DimSum<R, U1>, DimSum<R, U1>> + Allocator<T, DimSum<R, U1>, C>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        let m = nrows.value();
+        assert_eq!(
+            ncols.value(),
+            row.ncols(),
+            "Row insertion: the row has the wrong size."
+        );
+        assert!(
+            i <= m,
+            "Row insertion: i needs to be within the bound of the new matrix."
+        );
+
+        // [row; M] = diag(1, Q) * [row; R], followed by a permutation moving the first row to `i`.
+        let new_nrows = nrows.add(Const::<1>);
+        let q = Matrix::from_fn_generic(new_nrows, new_nrows, |a, b| match (a.cmp(&i), b) {
+            (Ordering::Equal, 0) => T::one(),
+            (Ordering::Equal, _) | (_, 0) => T::zero(),
+            (Ordering::Less, _) => self.q[(a, b - 1)].clone(),
+            (Ordering::Greater, _) => self.q[(a - 1, b - 1)].clone(),
+        });
+        let r = Matrix::from_fn_generic(new_nrows, ncols, |a, b| {
+            if a == 0 {
+                row[b].clone()
+            } else {
+                self.r[(a - 1, b)].clone()
+            }
+        });
+
+        let mut res = UpdatableQR { q, r };
+        res.cancel_subdiagonal(0);
+        res
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// its `i`th row removed.
+    #[must_use]
+    pub fn remove_row(&self, i: usize) -> UpdatableQR<T, DimDiff<R, U1>, C>
+    where
+        R: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<T, DimDiff<R, U1>, DimDiff<R, U1>> + Allocator<T, DimDiff<R, U1>, C>,
+    {
+        let (nrows, ncols) = self.r.shape_generic();
+        let m = nrows.value();
+        assert!(
+            i < m,
+            "Row removal: i needs to be within the bound of the matrix."
+        );
+
+        // Move the `i`th row of `Q` to the top.
+        let mut res = self.clone();
+        for k in (0..i).rev() {
+            res.q.swap_rows(k, k + 1);
+        }
+
+        // Reduce the first row of `Q` to a multiple of `e₀`. This turns `R` into an upper
+        // Hessenberg matrix whose first row corresponds to the removed row.
+        for k in (1..m).rev() {
+            let v = Vector2::new(
+                res.q[(0, k - 1)].clone().conjugate(),
+                res.q[(0, k)].clone().conjugate(),
+            );
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                res.apply_left(&rot, k - 1);
+                res.q[(0, k)] = T::zero();
+            }
+        }
+
+        // Since `Q` is unitary, its first column is now a multiple of `e₀` as well.
+        let new_nrows = nrows.sub(Const::<1>);
+        let q = Matrix::from_fn_generic(new_nrows, new_nrows, |a, b| res.q[(a + 1, b + 1)].clone());
+        let r = Matrix::from_fn_generic(new_nrows, ncols, |a, b| res.r[(a + 1, b)].clone());
+
+        UpdatableQR { q, r }
+    }
+
+    /// Performs `R = rot * R` on the rows `i` and `i + 1`, and updates `Q` accordingly.
+    fn apply_left(&mut self, rot: &GivensRotation<T>, i: usize) {
+        rot.rotate(&mut self.r.fixed_rows_mut::<2>(i));
+        rot.inverse()
+            .rotate_rows(&mut self.q.fixed_columns_mut::<2>(i));
+    }
+
+    /// Restores the upper trapezoidal structure of `R` when its columns `start..` have at most
+    /// one non-zero subdiagonal element.
+    fn cancel_subdiagonal(&mut self, start: usize) {
+        let (m, n) = self.r.shape();
+
+        for k in start..n.min(m.saturating_sub(1)) {
+            let v = Vector2::new(self.r[(k, k)].clone(), self.r[(k + 1, k)].clone());
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                self.apply_left(&rot, k);
+                self.r[(k + 1, k)] = T::zero();
+            }
+        }
+    }
+}
@@ -118,3 +118,44 @@ mod blas_proptest {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_gemm_matches_gemm() {
+    use na::DMatrix;
+
+    let a = DMatrix::from_fn(150, 130, |i, j| ((i * 7 + j * 3) % 13) as f64 - 6.0);
+    let b = DMatrix::from_fn(130, 90, |i, j| ((i * 5 + j * 11) % 17) as f64 - 8.0);
+    let c = DMatrix::from_fn(150, 90, |i, j| (i + j) as f64);
+
+    let mut expected = c.clone();
+    expected.gemm(2.0, &a, &b, -3.0);
+    let mut res = c.clone();
+    res.par_gemm(2.0, &a, &b, -3.0);
+    assert_eq!(res, expected);
+
+    // Strided views, and a single output column split along the rows.
+    let a = DMatrix::from_fn(5000, 70, |i, j| ((i + 3 * j) % 19) as f64);
+    let b = DMatrix::from_fn(70, 3, |i, j| (i * j) as f64);
+    let mut expected = DMatrix::zeros(5000, 1);
+    expected.gemm(1.0, &a.columns(0, 60), &b.view((10, 2), (60, 1)), 0.0);
+    let mut res = DMatrix::from_element(5000, 1, f64::NAN);
+    res.par_gemm(1.0, &a.columns(0, 60), &b.view((10, 2), (60, 1)), 0.0);
+    assert_eq!(res, expected);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_gemv_matches_gemv() {
+    use na::{DMatrix, DVector};
+
+    let a = DMatrix::from_fn(3000, 200, |i, j| ((i * 7 + j * 3) % 13) as f64 - 6.0);
+    let x = DVector::from_fn(200, |i, _| (i % 5) as f64);
+    let y = DVector::from_fn(3000, |i, _| i as f64);
+
+    let mut expected = y.clone();
+    expected.gemv(0.5, &a, &x, 2.0);
+    let mut res = y.clone();
+    res.par_gemv(0.5, &a, &x, 2.0);
+    assert_eq!(res, expected);
+}
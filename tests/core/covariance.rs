@@ -0,0 +1,69 @@
+use na::{DMatrix, DVector, Matrix2, Matrix3x2, Matrix4x2, RowVector2, Vector3};
+
+#[test]
+fn covariance_matches_variance() {
+    let m = DMatrix::from_fn(7, 3, |i, j| ((i * 3 + j * j) as f64).sin() * 10.0);
+    let cov = m.covariance();
+
+    assert_relative_eq!(cov, cov.transpose(), epsilon = 1.0e-12);
+    assert_relative_eq!(cov.diagonal(), m.row_variance_tr(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn covariance_catastrophic_cancellation() {
+    let m = Matrix3x2::new(1.0e9 + 1.0, 2.0, 1.0e9 + 2.0, 4.0, 1.0e9 + 3.0, 3.0);
+    let expected = Matrix2::new(2.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0);
+    assert_relative_eq!(m.covariance(), expected, epsilon = 1.0e-8);
+}
+
+#[test]
+fn correlation_perfectly_correlated() {
+    let m = Matrix4x2::new(1.0, -2.0, 2.0, -4.0, 3.0, -6.0, 5.0, -10.0);
+    let expected = Matrix2::new(1.0, -1.0, -1.0, 1.0);
+    assert_relative_eq!(m.correlation(), expected, epsilon = 1.0e-12);
+
+    // A constant variable has no defined correlation.
+    let m = Matrix4x2::<f64>::new(1.0, 3.0, 2.0, 3.0, 3.0, 3.0, 5.0, 3.0);
+    let corr = m.correlation();
+    assert_eq!(corr[(0, 0)], 1.0);
+    assert!(corr[(1, 1)].is_nan());
+}
+
+#[test]
+fn weighted_covariance_integer_weights() {
+    // Integer weights are equivalent to repeating the observations.
+    let m = Matrix3x2::new(1.0, 2.0, 2.0, 4.0, 3.0, 3.0);
+    let repeated = Matrix4x2::new(1.0, 2.0, 2.0, 4.0, 3.0, 3.0, 3.0, 3.0);
+    let weights = Vector3::new(1.0, 1.0, 2.0);
+
+    assert_eq!(m.weighted_row_mean(&weights), RowVector2::new(2.25, 3.0));
+    assert_relative_eq!(
+        m.weighted_row_mean(&weights),
+        repeated.row_mean(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.weighted_covariance(&weights),
+        repeated.covariance(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn weighted_covariance_zero_weight() {
+    // Observations with a zero weight are ignored.
+    let m = DMatrix::from_row_slice(4, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 3.0, 100.0, -100.0]);
+    let weights = DVector::from_vec(vec![0.5, 0.5, 0.5, 0.0]);
+
+    assert_relative_eq!(
+        m.weighted_covariance(&weights),
+        m.rows(0, 3).covariance(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn covariance_empty() {
+    let m = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(m.covariance(), DMatrix::zeros(3, 3));
+}
@@ -825,6 +825,52 @@ fn partial_cmp() {
     assert_eq!(a.partial_cmp(&d), None);
 }
 
+#[test]
+fn induced_norms() {
+    use na::{InducedInfNorm, InducedOneNorm, NuclearNorm, SpectralNorm};
+
+    let a = Matrix2x3::new(1.0, -2.0, 3.0, -4.0, 5.0, -6.0);
+    let b = Matrix2x3::new(1.0, -2.0, 3.0, -4.0, 5.0, 0.0);
+
+    assert_eq!(a.apply_norm(&InducedOneNorm), 9.0);
+    assert_eq!(a.apply_norm(&InducedInfNorm), 15.0);
+    assert_eq!(a.apply_metric_distance(&b, &InducedOneNorm), 6.0);
+    assert_eq!(a.apply_metric_distance(&b, &InducedInfNorm), 6.0);
+
+    let d = Matrix3::from_diagonal(&Vector3::new(3.0, -4.0, 0.5));
+    assert!(relative_eq!(
+        d.apply_norm(&SpectralNorm),
+        4.0,
+        epsilon = 1.0e-12
+    ));
+    assert!(relative_eq!(
+        d.apply_norm(&NuclearNorm),
+        7.5,
+        epsilon = 1.0e-12
+    ));
+    assert!(relative_eq!(
+        d.apply_metric_distance(&Matrix3::identity(), &SpectralNorm),
+        5.0,
+        epsilon = 1.0e-12
+    ));
+    assert!(relative_eq!(
+        d.apply_metric_distance(&Matrix3::identity(), &NuclearNorm),
+        7.5,
+        epsilon = 1.0e-12
+    ));
+
+    // For vectors, the induced norms are the usual vector norms.
+    let v = Vector3::new(1.0, -2.0, 2.0);
+    assert_eq!(v.apply_norm(&InducedOneNorm), 5.0);
+    assert_eq!(v.apply_norm(&InducedInfNorm), 2.0);
+    assert!(relative_eq!(
+        v.apply_norm(&SpectralNorm),
+        3.0,
+        epsilon = 1.0e-12
+    ));
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).apply_norm(&SpectralNorm), 0.0);
+}
+
 #[test]
 fn swizzle() {
     let a = Vector2::new(1.0f32, 2.0);
@@ -1003,6 +1049,29 @@ mod normalization_tests {
     }
 }
 
+#[cfg(feature = "proptest-support")]
+mod induced_norm_tests {
+    use crate::proptest::*;
+    use na::{EuclideanNorm, InducedInfNorm, InducedOneNorm, NuclearNorm, SpectralNorm};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn induced_norm_inequalities(m in dmatrix()) {
+            let one = m.apply_norm(&InducedOneNorm);
+            let inf = m.apply_norm(&InducedInfNorm);
+            let spectral = m.apply_norm(&SpectralNorm);
+            let nuclear = m.apply_norm(&NuclearNorm);
+            let frobenius = m.apply_norm(&EuclideanNorm);
+
+            prop_assert!(relative_eq!(one, m.transpose().apply_norm(&InducedInfNorm)));
+            prop_assert!(spectral <= (one * inf).sqrt() * (1.0 + 1.0e-7) + 1.0e-7);
+            prop_assert!(spectral <= frobenius * (1.0 + 1.0e-7) + 1.0e-7);
+            prop_assert!(frobenius <= nuclear * (1.0 + 1.0e-7) + 1.0e-7);
+        }
+    }
+}
+
 #[cfg(all(feature = "proptest-support", feature = "alga"))]
 // TODO: move this to alga ?
 mod finite_dim_inner_space_tests {
@@ -1332,3 +1401,88 @@ fn parallel_column_iteration_mut() {
     assert_eq!(first, second);
     assert_eq!(second, DMatrix::identity(400, 300));
 }
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_map_and_reductions() {
+    let m = DMatrix::from_fn(400, 300, |i, j| (i as f64 * 0.1 - j as f64 * 0.3).sin());
+
+    assert_eq!(m.par_map(|e| e * 2.0 + 1.0), m.map(|e| e * 2.0 + 1.0));
+    assert_relative_eq!(m.par_sum(), m.sum(), epsilon = 1.0e-9);
+    assert_relative_eq!(m.par_norm(), m.norm(), epsilon = 1.0e-9);
+    assert_relative_eq!(m.par_norm_squared(), m.norm_squared(), epsilon = 1.0e-9);
+
+    let mut res = m.clone();
+    let rhs = m.map(|e| e.cos());
+    res.par_zip_apply(&rhs, |a, b| *a = *a * b - 1.0);
+    assert_eq!(res, m.zip_map(&rhs, |a, b| a * b - 1.0));
+
+    // Views are iterated in column-major order.
+    let view = m.view((10, 20), (30, 40));
+    assert_eq!(view.par_map(|e| e.abs()), view.map(|e| e.abs()));
+}
+
+#[test]
+fn row_iteration_double_ended() {
+    let dmat = nalgebra::dmatrix![
+    13,14,15;
+    23,24,25;
+    33,34,35;
+    ];
+    let mut row_iter = dmat.row_iter();
+    assert_eq!(row_iter.next(), Some(dmat.row(0)));
+    assert_eq!(row_iter.next_back(), Some(dmat.row(2)));
+    assert_eq!(row_iter.len(), 1);
+    assert_eq!(row_iter.next_back(), Some(dmat.row(1)));
+    assert_eq!(row_iter.next(), None);
+    assert_eq!(row_iter.next_back(), None);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_row_iteration() {
+    use rayon::prelude::*;
+    let dmat = DMatrix::from_fn(300, 40, |i, j| (i * 100 + j) as f64);
+
+    let par_sums: Vec<f64> = dmat.par_row_iter().map(|row| row.sum()).collect();
+    let sums: Vec<f64> = dmat.row_iter().map(|row| row.sum()).collect();
+    assert_eq!(par_sums, sums);
+
+    let mut first = DMatrix::<f32>::zeros(400, 300);
+    let mut second = DMatrix::<f32>::zeros(400, 300);
+    first
+        .row_iter_mut()
+        .enumerate()
+        .for_each(|(idx, mut row)| row[idx % 300] = idx as f32);
+    second
+        .par_row_iter_mut()
+        .enumerate()
+        .for_each(|(idx, mut row)| row[idx % 300] = idx as f32);
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_columns_chunks() {
+    use rayon::prelude::*;
+    let dmat = DMatrix::from_fn(50, 103, |i, j| (i + j * 50) as f64);
+
+    let chunks: Vec<_> = dmat.par_columns_chunks(10).collect();
+    assert_eq!(chunks.len(), 11);
+    assert_eq!(chunks[3], dmat.columns(30, 10));
+    assert_eq!(chunks[10], dmat.columns(100, 3));
+
+    let mut res = DMatrix::<f64>::zeros(50, 103);
+    res.par_columns_chunks_mut(10)
+        .zip(dmat.par_columns_chunks(10))
+        .for_each(|(mut out, block)| out.copy_from(&(block * 2.0)));
+    assert_eq!(res, dmat * 2.0);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "rayon")]
+fn parallel_columns_chunks_zero_size() {
+    let dmat = DMatrix::<f64>::zeros(3, 3);
+    let _ = dmat.par_columns_chunks(0);
+}
@@ -1,12 +1,14 @@
 mod blas;
 mod cg;
 mod conversion;
+mod covariance;
 mod edition;
 mod empty;
 mod matrix;
 mod matrix_view;
 #[cfg(feature = "mint")]
 mod mint;
+mod order_statistics;
 mod reshape;
 #[cfg(feature = "rkyv-serialize-no-std")]
 mod rkyv;
@@ -0,0 +1,85 @@
+use na::{DMatrix, DVector, Matrix3x4, QuantileInterpolation, RowVector4, Vector3};
+
+#[test]
+fn median_odd_and_even() {
+    assert_eq!(DVector::from_vec(vec![5.0, 1.0, 3.0]).median(), 3.0);
+    assert_eq!(DVector::from_vec(vec![5.0, 1.0, 3.0, 10.0]).median(), 4.0);
+    assert_eq!(DVector::from_vec(vec![-2.0]).median(), -2.0);
+}
+
+#[test]
+fn quantile_interpolation_modes() {
+    // Same values as numpy's `quantile` with the corresponding methods.
+    let v = DVector::from_vec(vec![10.0, 1.0, 7.0, 4.0]);
+    let expected = [
+        (QuantileInterpolation::Linear, 3.25, 5.5),
+        (QuantileInterpolation::Lower, 1.0, 4.0),
+        (QuantileInterpolation::Higher, 4.0, 7.0),
+        (QuantileInterpolation::Nearest, 4.0, 7.0),
+        (QuantileInterpolation::Midpoint, 2.5, 5.5),
+    ];
+
+    for (interpolation, q_025, q_05) in expected {
+        assert_eq!(v.quantile(0.0, interpolation), 1.0);
+        assert_eq!(v.quantile(0.25, interpolation), q_025);
+        assert_eq!(v.quantile(0.5, interpolation), q_05);
+        assert_eq!(v.quantile(1.0, interpolation), 10.0);
+    }
+
+    // Ties of the nearest interpolation are resolved towards the even index.
+    let v = DVector::from_vec(vec![0.0, 1.0, 2.0]);
+    assert_eq!(v.quantile(0.25, QuantileInterpolation::Nearest), 0.0);
+    assert_eq!(v.quantile(0.75, QuantileInterpolation::Nearest), 2.0);
+}
+
+#[test]
+#[should_panic]
+fn quantile_empty() {
+    let _ = DVector::<f64>::zeros(0).median();
+}
+
+#[test]
+#[rustfmt::skip]
+fn median_along_axes() {
+    let m = Matrix3x4::new(
+        1.0, 8.0, 3.0, 0.0,
+        7.0, 2.0, 6.0, 0.0,
+        4.0, 5.0, 9.0, 1.0);
+
+    assert_eq!(m.row_median(), RowVector4::new(4.0, 5.0, 6.0, 0.0));
+    assert_eq!(m.row_median_tr(), m.row_median().transpose());
+    assert_eq!(m.column_median(), Vector3::new(2.0, 4.0, 4.5));
+    assert_eq!(
+        m.column_quantile(1.0, QuantileInterpolation::Linear),
+        Vector3::new(8.0, 7.0, 9.0)
+    );
+    assert_eq!(
+        m.row_quantile(0.0, QuantileInterpolation::Linear),
+        RowVector4::new(1.0, 2.0, 3.0, 0.0)
+    );
+}
+
+#[test]
+fn argsort_is_stable() {
+    let v = DVector::from_vec(vec![2, 1, 2, 0, 1]);
+    assert_eq!(v.argsort(), vec![3, 1, 4, 0, 2]);
+
+    // Matrices are sorted in column-major order.
+    let m = DMatrix::from_row_slice(2, 2, &[4.0, 1.0, 3.0, 2.0]);
+    assert_eq!(m.argsort(), vec![2, 3, 1, 0]);
+}
+
+#[test]
+fn sort_columns_and_rows_by() {
+    let m = DMatrix::from_fn(6, 9, |i, j| ((i * 9 + j * 7) % 11) as f64);
+
+    let mut sorted = m.clone();
+    let perm = sorted.sort_columns_by(|a, b| a.sum().partial_cmp(&b.sum()).unwrap());
+    assert_eq!(sorted, m.select_columns(&perm));
+    assert!(sorted.row_sum().as_slice().windows(2).all(|w| w[0] <= w[1]));
+
+    let mut sorted = m.clone();
+    let perm = sorted.sort_rows_by(|a, b| b[0].partial_cmp(&a[0]).unwrap());
+    assert_eq!(sorted, m.select_rows(&perm));
+    assert!(sorted.column(0).as_slice().windows(2).all(|w| w[0] >= w[1]));
+}
@@ -0,0 +1,186 @@
+use na::{BandedMatrix, DMatrix, DVector, Matrix4, TridiagonalMatrix, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn tridiagonal_conversions() {
+    let m = Matrix4::new(
+        2.0, -1.0, 0.0, 9.0,
+        -1.0, 2.0, -1.0, 0.0,
+        0.0, -1.0, 2.0, -1.0,
+        9.0, 0.0, -1.0, 2.0);
+    let tri = TridiagonalMatrix::from_dense(&m);
+
+    assert_eq!(tri.nrows(), 4);
+    assert_eq!(tri.diagonal(), &DVector::from_element(4, 2.0));
+    assert_eq!(tri.subdiagonal(), &DVector::from_element(3, -1.0));
+
+    // The elements outside of the band are dropped.
+    let mut expected = m;
+    expected[(0, 3)] = 0.0;
+    expected[(3, 0)] = 0.0;
+    assert_eq!(tri.to_dense(), expected);
+    assert_eq!(tri.to_banded().to_dense(), expected);
+    assert_eq!(DMatrix::from(tri.clone()), expected);
+
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(&tri * &v, expected * v);
+}
+
+#[test]
+fn tridiagonal_solve() {
+    let n = 50;
+    let tri = TridiagonalMatrix::new(
+        DVector::from_fn(n - 1, |i, _| (i as f64).sin()),
+        DVector::from_fn(n, |i, _| 4.0 + (i as f64).cos()),
+        DVector::from_fn(n - 1, |i, _| 1.0 - (i % 3) as f64),
+    );
+    let b = DMatrix::from_fn(n, 3, |i, j| (i * j) as f64 - 7.0);
+
+    let x = tri.solve(&b).unwrap();
+    assert!(relative_eq!(&tri * &x, b, epsilon = 1.0e-10));
+    assert!(relative_eq!(
+        x,
+        tri.to_dense().lu().solve(&b).unwrap(),
+        epsilon = 1.0e-10
+    ));
+
+    // A zero pivot.
+    let singular = TridiagonalMatrix::new(
+        DVector::from_element(2, 1.0),
+        DVector::from_element(3, 1.0),
+        DVector::from_element(2, 1.0),
+    );
+    assert!(singular.solve(&DVector::from_element(3, 1.0)).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn banded_conversions() {
+    let m = Matrix4::new(
+        1.0, 2.0, 0.0, 0.0,
+        3.0, 4.0, 5.0, 0.0,
+        6.0, 7.0, 8.0, 9.0,
+        0.0, 10.0, 11.0, 12.0);
+    let mut banded = BandedMatrix::from_dense(&m, 2, 1);
+
+    assert_eq!((banded.nrows(), banded.lower_bandwidth(), banded.upper_bandwidth()), (4, 2, 1));
+    assert_eq!(banded.band().shape(), (4, 4));
+    assert_eq!(banded.get(2, 0), Some(&6.0));
+    assert_eq!(banded.get(0, 2), None);
+    assert_eq!(banded.get(3, 4), None);
+    assert_eq!(banded.to_dense(), m);
+
+    *banded.get_mut(3, 1).unwrap() = -1.0;
+    assert_eq!(DMatrix::from(banded)[(3, 1)], -1.0);
+}
+
+#[test]
+fn banded_lu() {
+    let n = 30;
+    // A banded matrix that requires pivoting.
+    let banded = BandedMatrix::from_fn(n, 2, 3, |i, j| {
+        if i == j {
+            0.0
+        } else {
+            ((i * 7 + j * 3) % 11) as f64 - 5.0
+        }
+    });
+    let dense = banded.to_dense();
+    let b = DMatrix::from_fn(n, 2, |i, j| (i + j) as f64);
+
+    let lu = banded.lu();
+    let x = lu.solve(&b).unwrap();
+    assert!(relative_eq!(&dense * &x, b, epsilon = 1.0e-9));
+
+    assert!(lu.is_invertible());
+    assert!(relative_eq!(
+        lu.determinant(),
+        dense.determinant(),
+        max_relative = 1.0e-9
+    ));
+
+    let singular = BandedMatrix::from_fn(3, 1, 1, |i, _| if i == 1 { 0.0 } else { 1.0 });
+    let lu = singular.lu();
+    assert!(!lu.is_invertible());
+    assert!(lu.solve(&DVector::from_element(3, 1.0)).is_none());
+}
+
+#[test]
+fn banded_cholesky() {
+    let n = 40;
+    // A symmetric-definite-positive pentadiagonal matrix.
+    let banded = BandedMatrix::from_fn(n, 2, 2, |i, j| match i.abs_diff(j) {
+        0 => 6.0,
+        1 => -2.0,
+        _ => 0.5,
+    });
+    let dense = banded.to_dense();
+    let b = DVector::from_fn(n, |i, _| (i as f64).sqrt());
+
+    let chol = banded.clone().cholesky().unwrap();
+    let x = chol.solve(&b);
+    assert!(relative_eq!(&dense * &x, b, epsilon = 1.0e-10));
+
+    let l = chol.l().to_dense();
+    assert_eq!(l.lower_triangle(), l);
+    assert!(relative_eq!(&l * l.transpose(), dense, epsilon = 1.0e-10));
+    assert!(relative_eq!(
+        chol.determinant(),
+        dense.determinant(),
+        max_relative = 1.0e-9
+    ));
+
+    // Only the lower band is read.
+    let mut upper = BandedMatrix::from_dense(&dense, 2, 2);
+    *upper.get_mut(0, 2).unwrap() = 100.0;
+    assert!(relative_eq!(
+        upper.cholesky().unwrap().solve(&b),
+        x,
+        epsilon = 1.0e-12
+    ));
+
+    let indefinite = BandedMatrix::from_fn(3, 1, 1, |i, j| if i == j { 1.0 } else { 2.0 });
+    assert!(indefinite.cholesky().is_none());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{BandedMatrix, DMatrix};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn banded_lu(n in 1usize..20, kl in 0usize..4, ku in 0usize..4) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let banded = BandedMatrix::from_dense(&m, kl, ku);
+                        let b = DMatrix::<$scalar_type>::new_random(n, 2).map(|e| e.0);
+
+                        let x = banded.clone().lu().solve(&b).unwrap();
+                        prop_assert!(relative_eq!(&banded * &x, b, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn banded_cholesky(n in 1usize..20, kl in 0usize..4) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        // A Hermitian and strictly diagonally dominant banded matrix.
+                        let banded = BandedMatrix::from_dense(&m, kl, kl).to_dense();
+                        let diag = DMatrix::from_diagonal_element(n, n, na::convert(4.0 * (kl + 1) as f64));
+                        let spd = BandedMatrix::from_dense(&(&banded + banded.adjoint() + diag), kl, kl);
+                        let b = DMatrix::<$scalar_type>::new_random(n, 2).map(|e| e.0);
+
+                        let x = spd.clone().cholesky().unwrap().solve(&b);
+                        prop_assert!(relative_eq!(&spd * &x, b, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -1,6 +1,6 @@
 #[cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::Matrix4;
+use na::{DMatrix, Matrix3, Matrix4};
 
 #[test]
 fn col_piv_qr() {
@@ -22,6 +22,52 @@ fn col_piv_qr() {
     assert!(relative_eq!(m, qr, epsilon = 1.0e-7));
 }
 
+#[test]
+fn col_piv_qr_kahan() {
+    // The Kahan matrix `diag(1, s, s², …) * (I - c * U)`, where `U` is the strictly upper
+    // triangular matrix of ones and `s² + c² = 1`. All its columns have a unit norm.
+    let (n, c) = (20, 0.3f64);
+    let s = (1.0 - c * c).sqrt();
+    let kahan = DMatrix::from_fn(n, n, |i, j| {
+        let row_scale = s.powi(i as i32);
+        if i == j {
+            row_scale
+        } else if i < j {
+            -c * row_scale
+        } else {
+            0.0
+        }
+    });
+    let reference_rank = kahan.clone().col_piv_qr().rank(0.5);
+    assert!(reference_rank > 0 && reference_rank < n);
+
+    for scale in [1.0, 1.0e-20, 1.0e20] {
+        let m = &kahan * scale;
+        let qr = m.clone().col_piv_qr();
+        let r = qr.r();
+
+        // Pivoting on the column norms yields a non-increasing diagonal, starting with the
+        // largest column norm.
+        assert!(relative_eq!(r[(0, 0)].abs(), scale, max_relative = 1.0e-12));
+        for i in 1..n {
+            assert!(r[(i, i)].abs() <= r[(i - 1, i - 1)].abs() * (1.0 + 1.0e-12));
+        }
+
+        // The rank tolerance is relative, hence independent of the scale.
+        assert_eq!(qr.rank(1.0e-10), n);
+        assert_eq!(qr.rank(0.5), reference_rank);
+    }
+}
+
+#[test]
+fn col_piv_qr_rank_deficient_scaled() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+
+    for scale in [1.0, 1.0e-20, 1.0e20] {
+        assert_eq!((m * scale).col_piv_qr().rank(1.0e-10), 2);
+    }
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
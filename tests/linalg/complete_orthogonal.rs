@@ -0,0 +1,133 @@
+use na::{DMatrix, DVector, Matrix3x4};
+
+#[test]
+#[rustfmt::skip]
+fn complete_orthogonal_rank_deficient() {
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        1.0, 0.0, 1.0, 0.0);
+
+    let cod = m.complete_orthogonal_decomposition(1.0e-10);
+    assert_eq!(cod.rank(), 2);
+
+    let t = cod.t();
+    assert!(relative_eq!(t.view_range(2.., ..).amax(), 0.0));
+    assert!(relative_eq!(t.view_range(.., 2..).amax(), 0.0));
+
+    let mut recomposed = cod.q() * t * cod.z().transpose();
+    cod.p().inv_permute_columns(&mut recomposed);
+    assert!(relative_eq!(m, recomposed, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn complete_orthogonal_minimum_norm_solve() {
+    let m = DMatrix::from_row_slice(4, 3, &[
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+    ]);
+    let b = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+
+    let sol = m.clone().complete_orthogonal_decomposition(1.0e-10).solve_least_squares(&b);
+    let reference = m.clone().svd(true, true).solve_least_squares(&b).unwrap();
+
+    assert_eq!(sol.rank, 2);
+    assert!(relative_eq!(sol.solution, reference.solution, epsilon = 1.0e-10));
+    assert!(relative_eq!(sol.residual_norm, reference.residual_norm, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn null_and_column_spaces() {
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        1.0, 0.0, 1.0, 0.0);
+
+    let null_space = m.null_space(1.0e-10);
+    let column_space = m.column_space(1.0e-10);
+
+    assert_eq!(null_space.shape(), (4, 2));
+    assert_eq!(column_space.shape(), (3, 2));
+    assert!(relative_eq!((m * &null_space).norm(), 0.0, epsilon = 1.0e-10));
+    assert!(relative_eq!(null_space.transpose() * &null_space, DMatrix::identity(2, 2), epsilon = 1.0e-10));
+    assert!(relative_eq!(column_space.transpose() * &column_space, DMatrix::identity(2, 2), epsilon = 1.0e-10));
+
+    // Every column of `m` is its own projection onto the column space.
+    let projection = &column_space * column_space.transpose();
+    assert!(relative_eq!(projection * m, m, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn null_and_column_spaces_scaled() {
+    // The rank tolerance is relative, so scaling the matrix does not change its rank.
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        1.0, 0.0, 1.0, 0.0);
+
+    for scale in [1.0e-12, 1.0e12] {
+        let scaled = m * scale;
+        assert_eq!(scaled.complete_orthogonal_decomposition(1.0e-10).rank(), 2);
+        assert_eq!(scaled.null_space(1.0e-10).ncols(), 2);
+        assert_eq!(scaled.column_space(1.0e-10).ncols(), 2);
+    }
+}
+
+#[test]
+fn null_space_full_rank() {
+    let m = DMatrix::<f64>::identity(3, 3);
+    assert_eq!(m.null_space(1.0e-10).ncols(), 0);
+    assert_eq!(m.column_space(1.0e-10).ncols(), 3);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn complete_orthogonal(m in dmatrix_($scalar)) {
+                        let cod = m.clone().complete_orthogonal_decomposition(1.0e-10);
+                        let mut recomposed = cod.q() * cod.t() * cod.z().adjoint();
+                        cod.p().inv_permute_columns(&mut recomposed);
+
+                        prop_assert!(cod.z().is_orthogonal(1.0e-7));
+                        prop_assert!(relative_eq!(m, recomposed, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn complete_orthogonal_low_rank(n in PROPTEST_MATRIX_DIM, rank in 1usize..4) {
+                        let rank = rank.min(n);
+                        let m = DMatrix::<$scalar_type>::new_random(n, rank).map(|e| e.0) * DMatrix::<$scalar_type>::new_random(rank, n + 1).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, 2).map(|e| e.0);
+                        let cod = m.clone().complete_orthogonal_decomposition(1.0e-10);
+                        prop_assert_eq!(cod.rank(), rank);
+
+                        let null_space = m.null_space(1.0e-10);
+                        prop_assert_eq!(null_space.ncols(), n + 1 - rank);
+                        prop_assert!(relative_eq!(&m * &null_space, DMatrix::zeros(n, n + 1 - rank), epsilon = 1.0e-7));
+
+                        // The minimum-norm solution is orthogonal to the null space.
+                        let sol = cod.solve_least_squares(&b);
+                        prop_assert!(relative_eq!(null_space.adjoint() * sol.solution, DMatrix::zeros(n + 1 - rank, 2), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,159 @@
+use na::{Complex, DMatrix, DVector, Dyn, Matrix3};
+
+fn one_norm(m: &DMatrix<f64>) -> f64 {
+    m.column_iter()
+        .map(|c| c.iter().map(|e| e.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+fn exact_rcond(m: &DMatrix<f64>) -> f64 {
+    let inv = m.clone().try_inverse().unwrap();
+    1.0 / (one_norm(m) * one_norm(&inv))
+}
+
+#[test]
+fn rcond_hilbert() {
+    let m = DMatrix::from_fn(6, 6, |i, j| 1.0 / (i + j + 1) as f64);
+    let exact = exact_rcond(&m);
+
+    let estimates = [
+        m.clone().lu().rcond(),
+        m.clone().full_piv_lu().rcond(),
+        m.clone().qr().rcond(),
+        m.clone().cholesky().unwrap().rcond(),
+    ];
+
+    for estimate in estimates {
+        assert!(estimate >= exact * (1.0 - 1.0e-6));
+        assert!(estimate <= exact * 10.0);
+    }
+
+    assert!(exact < 1.0e-7);
+}
+
+#[test]
+fn rcond_identity_and_singular() {
+    let id = Matrix3::<f64>::identity();
+    assert!(relative_eq!(id.lu().rcond(), 1.0, epsilon = 1.0e-12));
+    assert!(relative_eq!(
+        id.full_piv_lu().rcond(),
+        1.0,
+        epsilon = 1.0e-12
+    ));
+    assert!(relative_eq!(id.qr().rcond(), 1.0, epsilon = 1.0e-12));
+    assert!(relative_eq!(
+        id.cholesky().unwrap().rcond(),
+        1.0,
+        epsilon = 1.0e-12
+    ));
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0);
+    assert_eq!(singular.lu().rcond(), 0.0);
+    assert_eq!(singular.full_piv_lu().rcond(), 0.0);
+}
+
+#[test]
+fn estimate_rcond_matrix_free() {
+    // A diagonal operator only known through its products and solves.
+    let diag = DVector::from_fn(5, |i, _| (i + 1) as f64);
+    let mul = |x: &mut DVector<f64>| {
+        x.component_mul_assign(&diag);
+        true
+    };
+    let solve = |x: &mut DVector<f64>| {
+        x.component_div_assign(&diag);
+        true
+    };
+
+    let rcond = na::linalg::estimate_rcond(Dyn(5), mul, mul, solve, solve);
+    assert!(relative_eq!(rcond, 0.2, epsilon = 1.0e-12));
+
+    // A failed solve means that the operator is singular.
+    let rcond = na::linalg::estimate_rcond(Dyn(5), mul, mul, |_| false, |_| false);
+    assert_eq!(rcond, 0.0);
+}
+
+#[test]
+fn rcond_complex() {
+    let m = DMatrix::from_fn(5, 5, |i, j| {
+        Complex::new(1.0 / (i + j + 1) as f64, (i as f64 - j as f64) * 0.1)
+    });
+    let inv = m.clone().try_inverse().unwrap();
+    let norm = |m: &DMatrix<Complex<f64>>| {
+        m.column_iter()
+            .map(|c| c.iter().map(|e| e.norm()).sum::<f64>())
+            .fold(0.0, f64::max)
+    };
+    let exact = 1.0 / (norm(&m) * norm(&inv));
+
+    for estimate in [m.clone().lu().rcond(), m.clone().qr().rcond()] {
+        assert!(estimate >= exact * (1.0 - 1.0e-6));
+        assert!(estimate <= exact * 10.0);
+    }
+
+    let qr = m.clone().qr();
+    let mut x = m.column(0).into_owned();
+    qr.q_tr_mul(&mut x);
+    qr.q_mul(&mut x);
+    assert!(relative_eq!(x, m.column(0).into_owned(), epsilon = 1.0e-12));
+}
+
+#[test]
+fn condition_number_2_norm() {
+    let m = Matrix3::from_diagonal(&na::Vector3::new(1.0, -4.0, 2.0));
+    assert!(relative_eq!(m.condition_number(), 4.0, epsilon = 1.0e-12));
+
+    let rectangular = DMatrix::from_row_slice(3, 2, &[3.0, 0.0, 0.0, 0.5, 0.0, 0.0]);
+    assert!(relative_eq!(
+        rectangular.condition_number(),
+        6.0,
+        epsilon = 1.0e-12
+    ));
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0);
+    assert!(singular.condition_number() > 1.0e12);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).condition_number(), 1.0);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::DMatrix;
+    use proptest::{prop_assert, proptest};
+
+    use crate::proptest::*;
+
+    proptest! {
+        #[test]
+        fn rcond_bounds(n in PROPTEST_MATRIX_DIM) {
+            let n = n.max(1);
+            let m = DMatrix::<f64>::new_random(n, n);
+
+            if let Some(inv) = m.clone().try_inverse() {
+                let exact = 1.0 / (super::one_norm(&m) * super::one_norm(&inv));
+
+                for estimate in [m.clone().lu().rcond(), m.clone().full_piv_lu().rcond(), m.clone().qr().rcond()] {
+                    prop_assert!(estimate >= exact * (1.0 - 1.0e-6));
+                    prop_assert!(estimate <= exact * 10.0);
+                }
+
+                // The 1-norm and 2-norm condition numbers are within a factor `n` of each other.
+                let kappa = m.condition_number();
+                prop_assert!(kappa <= n as f64 / exact * (1.0 + 1.0e-6));
+                prop_assert!(kappa >= 1.0 / (n as f64 * exact) * (1.0 - 1.0e-6));
+            }
+        }
+
+        #[test]
+        fn rcond_spd(n in PROPTEST_MATRIX_DIM) {
+            let n = n.max(1);
+            let m = DMatrix::<f64>::new_random(n, n);
+            let m = &m * m.transpose() + DMatrix::identity(n, n) * 1.0e-3;
+            let inv = m.clone().try_inverse().unwrap();
+            let exact = 1.0 / (super::one_norm(&m) * super::one_norm(&inv));
+            let estimate = m.cholesky().unwrap().rcond();
+
+            prop_assert!(estimate >= exact * (1.0 - 1.0e-6));
+            prop_assert!(estimate <= exact * 10.0);
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use na::{DVector, Vector2, Vector3, Vector4, Vector5};
+use na::{
+    dmatrix, dvector, ConvolutionBoundary, ConvolutionMode, DMatrix, DVector, Matrix2, Vector1,
+    Vector2, Vector3, Vector4, Vector5,
+};
 use std::panic;
 
 //
@@ -23,6 +26,21 @@ fn convolve_same_check() {
 
     assert!(relative_eq!(actual_d, expected_d, epsilon = 1.0e-7));
 
+    // >>> convolve([1,2,3,4],[1,2,3],"same")
+    // array([ 4, 10, 16, 17])
+    let odd = Vector4::new(1.0, 2.0, 3.0, 4.0).convolve_same(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(odd, Vector4::new(4.0, 10.0, 16.0, 17.0));
+
+    // >>> convolve([1,2,3,4,5],[1,1,1,1],"same")
+    // array([ 3,  6, 10, 14, 12])
+    let even = Vector5::new(1.0, 2.0, 3.0, 4.0, 5.0).convolve_same(Vector4::repeat(1.0));
+    assert_eq!(even, Vector5::new(3.0, 6.0, 10.0, 14.0, 12.0));
+
+    // >>> convolve([1,2,3,4],[2],"same")
+    // array([2, 4, 6, 8])
+    let single = Vector4::new(1.0, 2.0, 3.0, 4.0).convolve_same(Vector1::new(2.0));
+    assert_eq!(single, Vector4::new(2.0, 4.0, 6.0, 8.0));
+
     // Panic Tests
     // These really only apply to dynamic sized vectors
     assert!(panic::catch_unwind(|| {
@@ -117,3 +135,158 @@ fn convolve_valid_check() {
     })
     .is_err());
 }
+
+// >>> convolve2d([[1,2,3],[4,5,6],[7,8,9]], [[1,2],[3,4]], "full")
+// array([[ 1,  4,  7,  6],
+//        [ 7, 23, 33, 24],
+//        [19, 53, 63, 42],
+//        [21, 52, 59, 36]])
+#[test]
+fn convolve2d_modes_check() {
+    let image = dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+    let kernel = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let zero = ConvolutionBoundary::Zero;
+
+    let full = dmatrix![
+        1.0, 4.0, 7.0, 6.0;
+        7.0, 23.0, 33.0, 24.0;
+        19.0, 53.0, 63.0, 42.0;
+        21.0, 52.0, 59.0, 36.0
+    ];
+    assert_eq!(image.convolve2d(&kernel, ConvolutionMode::Full, zero), full);
+    assert_eq!(
+        image.convolve2d(&kernel, ConvolutionMode::Valid, zero),
+        full.view((1, 1), (2, 2))
+    );
+    assert_eq!(
+        image.convolve2d(&kernel, ConvolutionMode::Same, zero),
+        full.view((0, 0), (3, 3))
+    );
+
+    // Correlating with the kernel rotated by 180 degrees is a convolution.
+    let rotated = Matrix2::new(4.0, 3.0, 2.0, 1.0);
+    for mode in [
+        ConvolutionMode::Full,
+        ConvolutionMode::Valid,
+        ConvolutionMode::Same,
+    ] {
+        assert_eq!(
+            image.correlate2d(&rotated, mode, zero),
+            image.convolve2d(&kernel, mode, zero)
+        );
+    }
+}
+
+#[test]
+fn convolve2d_boundaries_check() {
+    // A kernel that shifts the matrix two columns to the right.
+    let row = dmatrix![1.0, 2.0, 3.0, 4.0];
+    let shift = dmatrix![1.0, 0.0, 0.0, 0.0, 0.0];
+    let same = |boundary| row.correlate2d(&shift, ConvolutionMode::Same, boundary);
+
+    assert_eq!(
+        same(ConvolutionBoundary::Zero),
+        dmatrix![0.0, 0.0, 1.0, 2.0]
+    );
+    assert_eq!(
+        same(ConvolutionBoundary::Reflect),
+        dmatrix![2.0, 1.0, 1.0, 2.0]
+    );
+    assert_eq!(
+        same(ConvolutionBoundary::Wrap),
+        dmatrix![3.0, 4.0, 1.0, 2.0]
+    );
+    assert_eq!(
+        same(ConvolutionBoundary::Clamp),
+        dmatrix![1.0, 1.0, 1.0, 2.0]
+    );
+
+    // The discrete laplacian.
+    let image = dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+    let laplacian = dmatrix![0.0, 1.0, 0.0; 1.0, -4.0, 1.0; 0.0, 1.0, 0.0];
+    let same = |boundary| image.convolve2d(&laplacian, ConvolutionMode::Same, boundary);
+
+    assert_eq!(
+        same(ConvolutionBoundary::Zero),
+        dmatrix![2.0, 1.0, -4.0; -3.0, 0.0, -7.0; -16.0, -11.0, -22.0]
+    );
+    assert_eq!(
+        same(ConvolutionBoundary::Reflect),
+        dmatrix![4.0, 3.0, 2.0; 1.0, 0.0, -1.0; -2.0, -3.0, -4.0]
+    );
+    assert_eq!(
+        same(ConvolutionBoundary::Wrap),
+        dmatrix![12.0, 9.0, 6.0; 3.0, 0.0, -3.0; -6.0, -9.0, -12.0]
+    );
+
+    // The boundary is ignored in `Valid` mode.
+    let valid = image.convolve2d(
+        &laplacian,
+        ConvolutionMode::Valid,
+        ConvolutionBoundary::Wrap,
+    );
+    assert_eq!(valid, dmatrix![0.0]);
+}
+
+#[test]
+fn convolve2d_separable_check() {
+    let image = DMatrix::from_fn(7, 9, |i, j| ((i * 3 + j * 5) % 11) as f64 - 4.0);
+    let col_kernel = dvector![1.0, -2.0, 0.5];
+    let row_kernel = Vector2::new(3.0, 1.0);
+    let kernel = &col_kernel * row_kernel.transpose();
+
+    for mode in [
+        ConvolutionMode::Full,
+        ConvolutionMode::Valid,
+        ConvolutionMode::Same,
+    ] {
+        for boundary in [
+            ConvolutionBoundary::Zero,
+            ConvolutionBoundary::Reflect,
+            ConvolutionBoundary::Wrap,
+            ConvolutionBoundary::Clamp,
+        ] {
+            assert!(relative_eq!(
+                image.convolve2d_separable(&col_kernel, &row_kernel, mode, boundary),
+                image.convolve2d(&kernel, mode, boundary),
+                epsilon = 1.0e-12
+            ));
+            assert!(relative_eq!(
+                image.correlate2d_separable(&col_kernel, &row_kernel, mode, boundary),
+                image.correlate2d(&kernel, mode, boundary),
+                epsilon = 1.0e-12
+            ));
+        }
+    }
+}
+
+#[test]
+fn convolve2d_panics_check() {
+    let image = DMatrix::<f64>::zeros(3, 3);
+    let zero = ConvolutionBoundary::Zero;
+
+    assert!(panic::catch_unwind(|| {
+        let _ = image.convolve2d(&DMatrix::zeros(0, 2), ConvolutionMode::Full, zero);
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = image.correlate2d(&DMatrix::zeros(2, 4), ConvolutionMode::Valid, zero);
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = image.convolve2d_separable(
+            &DVector::zeros(2),
+            &DVector::zeros(0),
+            ConvolutionMode::Same,
+            zero,
+        );
+    })
+    .is_err());
+
+    // A kernel larger than the matrix is allowed in `Full` and `Same` modes.
+    let big = DMatrix::from_element(5, 5, 1.0);
+    let same = image.convolve2d(&big, ConvolutionMode::Same, ConvolutionBoundary::Reflect);
+    assert_eq!(same.shape(), (3, 3));
+}
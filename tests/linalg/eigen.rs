@@ -1,11 +1,16 @@
-use na::DMatrix;
+use na::allocator::Allocator;
+use na::{
+    Complex, DMatrix, DefaultAllocator, Dim, Eigen, Matrix2, Matrix3, Matrix4, OMatrix,
+    SymmetricEigen,
+};
+use std::ops::MulAssign;
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
         ($module: ident, $scalar: expr, $scalar_type: ty) => {
             mod $module {
-                use na::DMatrix;
+                use na::{ComplexField, DMatrix};
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use std::cmp;
@@ -36,6 +41,23 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
                     }
 
+                    #[test]
+                    fn symmetric_eigen_generalized(n in PROPTEST_MATRIX_DIM) {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let k      = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let m      = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m      = &m * m.adjoint() + DMatrix::identity(n, n);
+                        let eig    = na::SymmetricEigen::new_generalized(k.clone(), m.clone()).unwrap();
+                        let vecs   = &eig.eigenvectors;
+                        let mut mv = &m * vecs;
+                        for (j, mut col) in mv.column_iter_mut().enumerate() {
+                            col.apply(|e| *e = e.scale(eig.eigenvalues[j]));
+                        }
+
+                        prop_assert!(relative_eq!(&k * vecs, mv, epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(vecs.adjoint() * &m * vecs, DMatrix::identity(n, n), epsilon = 1.0e-5));
+                    }
+
                     #[test]
                     fn symmetric_eigen_static_square_4x4(m in matrix4_($scalar)) {
                         let m      = m.hermitian_part();
@@ -116,88 +138,184 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
-//  #[cfg(feature = "arbitrary")]
-//  quickcheck! {
-// TODO: full eigendecomposition is not implemented yet because of its complexity when some
-// eigenvalues have multiplicity > 1.
-//
-//    /*
-//     * NOTE: for the following tests, we use only upper-triangular matrices.
-//     * This ensures the schur decomposition will work, and allows use to test the eigenvector
-//     * computation.
-//     */
-//    fn eigen(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_adjacent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some adjacent diagonal elements.
-//        for i in 0 .. n / 2 {
-//            m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_nonadjacent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(3, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some diagonal elements.
-//        for i in n / 2 .. n {
-//            m[(i, i)] = m[(i - n / 2, i - n / 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        println!("{}", m);
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//  }
-//
-// fn verify_eigenvectors<D: Dim>(m: OMatrix<f64, D>, mut eig: RealEigen<f64, D>) -> bool
-//     where DefaultAllocator: Allocator<f64, D, D>   +
-//                             Allocator<f64, D>      +
-//                             Allocator<usize, D, D> +
-//                             Allocator<usize, D>,
-//           OMatrix<f64, D>: Display,
-//           OVector<f64, D>: Display {
-//     let mv = &m * &eig.eigenvectors;
-//
-//     println!("eigenvalues: {}eigenvectors: {}", eig.eigenvalues, eig.eigenvectors);
-//
-//     let dim = m.nrows();
-//     for i in 0 .. dim {
-//         let mut col = eig.eigenvectors.column_mut(i);
-//         col *= eig.eigenvalues[i];
-//     }
-//
-//     println!("{}{:.5}{:.5}", m, mv, eig.eigenvectors);
-//
-//     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
-// }
+#[test]
+#[rustfmt::skip]
+fn symmetric_eigen_generalized_mass_spring() {
+    // Three masses connected by springs, fixed at one end.
+    let k = Matrix3::new(
+        2.0, -1.0, 0.0,
+        -1.0, 2.0, -1.0,
+        0.0, -1.0, 1.0);
+    let m = Matrix3::from_diagonal(&na::Vector3::new(2.0, 1.0, 0.5));
+
+    let eig = SymmetricEigen::new_generalized(k, m).unwrap();
+    let vecs = eig.eigenvectors;
+    let vals = Matrix3::from_diagonal(&eig.eigenvalues);
+
+    assert!(relative_eq!(k * vecs, m * vecs * vals, epsilon = 1.0e-10));
+    assert!(relative_eq!(vecs.transpose() * m * vecs, Matrix3::identity(), epsilon = 1.0e-10));
+}
+
+#[test]
+fn symmetric_eigen_generalized_indefinite_mass() {
+    let k = Matrix2::new(2.0, 1.0, 1.0, 3.0);
+    let m = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+
+    assert!(SymmetricEigen::new_generalized(k, m).is_none());
+}
+
+fn verify_eigenvectors<D: Dim>(m: &OMatrix<f64, D, D>, eig: &Eigen<f64, D>) -> bool
+where
+    DefaultAllocator:
+        Allocator<f64, D, D> + Allocator<Complex<f64>, D, D> + Allocator<Complex<f64>, D>,
+{
+    let mc = m.map(Complex::from);
+    let mv = &mc * &eig.eigenvectors;
+    let mut vl = eig.eigenvectors.clone();
+
+    for i in 0..m.nrows() {
+        vl.column_mut(i).mul_assign(eig.eigenvalues[i]);
+    }
+
+    let scale = m.amax().max(1.0);
+    relative_eq!(mv, vl, epsilon = 1.0e-7 * scale)
+}
+
+#[test]
+fn eigen_rotation() {
+    let angle = 0.7_f64;
+    let (s, c) = angle.sin_cos();
+    let m = Matrix2::new(c, -s, s, c);
+    let eig = m.eigen();
+
+    assert!(!eig.eigenvalues_are_real());
+    assert!(relative_eq!(
+        eig.eigenvalues[0],
+        Complex::new(c, s),
+        epsilon = 1.0e-10
+    ));
+    assert!(relative_eq!(
+        eig.eigenvalues[1],
+        Complex::new(c, -s),
+        epsilon = 1.0e-10
+    ));
+    assert!(verify_eigenvectors(&m, &eig));
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_complex_conjugate_pairs() {
+    // Companion matrix of (x² + 1)(x² + 4)(x - 3).
+    let m = DMatrix::from_row_slice(5, 5, &[
+        0.0, 0.0, 0.0, 0.0, 12.0,
+        1.0, 0.0, 0.0, 0.0, -4.0,
+        0.0, 1.0, 0.0, 0.0, 15.0,
+        0.0, 0.0, 1.0, 0.0, -5.0,
+        0.0, 0.0, 0.0, 1.0, 3.0,
+    ]);
+    let eig = m.clone().eigen();
+
+    let mut moduli: Vec<_> = eig.eigenvalues.iter().map(|e| e.norm()).collect();
+    moduli.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!(relative_eq!(&moduli[..], &[1.0, 1.0, 2.0, 2.0, 3.0][..], epsilon = 1.0e-8));
+    assert!(verify_eigenvectors(&m, &eig));
+}
+
+#[test]
+fn eigen_identity() {
+    let m = Matrix4::<f64>::identity();
+    let eig = m.eigen();
+
+    assert!(eig.eigenvalues_are_real());
+    assert!(verify_eigenvectors(&m, &eig));
+    // Repeated but non-defective eigenvalues still yield a basis.
+    assert!(eig.eigenvectors.determinant().norm() > 0.5);
+}
+
+#[test]
+fn eigen_empty() {
+    let m = DMatrix::<f64>::zeros(0, 0);
+    let eig = m.clone().eigen();
+
+    assert!(eig.eigenvalues.is_empty());
+    assert!(eig.eigenvectors.is_empty());
+    assert!(eig.eigenvalues_are_real());
+    assert!(m.schur().eigenvalues().unwrap().is_empty());
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_defective() {
+    let m = Matrix3::new(
+        2.0, 1.0, 0.0,
+        0.0, 2.0, 1.0,
+        0.0, 0.0, 2.0);
+    let eig = m.eigen();
+
+    assert!(verify_eigenvectors(&m, &eig));
+}
+
+#[cfg(feature = "proptest-support")]
+mod eigen_proptest_tests {
+    use super::verify_eigenvectors;
+    use crate::proptest::*;
+    use na::DMatrix;
+    use proptest::{prop_assert, proptest};
+    use std::cmp;
+
+    proptest! {
+        #[test]
+        fn eigen(m in dmatrix()) {
+            let n = cmp::min(m.nrows(), m.ncols());
+            let m = m.view((0, 0), (n, n)).into_owned();
+            let eig = m.clone().eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+
+        #[test]
+        fn eigen_with_adjacent_duplicate_diagonals(n in PROPTEST_MATRIX_DIM) {
+            let n = cmp::max(1, cmp::min(n, 10));
+            let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
+
+            // Duplicate some adjacent diagonal elements.
+            for i in 0..n / 2 {
+                m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
+            }
+
+            let eig = m.clone().eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+
+        #[test]
+        fn eigen_with_nonadjacent_duplicate_diagonals(n in PROPTEST_MATRIX_DIM) {
+            let n = cmp::max(3, cmp::min(n, 10));
+            let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
+
+            // Duplicate some diagonal elements.
+            for i in n / 2..n {
+                m[(i, i)] = m[(i - n / 2, i - n / 2)];
+            }
+
+            let eig = m.clone().eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+
+        #[test]
+        fn eigen_static_square_4x4(m in matrix4()) {
+            let eig = m.eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+
+        #[test]
+        fn eigen_static_square_3x3(m in matrix3()) {
+            let eig = m.eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+
+        #[test]
+        fn eigen_static_square_2x2(m in matrix2()) {
+            let eig = m.eigen();
+            prop_assert!(verify_eigenvectors(&m, &eig))
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use na::{Complex, DVector, Vector4};
+use std::f64::consts::PI;
+
+/// The discrete Fourier transform computed from its definition.
+fn naive_dft(v: &DVector<Complex<f64>>, inverse: bool) -> DVector<Complex<f64>> {
+    let n = v.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    DVector::from_fn(n, |k, _| {
+        (0..n)
+            .map(|j| {
+                let angle = sign * 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                v[j] * Complex::new(angle.cos(), angle.sin())
+            })
+            .sum()
+    })
+}
+
+fn signal(n: usize) -> DVector<Complex<f64>> {
+    DVector::from_fn(n, |i, _| {
+        Complex::new((i as f64 * 0.7).sin() + 1.0, (i as f64 * 1.3).cos())
+    })
+}
+
+#[test]
+fn fft_check() {
+    for n in 0..70 {
+        let v = signal(n);
+        let scale = 1.0 + n as f64;
+
+        assert!(relative_eq!(
+            v.fft(),
+            naive_dft(&v, false),
+            epsilon = 1.0e-12 * scale
+        ));
+        assert!(relative_eq!(
+            v.ifft(),
+            naive_dft(&v, true).unscale(n.max(1) as f64),
+            epsilon = 1.0e-12
+        ));
+        assert!(relative_eq!(v.fft().ifft(), v, epsilon = 1.0e-12));
+    }
+
+    // Static vectors and views.
+    let v = Vector4::new(1.0, 0.0, -1.0, 0.0).map(|e| Complex::new(e, 0.0));
+    let expected = Vector4::new(0.0, 2.0, 0.0, 2.0).map(|e| Complex::new(e, 0.0));
+    assert!(relative_eq!(v.fft(), expected, epsilon = 1.0e-12));
+
+    let v = signal(20);
+    let view = v.rows_with_step(1, 6, 2);
+    assert!(relative_eq!(
+        view.fft(),
+        naive_dft(&view.into_owned(), false),
+        epsilon = 1.0e-12
+    ));
+}
+
+#[test]
+fn rfft_check() {
+    for n in 0..70 {
+        let v = DVector::from_fn(n, |i, _| (i as f64 * 0.3).sin() + (i % 5) as f64);
+        let spectrum = v.map(|e| Complex::new(e, 0.0)).fft();
+        let rspectrum = v.rfft();
+
+        assert_eq!(rspectrum.len(), n / 2 + 1);
+        if n > 0 {
+            assert!(relative_eq!(
+                rspectrum,
+                spectrum.rows(0, n / 2 + 1).into_owned(),
+                epsilon = 1.0e-12 * (1.0 + n as f64)
+            ));
+        }
+        assert!(relative_eq!(rspectrum.irfft(n), v, epsilon = 1.0e-12));
+    }
+}
+
+#[test]
+fn fft_convolve_check() {
+    // Long enough to use the FFT path.
+    let signal = DVector::from_fn(1000, |i, _| ((i * 7) % 13) as f64 - 6.0);
+    let kernel = DVector::from_fn(200, |i, _| ((i * 5) % 11) as f64 - 5.0);
+    let (n, k) = (signal.len(), kernel.len());
+
+    let expected = DVector::from_fn(n + k - 1, |i, _| {
+        (0..k)
+            .filter(|j| *j <= i && i - j < n)
+            .map(|j| signal[i - j] * kernel[j])
+            .sum::<f64>()
+    });
+
+    let full = signal.convolve_full(kernel.clone());
+    assert!(relative_eq!(full, expected, epsilon = 1.0e-8));
+
+    let valid = signal.convolve_valid(kernel.clone());
+    assert!(relative_eq!(
+        valid,
+        expected.rows(k - 1, n - k + 1).into_owned(),
+        epsilon = 1.0e-8
+    ));
+
+    let same = signal.convolve_same(kernel.clone());
+    assert!(relative_eq!(
+        same,
+        expected.rows((k - 1) / 2, n).into_owned(),
+        epsilon = 1.0e-8
+    ));
+}
+
+#[test]
+fn convolve_direct_and_fft_paths_agree() {
+    // With 1000 elements, kernels up to 135 elements use the direct path, and longer kernels use
+    // the FFT path. Check odd and even kernels on both sides of that threshold.
+    let signal = DVector::from_fn(1000, |i, _| ((i * 7) % 13) as f64 - 6.0);
+    let n = signal.len();
+
+    for k in 128..=144 {
+        let kernel = DVector::from_fn(k, |i, _| ((i * 5) % 11) as f64 - 5.0);
+        let expected = DVector::from_fn(n + k - 1, |i, _| {
+            (0..k)
+                .filter(|j| *j <= i && i - j < n)
+                .map(|j| signal[i - j] * kernel[j])
+                .sum::<f64>()
+        });
+
+        let same = signal.convolve_same(kernel.clone());
+        assert!(relative_eq!(
+            same,
+            expected.rows((k - 1) / 2, n).into_owned(),
+            epsilon = 1.0e-8
+        ));
+
+        let full = signal.convolve_full(kernel.clone());
+        assert!(relative_eq!(full, expected, epsilon = 1.0e-8));
+
+        let valid = signal.convolve_valid(kernel);
+        assert!(relative_eq!(
+            valid,
+            expected.rows(k - 1, n - k + 1).into_owned(),
+            epsilon = 1.0e-8
+        ));
+
+        // A delta kernel at the center shifts the signal by the same amount on both paths.
+        let delta = DVector::from_fn(k, |i, _| if i == (k - 1) / 2 { 1.0 } else { 0.0 });
+        assert!(relative_eq!(
+            signal.convolve_same(delta),
+            signal,
+            epsilon = 1.0e-8
+        ));
+    }
+}
@@ -0,0 +1,327 @@
+use na::linalg::{
+    conjugate_gradient, gmres, minres, FnOperator, IdentityPreconditioner, JacobiPreconditioner,
+};
+use na::{Complex, DMatrix, DVector};
+
+/// The 1D Laplacian, with a varying diagonal to make the Jacobi preconditioner useful.
+fn laplacian(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            2.0 + (i % 7) as f64 * 10.0
+        } else if i.abs_diff(j) == 1 {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[test]
+fn conjugate_gradient_spd() {
+    let n = 50;
+    let a = laplacian(n);
+    let b = DVector::from_fn(n, |i, _| (i as f64).sin());
+
+    let sol = conjugate_gradient(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-10,
+        0,
+    );
+    assert!(sol.converged);
+    assert!(sol.residual_norm <= 1.0e-10 * b.norm() * 10.0);
+    assert_eq!(sol.residual_history.len(), sol.niter + 1);
+    assert!(relative_eq!(&a * &sol.solution, b, epsilon = 1.0e-8));
+
+    let jacobi = JacobiPreconditioner::new(&a.diagonal());
+    let precond = conjugate_gradient(&a, &b, None, &jacobi, 1.0e-10, 0);
+    assert!(precond.converged);
+    assert!(precond.niter < sol.niter);
+    assert!(relative_eq!(&a * &precond.solution, b, epsilon = 1.0e-8));
+
+    // Starting from the solution requires no iteration.
+    let restarted = conjugate_gradient(&a, &b, Some(precond.solution), &jacobi, 1.0e-6, 0);
+    assert!(restarted.converged);
+    assert_eq!(restarted.niter, 0);
+}
+
+#[test]
+fn conjugate_gradient_complex() {
+    let n = 20;
+    let m = DMatrix::from_fn(n, n, |i, j| {
+        Complex::new(((i + j) as f64).cos(), (i as f64 - j as f64).sin())
+    });
+    let a = m.adjoint() * &m + DMatrix::identity(n, n) * Complex::from(n as f64);
+    let b = DVector::from_fn(n, |i, _| Complex::new(1.0, i as f64));
+
+    let sol = conjugate_gradient(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    assert!(sol.converged);
+    assert!(relative_eq!(&a * sol.solution, b, epsilon = 1.0e-8));
+}
+
+#[test]
+fn minres_indefinite() {
+    let n = 40;
+    // A symmetric matrix with both positive and negative eigenvalues.
+    let a = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            if i % 2 == 0 {
+                4.0 + i as f64
+            } else {
+                -4.0 - i as f64
+            }
+        } else if i.abs_diff(j) <= 2 {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| 1.0 + (i as f64).cos());
+
+    let sol = minres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    assert!(sol.converged);
+    assert!(relative_eq!(&a * &sol.solution, b, epsilon = 1.0e-8));
+
+    // The residual estimates of MINRES are non-increasing, and match the true residual.
+    assert!(sol
+        .residual_history
+        .windows(2)
+        .all(|w| w[1] <= w[0] * (1.0 + 1.0e-12)));
+    assert!(relative_eq!(
+        *sol.residual_history.last().unwrap(),
+        sol.residual_norm,
+        epsilon = 1.0e-8
+    ));
+
+    // The preconditioner must be definite-positive, so use the absolute value of the diagonal.
+    let jacobi = JacobiPreconditioner::new(&a.diagonal().abs());
+    let precond = minres(&a, &b, None, &jacobi, 1.0e-12, 0);
+    assert!(precond.converged);
+    assert!(relative_eq!(&a * &precond.solution, b, epsilon = 1.0e-8));
+}
+
+#[test]
+fn gmres_nonsymmetric() {
+    let n = 60;
+    let a = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            5.0 + i as f64 * 0.1
+        } else if j == i + 1 {
+            2.0
+        } else if i == j + 1 {
+            -1.0
+        } else if j == i + 3 {
+            0.5
+        } else {
+            0.0
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| (i as f64 * 0.7).sin());
+
+    let full = gmres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        n,
+        1.0e-12,
+        0,
+    );
+    assert!(full.converged);
+    assert!(relative_eq!(&a * &full.solution, b, epsilon = 1.0e-9));
+
+    let restarted = gmres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        5,
+        1.0e-12,
+        0,
+    );
+    assert!(restarted.converged);
+    assert!(restarted.niter >= full.niter);
+    assert!(relative_eq!(&a * &restarted.solution, b, epsilon = 1.0e-9));
+
+    let jacobi = JacobiPreconditioner::new(&a.diagonal());
+    let precond = gmres(&a, &b, None, &jacobi, 10, 1.0e-12, 0);
+    assert!(precond.converged);
+    assert!(precond.residual_norm <= 1.0e-10);
+    assert!(relative_eq!(&a * &precond.solution, b, epsilon = 1.0e-9));
+}
+
+#[test]
+fn gmres_happy_breakdown() {
+    // The Krylov subspace of `b` has dimension 2, so GMRES converges exactly in 2 iterations.
+    let a = DMatrix::from_diagonal(&DVector::from_fn(10, |i, _| if i < 5 { 2.0 } else { 3.0 }));
+    let b = DVector::from_element(10, 1.0);
+
+    let sol = gmres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        10,
+        1.0e-14,
+        0,
+    );
+    assert!(sol.converged);
+    assert_eq!(sol.niter, 2);
+    assert!(relative_eq!(&a * sol.solution, b, epsilon = 1.0e-12));
+}
+
+#[test]
+fn krylov_matrix_free_operator() {
+    let n = 30;
+    let dense = laplacian(n);
+    let operator = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+        for i in 0..n {
+            y[i] = dense[(i, i)] * x[i];
+            if i > 0 {
+                y[i] -= x[i - 1];
+            }
+            if i + 1 < n {
+                y[i] -= x[i + 1];
+            }
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| i as f64);
+
+    let expected = dense.clone().lu().solve(&b).unwrap();
+    let cg = conjugate_gradient(
+        &operator,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    let mr = minres(
+        &operator,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    let gm = gmres(
+        &operator,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        10,
+        1.0e-12,
+        0,
+    );
+
+    for sol in [cg, mr, gm] {
+        assert!(sol.converged);
+        assert!(relative_eq!(sol.solution, expected, epsilon = 1.0e-8));
+    }
+}
+
+#[test]
+fn krylov_not_converged() {
+    let n = 50;
+    let a = laplacian(n);
+    let b = DVector::from_fn(n, |i, _| (i as f64).sin());
+
+    let cg = conjugate_gradient(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-14,
+        3,
+    );
+    let mr = minres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-14,
+        3,
+    );
+    let gm = gmres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        2,
+        1.0e-14,
+        3,
+    );
+
+    for sol in [cg, mr, gm] {
+        assert!(!sol.converged);
+        assert_eq!(sol.niter, 3);
+        assert!(sol.residual_norm > 1.0e-14 * b.norm());
+        assert!(relative_eq!(
+            (&b - &a * &sol.solution).norm(),
+            sol.residual_norm,
+            epsilon = 1.0e-12
+        ));
+    }
+}
+
+#[test]
+fn krylov_zero_rhs() {
+    let a = laplacian(10);
+    let b = DVector::zeros(10);
+
+    let cg = conjugate_gradient(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    let mr = minres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        1.0e-12,
+        0,
+    );
+    let gm = gmres(
+        &a,
+        &b,
+        None,
+        &IdentityPreconditioner::new(b.len()),
+        5,
+        1.0e-12,
+        0,
+    );
+
+    for sol in [cg, mr, gm] {
+        assert!(sol.converged);
+        assert_eq!(sol.niter, 0);
+        assert_eq!(sol.solution, b);
+    }
+}
+
+#[test]
+#[should_panic]
+fn krylov_dimension_mismatch() {
+    let a = laplacian(10);
+    let b = DVector::zeros(9);
+    let _ = conjugate_gradient(&a, &b, None, &IdentityPreconditioner::new(9), 1.0e-12, 0);
+}
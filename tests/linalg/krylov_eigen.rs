@@ -0,0 +1,395 @@
+use na::linalg::{
+    arnoldi, arnoldi_shift_invert, lanczos, lanczos_shift_invert, EigenvalueSelection, FnOperator,
+    KrylovEigenOptions,
+};
+use na::{Complex, DMatrix, DVector};
+
+fn options(
+    nev: usize,
+    which: EigenvalueSelection,
+    ncv: usize,
+    tolerance: f64,
+    max_niter: usize,
+) -> KrylovEigenOptions<f64> {
+    KrylovEigenOptions {
+        nev,
+        which,
+        ncv,
+        tolerance,
+        max_niter,
+    }
+}
+
+/// The Laplacian of a 2D grid graph, which is singular and has many repeated eigenvalues.
+fn grid_laplacian(nx: usize, ny: usize) -> DMatrix<f64> {
+    let n = nx * ny;
+    let mut laplacian = DMatrix::zeros(n, n);
+
+    for i in 0..nx {
+        for j in 0..ny {
+            let k = i * ny + j;
+            let mut neighbors = |l: usize| {
+                laplacian[(k, l)] -= 1.0;
+                laplacian[(k, k)] += 1.0;
+            };
+            if i > 0 {
+                neighbors(k - ny);
+            }
+            if i + 1 < nx {
+                neighbors(k + ny);
+            }
+            if j > 0 {
+                neighbors(k - 1);
+            }
+            if j + 1 < ny {
+                neighbors(k + 1);
+            }
+        }
+    }
+
+    laplacian
+}
+
+/// A non-symmetric matrix with both real and complex eigenvalues.
+fn non_symmetric(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| match j as isize - i as isize {
+        0 => (i as f64 * 0.37).sin() * 10.0,
+        1 => 1.0 + (i % 3) as f64,
+        -1 => -2.0,
+        3 => 0.5,
+        _ => 0.0,
+    })
+}
+
+fn sorted(mut values: Vec<f64>) -> Vec<f64> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values
+}
+
+#[test]
+fn lanczos_extremal_eigenvalues() {
+    let a = grid_laplacian(12, 9);
+    let n = a.nrows();
+    let expected = sorted(a.clone().symmetric_eigenvalues().as_slice().to_vec());
+
+    let largest = lanczos(
+        &a,
+        options(5, EigenvalueSelection::LargestReal, 30, 1.0e-10, 0),
+    );
+    assert!(largest.converged);
+    for i in 0..5 {
+        assert_relative_eq!(
+            largest.eigenvalues[i],
+            expected[n - 1 - i],
+            epsilon = 1.0e-8
+        );
+    }
+
+    let smallest = lanczos(
+        &a,
+        options(3, EigenvalueSelection::SmallestReal, 40, 1.0e-10, 0),
+    );
+    assert!(smallest.converged);
+    for i in 0..3 {
+        assert_relative_eq!(smallest.eigenvalues[i], expected[i], epsilon = 1.0e-8);
+    }
+
+    for eigen in [largest, smallest] {
+        let v = &eigen.eigenvectors;
+        assert_relative_eq!(
+            v.adjoint() * v,
+            DMatrix::identity(v.ncols(), v.ncols()),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            &a * v,
+            v * DMatrix::from_diagonal(&eigen.eigenvalues),
+            epsilon = 1.0e-7
+        );
+        assert!(eigen.residual_norms.iter().all(|r| *r <= 1.0e-8));
+    }
+}
+
+#[test]
+fn lanczos_magnitude_selection() {
+    // A matrix whose eigenvalues are `-10, -9.5, ..., 9.5, 10` with a random-like basis.
+    let n = 41;
+    let q = DMatrix::from_fn(n, n, |i, j| ((i * 7 + j * 13) as f64).sin())
+        .qr()
+        .q();
+    let d = DVector::from_fn(n, |i, _| i as f64 * 0.5 - 10.0);
+    let a = &q * DMatrix::from_diagonal(&d) * q.transpose();
+
+    let largest = lanczos(
+        &a,
+        options(2, EigenvalueSelection::LargestMagnitude, 10, 1.0e-10, 0),
+    );
+    assert!(largest.converged);
+    let mut values = largest.eigenvalues.as_slice().to_vec();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(values[0], -10.0, epsilon = 1.0e-8);
+    assert_relative_eq!(values[1], 10.0, epsilon = 1.0e-8);
+
+    let smallest = lanczos(
+        &a,
+        options(1, EigenvalueSelection::SmallestMagnitude, 20, 1.0e-10, 0),
+    );
+    assert!(smallest.converged);
+    assert_relative_eq!(smallest.eigenvalues[0], 0.0, epsilon = 1.0e-8);
+}
+
+#[test]
+fn lanczos_shift_invert_smallest() {
+    let a = grid_laplacian(20, 15);
+    let n = a.nrows();
+    let expected = sorted(a.clone().symmetric_eigenvalues().as_slice().to_vec());
+
+    let sigma = -1.0e-2;
+    let cholesky = (&a - DMatrix::identity(n, n) * sigma).cholesky().unwrap();
+    let inverse = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+        y.copy_from(x);
+        cholesky.solve_mut(y);
+    });
+
+    let eigen = lanczos_shift_invert(
+        &inverse,
+        sigma,
+        options(10, EigenvalueSelection::LargestMagnitude, 25, 1.0e-12, 0),
+    );
+    assert!(eigen.converged);
+    for i in 0..10 {
+        assert_relative_eq!(eigen.eigenvalues[i], expected[i], epsilon = 1.0e-8);
+    }
+    assert_relative_eq!(
+        &a * &eigen.eigenvectors,
+        &eigen.eigenvectors * DMatrix::from_diagonal(&eigen.eigenvalues),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn lanczos_complex_hermitian() {
+    let n = 60;
+    let m = DMatrix::from_fn(n, n, |i, j| {
+        Complex::new(((i + 2 * j) as f64).cos(), (i as f64 - j as f64).sin())
+    });
+    let a = &m
+        + m.adjoint()
+        + DMatrix::from_fn(n, n, |i, j| {
+            Complex::from(if i == j { (i as f64).sqrt() } else { 0.0 })
+        });
+    let expected = sorted(a.clone().symmetric_eigenvalues().as_slice().to_vec());
+
+    let eigen = lanczos(
+        &a,
+        options(4, EigenvalueSelection::LargestReal, 20, 1.0e-10, 0),
+    );
+    assert!(eigen.converged);
+    for i in 0..4 {
+        assert_relative_eq!(eigen.eigenvalues[i], expected[n - 1 - i], epsilon = 1.0e-8);
+    }
+
+    let d = eigen.eigenvalues.map(Complex::from);
+    assert_relative_eq!(
+        &a * &eigen.eigenvectors,
+        &eigen.eigenvectors * DMatrix::from_diagonal(&d),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn lanczos_invariant_subspace() {
+    // Only three distinct eigenvalues: the Krylov subspace is invariant after three iterations.
+    let n = 30;
+    let a = DMatrix::from_diagonal(&DVector::from_fn(n, |i, _| (i % 3) as f64));
+
+    let eigen = lanczos(
+        &a,
+        options(2, EigenvalueSelection::LargestReal, 8, 1.0e-12, 0),
+    );
+    assert!(eigen.converged);
+    assert_eq!(eigen.niter, 0);
+    assert_relative_eq!(
+        eigen.eigenvalues,
+        DVector::from_vec(vec![2.0, 2.0]),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        &a * &eigen.eigenvectors,
+        &eigen.eigenvectors * 2.0,
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn arnoldi_non_symmetric() {
+    let n = 80;
+    let a = non_symmetric(n);
+    let ac = a.map(Complex::from);
+    let expected = a.clone().complex_eigenvalues();
+
+    for which in [
+        EigenvalueSelection::LargestMagnitude,
+        EigenvalueSelection::LargestReal,
+        EigenvalueSelection::SmallestReal,
+    ] {
+        let eigen = arnoldi(&a, options(4, which, 20, 1.0e-10, 0));
+        assert!(eigen.converged);
+
+        for (i, lambda) in eigen.eigenvalues.iter().enumerate() {
+            // Each computed eigenvalue is an eigenvalue of `a`.
+            assert!(expected.iter().any(|e| (e - lambda).norm() < 1.0e-7));
+
+            let v = eigen.eigenvectors.column(i);
+            assert_relative_eq!(v.norm(), 1.0, epsilon = 1.0e-10);
+            assert_relative_eq!(&ac * v, v * *lambda, epsilon = 1.0e-7);
+        }
+
+        // The computed eigenvalues are the most wanted ones.
+        let key = |e: &Complex<f64>| match which {
+            EigenvalueSelection::LargestMagnitude => -e.norm(),
+            EigenvalueSelection::LargestReal => -e.re,
+            _ => e.re,
+        };
+        let mut keys = sorted(expected.iter().map(key).collect());
+        keys.truncate(4);
+        let computed: Vec<_> = eigen.eigenvalues.iter().map(key).collect();
+        assert_relative_eq!(computed.as_slice(), keys.as_slice(), epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn arnoldi_complex_eigenvalues() {
+    // Rotation-like 2x2 blocks with eigenvalues `i ± k * i`, coupled by an upper triangular part.
+    let n = 40;
+    let a = DMatrix::from_fn(n, n, |i, j| {
+        let (bi, bj) = (i / 2, j / 2);
+        if bi == bj {
+            let k = bi as f64 + 1.0;
+            match (i % 2, j % 2) {
+                (0, 1) => k,
+                (1, 0) => -k,
+                _ => 1.0,
+            }
+        } else if bj == bi + 1 {
+            0.1
+        } else {
+            0.0
+        }
+    });
+
+    let eigen = arnoldi(
+        &a,
+        options(4, EigenvalueSelection::LargestMagnitude, 16, 1.0e-10, 0),
+    );
+    assert!(eigen.converged);
+    // The restarts apply pairs of complex conjugate shifts.
+    assert!(eigen.niter > 0);
+    assert_relative_eq!(
+        eigen.eigenvalues[0],
+        Complex::new(1.0, 20.0),
+        epsilon = 1.0e-8
+    );
+    assert_relative_eq!(
+        eigen.eigenvalues[1],
+        Complex::new(1.0, -20.0),
+        epsilon = 1.0e-8
+    );
+    assert_relative_eq!(
+        eigen.eigenvalues[2],
+        Complex::new(1.0, 19.0),
+        epsilon = 1.0e-8
+    );
+    assert_relative_eq!(
+        eigen.eigenvalues[3],
+        Complex::new(1.0, -19.0),
+        epsilon = 1.0e-8
+    );
+
+    let ac = a.map(Complex::from);
+    for (i, lambda) in eigen.eigenvalues.iter().enumerate() {
+        let v = eigen.eigenvectors.column(i);
+        assert_relative_eq!(&ac * v, v * *lambda, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn arnoldi_shift_invert_interior() {
+    let n = 80;
+    let a = non_symmetric(n);
+    let expected = a.clone().complex_eigenvalues();
+
+    let sigma = 0.3;
+    let lu = (&a - DMatrix::identity(n, n) * sigma).lu();
+    let inverse = FnOperator::square(n, |x: &DVector<f64>, y: &mut DVector<f64>| {
+        y.copy_from(x);
+        assert!(lu.solve_mut(y));
+    });
+
+    let eigen = arnoldi_shift_invert(
+        &inverse,
+        sigma,
+        options(3, EigenvalueSelection::LargestMagnitude, 12, 1.0e-12, 0),
+    );
+    assert!(eigen.converged);
+
+    let mut distances = sorted(expected.iter().map(|e| (e - sigma).norm()).collect());
+    distances.truncate(3);
+    let computed: Vec<_> = eigen
+        .eigenvalues
+        .iter()
+        .map(|e| (e - sigma).norm())
+        .collect();
+    assert_relative_eq!(computed.as_slice(), distances.as_slice(), epsilon = 1.0e-8);
+
+    let ac = a.map(Complex::from);
+    for (i, lambda) in eigen.eigenvalues.iter().enumerate() {
+        let v = eigen.eigenvectors.column(i);
+        assert_relative_eq!(&ac * v, v * *lambda, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn krylov_eigen_not_converged() {
+    let a = grid_laplacian(12, 9);
+
+    let lanczos = lanczos(
+        &a,
+        options(4, EigenvalueSelection::SmallestReal, 6, 1.0e-14, 1),
+    );
+    assert!(!lanczos.converged);
+    assert_eq!(lanczos.niter, 1);
+    assert_eq!(lanczos.eigenvalues.len(), 4);
+    assert!(lanczos.residual_norms.iter().any(|r| *r > 1.0e-14));
+
+    let b = non_symmetric(80);
+    let arnoldi = arnoldi(
+        &b,
+        options(4, EigenvalueSelection::SmallestReal, 7, 1.0e-14, 1),
+    );
+    assert!(!arnoldi.converged);
+    assert_eq!(arnoldi.niter, 1);
+    assert_eq!(arnoldi.eigenvectors.shape(), (80, 4));
+}
+
+#[test]
+fn krylov_eigen_default_options() {
+    let a = grid_laplacian(12, 9);
+    let expected = sorted(a.clone().symmetric_eigenvalues().as_slice().to_vec());
+    let largest = expected[expected.len() - 1];
+
+    let lanczos = lanczos(&a, KrylovEigenOptions::new(3));
+    assert!(lanczos.converged);
+    assert_relative_eq!(lanczos.eigenvalues[0], largest, epsilon = 1.0e-10);
+
+    let arnoldi = arnoldi(&a, KrylovEigenOptions::new(3));
+    assert!(arnoldi.converged);
+    assert_relative_eq!(arnoldi.eigenvalues[0].re, largest, epsilon = 1.0e-10);
+}
+
+#[test]
+#[should_panic]
+fn krylov_eigen_non_square_operator() {
+    let a = DMatrix::<f64>::zeros(30, 29);
+    let _ = lanczos(&a, KrylovEigenOptions::new(2));
+}
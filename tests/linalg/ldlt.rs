@@ -0,0 +1,150 @@
+use na::{DMatrix, DVector, Matrix2, Matrix3, Matrix4, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_simple() {
+    let m = Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+
+    let ldlt = m.ldlt();
+    assert!(relative_eq!(ldlt.determinant(), 4.0, epsilon = 1.0e-12));
+    assert_eq!(ldlt.inertia(), (3, 0, 0));
+
+    let (p, l, d) = ldlt.unpack();
+    let mut ldlt = l * d * l.transpose();
+    p.inv_permute_rows(&mut ldlt);
+    p.inv_permute_columns(&mut ldlt);
+
+    assert!(relative_eq!(m, ldlt, epsilon = 1.0e-12));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_zero_diagonal_needs_2x2_pivot() {
+    let m = Matrix2::new(
+        0.0, 1.0,
+        1.0, 0.0);
+
+    let ldlt = m.ldlt();
+    assert!(relative_eq!(ldlt.determinant(), -1.0, epsilon = 1.0e-12));
+    assert_eq!(ldlt.inertia(), (1, 1, 0));
+    assert!(relative_eq!(ldlt.try_inverse().unwrap(), m, epsilon = 1.0e-12));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_kkt_system() {
+    // Equality-constrained quadratic program: the Hessian is positive-definite and there are
+    // two constraints, so the KKT matrix has 2 positive and 2 negative eigenvalues.
+    let m = Matrix4::new(
+        4.0, 1.0, 1.0, 0.0,
+        1.0, 3.0, 1.0, 1.0,
+        1.0, 1.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0);
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    let ldlt = m.ldlt();
+    assert_eq!(ldlt.inertia(), (2, 2, 0));
+    assert!(relative_eq!(ldlt.determinant(), m.determinant(), epsilon = 1.0e-10));
+
+    let x = ldlt.solve(&b).unwrap();
+    assert!(relative_eq!(m * x, b, epsilon = 1.0e-10));
+
+    let inv = ldlt.try_inverse().unwrap();
+    assert!(relative_eq!(m * inv, Matrix4::identity(), epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_singular() {
+    let m = Matrix3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        3.0, 6.0, -1.0);
+
+    let ldlt = m.ldlt();
+    assert_eq!(ldlt.determinant(), 0.0);
+    assert!(!ldlt.is_invertible());
+    assert_eq!(ldlt.inertia().2, 1);
+    assert!(ldlt.solve(&na::Vector3::new(1.0, 2.0, 3.0)).is_none());
+}
+
+#[test]
+fn ldlt_reads_lower_triangle_only() {
+    let m = DMatrix::from_row_slice(3, 3, &[1.0, 100.0, 100.0, 2.0, -3.0, 100.0, 0.5, 1.0, 0.0]);
+    let full =
+        m.lower_triangle() + m.lower_triangle().transpose() - DMatrix::from_diagonal(&m.diagonal());
+    let b = DVector::from_vec(vec![1.0, -1.0, 2.0]);
+
+    let x = m.ldlt().solve(&b).unwrap();
+    assert!(relative_eq!(full * x, b, epsilon = 1.0e-10));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr) => {
+            mod $module {
+                use na::{DMatrix, Vector4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn ldlt(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<f64>::new_random(n, n).hermitian_part() - DMatrix::identity(n, n) * 0.5;
+                        let (p, l, d) = m.clone().ldlt().unpack();
+                        let mut ldlt = &l * d * l.transpose();
+                        p.inv_permute_rows(&mut ldlt);
+                        p.inv_permute_columns(&mut ldlt);
+
+                        prop_assert!(relative_eq!(m, ldlt, epsilon = 1.0e-7))
+                    }
+
+                    #[test]
+                    fn ldlt_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<f64>::new_random(n, n).hermitian_part() - DMatrix::identity(n, n) * 0.5;
+                        let ldlt = m.clone().ldlt();
+                        let b = DMatrix::<f64>::new_random(n, nb);
+
+                        if let Some(x) = ldlt.solve(&b) {
+                            let scale = m.amax().max(1.0) * x.amax().max(1.0);
+                            prop_assert!(relative_eq!(&m * x, b, epsilon = 1.0e-7 * scale));
+                        }
+                    }
+
+                    #[test]
+                    fn ldlt_inertia(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<f64>::new_random(n, n).hermitian_part() - DMatrix::identity(n, n) * 0.5;
+                        let eigenvalues = m.symmetric_eigenvalues();
+                        let npos = eigenvalues.iter().filter(|e| **e > 0.0).count();
+                        let nneg = eigenvalues.iter().filter(|e| **e < 0.0).count();
+                        let (lpos, lneg, _) = m.ldlt().inertia();
+
+                        prop_assert_eq!((lpos, lneg), (npos, nneg));
+                    }
+
+                    #[test]
+                    fn ldlt_static(m in matrix4_($scalar)) {
+                        let m = m.hermitian_part();
+                        let ldlt = m.ldlt();
+                        let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+                        prop_assert!(relative_eq!(ldlt.determinant(), m.determinant(), epsilon = 1.0e-7, max_relative = 1.0e-7));
+
+                        if let Some(x) = ldlt.solve(&b) {
+                            let scale = m.amax().max(1.0) * x.amax().max(1.0);
+                            prop_assert!(relative_eq!(m * x, b, epsilon = 1.0e-7 * scale));
+                        }
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(f64, PROPTEST_F64);
+}
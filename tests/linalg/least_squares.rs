@@ -0,0 +1,131 @@
+use na::{DMatrix, DVector, Matrix4x3, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn qr_least_squares_line_fit() {
+    // Fit y = a + b * t through (0, 1), (1, 3), (2, 4), (3, 8).
+    let m = Matrix4x3::new(
+        1.0, 0.0, 0.0,
+        1.0, 1.0, 1.0,
+        1.0, 2.0, 4.0,
+        1.0, 3.0, 9.0);
+    let b = Vector4::new(1.0, 3.0, 4.0, 8.0);
+
+    let sol = m.qr().solve_least_squares(&b).unwrap();
+    let normal = (m.transpose() * m).lu().solve(&(m.transpose() * b)).unwrap();
+
+    assert_eq!(sol.rank, 3);
+    assert!(relative_eq!(sol.solution, normal, epsilon = 1.0e-10));
+    assert!(relative_eq!(sol.residual_norm, (m * sol.solution - b).norm(), epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn col_piv_qr_least_squares_rank_deficient() {
+    let m = DMatrix::from_row_slice(4, 3, &[
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+    ]);
+    let b = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+
+    let qr = m.clone().col_piv_qr();
+    let sol = qr.solve_least_squares(&b, 1.0e-10);
+    let reference = m.clone().svd(true, true).solve_least_squares(&b).unwrap();
+
+    // Both solutions minimize the residual, but only the SVD one has a minimal norm.
+    assert_eq!(sol.rank, 2);
+    assert_eq!(reference.rank, 2);
+    assert!(relative_eq!(sol.residual_norm, reference.residual_norm, epsilon = 1.0e-10));
+    assert!(relative_eq!(sol.residual_norm, (&m * &sol.solution - &b).norm(), epsilon = 1.0e-10));
+    assert!(sol.solution.norm() >= reference.solution.norm() - 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn least_squares_underdetermined_minimum_norm() {
+    let m = DMatrix::from_row_slice(2, 4, &[
+        1.0, 2.0, 0.0, 1.0,
+        0.0, 1.0, 1.0, -1.0,
+    ]);
+    let b = DVector::from_vec(vec![3.0, 1.0]);
+
+    let sol = m.solve_least_squares(&b).unwrap();
+    let pinv = m.clone().pseudo_inverse(1.0e-10).unwrap();
+
+    assert_eq!(sol.rank, 2);
+    assert!(relative_eq!(sol.residual_norm, 0.0, epsilon = 1.0e-10));
+    assert!(relative_eq!(&m * &sol.solution, b, epsilon = 1.0e-10));
+    assert!(relative_eq!(sol.solution, pinv * &b, epsilon = 1.0e-10));
+
+    // The basic solution of the column-pivoted QR is also exact.
+    let basic = m.clone().col_piv_qr().solve_least_squares(&b, 1.0e-10);
+    assert!(relative_eq!(&m * basic.solution, b, epsilon = 1.0e-10));
+}
+
+#[test]
+fn least_squares_multiple_right_hand_sides() {
+    let m = DMatrix::from_fn(6, 3, |i, j| ((i + 1) as f64).powi(j as i32));
+    let b = DMatrix::from_fn(6, 2, |i, j| (i as f64 * 0.7 + j as f64).sin());
+
+    let sol = m.solve_least_squares(&b).unwrap();
+    let qr_sol = m.clone().qr().solve_least_squares(&b).unwrap();
+
+    assert!(relative_eq!(
+        sol.solution,
+        qr_sol.solution,
+        epsilon = 1.0e-8
+    ));
+    assert!(relative_eq!(
+        sol.residual_norm,
+        (&m * &sol.solution - &b).norm(),
+        epsilon = 1.0e-10
+    ));
+    assert!(relative_eq!(
+        sol.residual_norm,
+        qr_sol.residual_norm,
+        epsilon = 1.0e-10
+    ));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::DMatrix;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    use crate::proptest::*;
+
+    proptest! {
+        #[test]
+        fn least_squares_overdetermined(n in PROPTEST_MATRIX_DIM, extra in 0usize..5) {
+            let m = DMatrix::<f64>::new_random(n + extra, n);
+            let b = DMatrix::<f64>::new_random(n + extra, 2);
+
+            let qr = m.clone().qr().solve_least_squares(&b).unwrap();
+            let col_piv_qr = m.clone().col_piv_qr().solve_least_squares(&b, 1.0e-12);
+            let svd = m.solve_least_squares(&b).unwrap();
+
+            // The residual is orthogonal to the range of `m`.
+            let residual = &m * &qr.solution - &b;
+            prop_assert!(relative_eq!(m.transpose() * residual, DMatrix::zeros(n, 2), epsilon = 1.0e-6));
+
+            prop_assert_eq!(col_piv_qr.rank, n);
+            prop_assert!(relative_eq!(qr.residual_norm, col_piv_qr.residual_norm, epsilon = 1.0e-6));
+            prop_assert!(relative_eq!(qr.residual_norm, svd.residual_norm, epsilon = 1.0e-6));
+        }
+
+        #[test]
+        fn least_squares_underdetermined(n in PROPTEST_MATRIX_DIM, extra in 1usize..5) {
+            let m = DMatrix::<f64>::new_random(n, n + extra);
+            let b = DMatrix::<f64>::new_random(n, 1);
+
+            let col_piv_qr = m.clone().col_piv_qr().solve_least_squares(&b, 1.0e-12);
+            let svd = m.solve_least_squares(&b).unwrap();
+
+            prop_assert!(relative_eq!(&m * &svd.solution, b, epsilon = 1.0e-6));
+            prop_assert!(relative_eq!(&m * &col_piv_qr.solution, b, epsilon = 1.0e-6));
+            prop_assert!(svd.solution.norm() <= col_piv_qr.solution.norm() + 1.0e-6);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use na::{DMatrix, Matrix1, Matrix2, Matrix3, Matrix4, MatrixFunctionError};
+
+#[test]
+fn ln_identity() {
+    let m = Matrix3::<f64>::identity();
+    assert!(relative_eq!(m.ln(), Matrix3::zeros(), epsilon = 1.0e-12));
+}
+
+#[test]
+fn ln_scalar() {
+    let m = Matrix1::new(3.0_f64);
+    assert!(relative_eq!(
+        m.ln(),
+        Matrix1::new(3.0_f64.ln()),
+        epsilon = 1.0e-12
+    ));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ln_diagonal() {
+    let m = Matrix3::new(
+        2.0, 0.0, 0.0,
+        0.0, 5.0, 0.0,
+        0.0, 0.0, 0.5);
+    let expected = Matrix3::from_diagonal(&m.diagonal().map(|e: f64| e.ln()));
+
+    assert!(relative_eq!(m.ln(), expected, epsilon = 1.0e-10));
+}
+
+#[test]
+fn ln_rotation() {
+    // The logarithm of a rotation matrix is the skew-symmetric matrix of its angle,
+    // which exercises the 2x2 blocks of the real Schur form.
+    let angle = 1.2_f64;
+    let (s, c) = angle.sin_cos();
+    let m = Matrix2::new(c, -s, s, c);
+    let expected = Matrix2::new(0.0, -angle, angle, 0.0);
+
+    assert!(relative_eq!(m.ln(), expected, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ln_exp_roundtrip() {
+    let m = Matrix4::new(
+        0.1, 0.7, -0.3, 0.2,
+        -0.4, 0.2, 0.5, 0.0,
+        0.3, -0.6, -0.1, 0.4,
+        0.0, 0.2, -0.5, 0.3);
+
+    assert!(relative_eq!(m.exp().ln(), m, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn exp_ln_roundtrip_dynamic() {
+    let m = DMatrix::from_row_slice(4, 4, &[
+        4.0, 1.0, 0.5, 0.0,
+        1.0, 3.0, 0.2, 0.1,
+        -0.5, 0.3, 2.0, 1.0,
+        0.0, 0.1, -1.0, 5.0,
+    ]);
+
+    assert!(relative_eq!(m.ln().exp(), m, epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ln_non_normal() {
+    let m = Matrix3::new(
+        1.0, 100.0, 3.0,
+        0.0, 2.0, -50.0,
+        0.0, 0.0, 3.0);
+
+    assert!(relative_eq!(m.ln().exp(), m, epsilon = 1.0e-8));
+}
+
+#[test]
+fn ln_negative_eigenvalue() {
+    let m = Matrix2::new(-1.0, 0.0, 0.0, 2.0);
+    assert_eq!(m.try_ln(), Err(MatrixFunctionError::NonPositiveEigenvalue));
+}
+
+#[test]
+fn ln_singular() {
+    let m = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    assert_eq!(m.try_ln(), Err(MatrixFunctionError::NonPositiveEigenvalue));
+}
+
+#[test]
+#[should_panic]
+fn ln_negative_eigenvalue_panic() {
+    let m = Matrix3::new(1.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 3.0);
+    let _ = m.ln();
+}
+
+#[test]
+fn ln_exp_roundtrip_complex() {
+    use na::Complex;
+    let m = Matrix3::new(
+        Complex::new(0.1, 0.2),
+        Complex::new(0.3, -0.1),
+        Complex::new(0.0, 0.4),
+        Complex::new(-0.2, 0.0),
+        Complex::new(0.5, 0.1),
+        Complex::new(0.1, 0.1),
+        Complex::new(0.2, -0.3),
+        Complex::new(0.0, 0.2),
+        Complex::new(-0.4, 0.0),
+    );
+
+    assert!(relative_eq!(m.exp().ln(), m, epsilon = 1.0e-10));
+}
@@ -1,4 +1,4 @@
-use na::Matrix3;
+use na::{Matrix3, Vector3};
 
 #[test]
 #[rustfmt::skip]
@@ -38,6 +38,27 @@ fn lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+#[rustfmt::skip]
+fn lu_rank_one_update_simple() {
+    let m = Matrix3::new(
+        4.0, -1.0,  0.0,
+       -1.0,  4.0, -1.0,
+        0.0, -1.0,  4.0);
+    let u = Vector3::new(1.0, 2.0, 0.5);
+    let v = Vector3::new(-0.5, 1.0, 1.0);
+
+    let mut lu = m.lu();
+    assert!(lu.rank_one_update(&u, &v));
+
+    let expected = m + u * v.transpose();
+    let (p, l, u) = lu.unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+
+    assert!(relative_eq!(expected, lu, epsilon = 1.0e-10));
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -117,6 +138,53 @@ mod proptest_tests {
                          prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_refactor(m1 in dmatrix_($scalar)) {
+                        let m2 = DMatrix::<$scalar_type>::new_random(m1.nrows(), m1.ncols()).map(|e| e.0);
+
+                        let mut lu = m1.lu();
+                        lu.refactor(&m2);
+                        let (p, l, u) = lu.unpack();
+                        let mut lu = l * u;
+                        p.inv_permute_rows(&mut lu);
+
+                        prop_assert!(relative_eq!(m2, lu, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn lu_rank_one_update(n in PROPTEST_MATRIX_DIM) {
+                        // Diagonally dominant matrices keep their pivots away from zero.
+                        let mut m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        m.fill_diagonal(na::convert(4.0 * n as f64));
+                        let u = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let v = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+
+                        let mut lu = m.clone().lu();
+                        prop_assert!(lu.rank_one_update(&u, &v));
+
+                        let b = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let sol = lu.solve(&b).unwrap();
+                        prop_assert!(relative_eq!((m + &u * v.adjoint()) * sol, b, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn lu_rank_one_update_static(m in matrix4_($scalar)) {
+                        let u = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+                        let v = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+
+                        let mut lu = m.lu();
+
+                        if lu.rank_one_update_mut(&mut u.clone(), &mut v.clone()) {
+                            let (p, l, u2) = lu.unpack();
+                            let mut lu = l * u2;
+                            p.inv_permute_rows(&mut lu);
+                            let expected = m + u * v.adjoint();
+                            let scale = expected.norm().max(1.0) * l.norm().max(1.0);
+
+                            prop_assert!(relative_eq!(expected, lu, epsilon = 1.0e-7 * scale));
+                        }
+                    }
+
                     #[test]
                     fn lu_inverse(n in PROPTEST_MATRIX_DIM) {
                         let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
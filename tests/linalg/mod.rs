@@ -1,18 +1,33 @@
 mod balancing;
+mod banded;
 mod bidiagonal;
 mod cholesky;
 mod col_piv_qr;
+mod complete_orthogonal;
+mod condition;
 mod convolution;
 mod eigen;
 mod exp;
+mod fft;
 mod full_piv_lu;
 mod hessenberg;
 mod inverse;
+mod krylov;
+mod krylov_eigen;
+mod ldlt;
+mod least_squares;
+mod log;
 mod lu;
+mod pca;
 mod pow;
 mod qr;
+mod qz;
+mod riccati;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
+mod sylvester;
+mod toeplitz;
 mod tridiagonal;
 mod udu;
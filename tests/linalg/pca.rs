@@ -0,0 +1,79 @@
+use na::{DMatrix, DVector, PCA};
+
+fn data() -> DMatrix<f64> {
+    // Noisy observations close to the plane spanned by `(1, 1, 0)` and `(0, 1, 2)`.
+    DMatrix::from_fn(50, 3, |i, j| {
+        let (s, t) = ((i as f64 * 0.7).sin(), (i as f64 * 0.3).cos());
+        let noise = ((i * 7 + j * 13) as f64).sin() * 1.0e-2;
+        [s, s + t, 2.0 * t][j] * 3.0 + 1.0 + noise
+    })
+}
+
+#[test]
+fn pca_matches_covariance() {
+    let m = data();
+    let pca = m.pca(3);
+    let cov = m.covariance();
+
+    assert_relative_eq!(pca.mean(), &m.row_mean(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        pca.explained_variance().sum(),
+        cov.trace(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(pca.explained_variance_ratio().sum(), 1.0, epsilon = 1.0e-12);
+
+    // The components are the orthonormal eigenvectors of the covariance matrix.
+    let components = pca.components();
+    assert!((components.transpose() * components).is_identity(1.0e-12));
+    assert_relative_eq!(
+        &cov * components,
+        components * DMatrix::from_diagonal(pca.explained_variance()),
+        epsilon = 1.0e-10
+    );
+
+    let from_cov = PCA::from_covariance(m.row_mean(), cov, 3);
+    assert_relative_eq!(
+        from_cov.explained_variance(),
+        pca.explained_variance(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn pca_transform_roundtrip() {
+    let m = data();
+
+    // All the components reconstruct the data exactly.
+    let pca = m.pca(3);
+    let scores = pca.transform(&m);
+    assert_eq!(scores.shape(), (50, 3));
+    assert_relative_eq!(pca.inverse_transform(&scores), m, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        scores.row_variance_tr(),
+        pca.explained_variance(),
+        epsilon = 1.0e-10
+    );
+
+    // The first two components explain almost all the variance of the data.
+    let pca = m.pca(2);
+    let ratio = pca.explained_variance_ratio();
+    assert!(ratio.sum() > 0.9999);
+    let reconstructed = pca.inverse_transform(&pca.transform(&m));
+    assert!((reconstructed - &m).amax() < 0.05);
+}
+
+#[test]
+fn pca_weighted_covariance() {
+    let m = data();
+    let weights = DVector::from_fn(50, |i, _| 1.0 + (i % 3) as f64);
+    let pca = PCA::from_covariance(
+        m.weighted_row_mean(&weights),
+        m.weighted_covariance(&weights),
+        1,
+    );
+
+    assert_eq!(pca.n_components(), 1);
+    assert!(pca.explained_variance()[0] > 0.0);
+    assert_relative_eq!(pca.components().norm(), 1.0, epsilon = 1.0e-12);
+}
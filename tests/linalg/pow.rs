@@ -1,3 +1,98 @@
+use na::{DMatrix, Matrix2, Matrix3, Matrix4, MatrixFunctionError, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn powf_integer() {
+    let m = Matrix3::new(
+        2.0, 1.0, 0.0,
+        0.5, 3.0, 1.0,
+        0.0, -1.0, 4.0);
+
+    assert!(relative_eq!(m.powf(3.0), m.pow(3), epsilon = 1.0e-9));
+    assert!(relative_eq!(m.powf(-1.0), m.try_inverse().unwrap(), epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_half_is_sqrt() {
+    let m = DMatrix::from_row_slice(3, 3, &[
+        4.0, 1.0, 0.5,
+        1.0, 3.0, 0.2,
+        0.5, 0.2, 2.0,
+    ]);
+
+    assert!(relative_eq!(m.powf(0.5), m.sqrt(), epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_additive() {
+    let m = Matrix3::new(
+        3.0, 1.0, -0.5,
+        0.2, 2.0, 1.0,
+        -1.0, 0.5, 4.0);
+    let a = m.powf(0.3);
+    let b = m.powf(0.7);
+
+    assert!(relative_eq!(a * b, m, epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_singular() {
+    let m = Matrix2::new(
+        1.0, 1.0,
+        1.0, 1.0);
+    let s = m.try_powf(0.5).unwrap();
+
+    assert!(relative_eq!(s, m / 2.0f64.sqrt(), epsilon = 1.0e-10));
+
+    // A non-normal matrix with a repeated zero eigenvalue, whose powers are known.
+    let v = Matrix4::new(
+        1.0, 2.0, 0.0, 1.0,
+        0.0, 1.0, 1.0, -1.0,
+        1.0, 0.0, 3.0, 0.5,
+        -1.0, 0.5, 0.0, 2.0);
+    let v_inv = v.try_inverse().unwrap();
+    let power = |p: f64| {
+        let d = Vector4::new(0.0, 2.0, 0.0, 5.0).map(|e: f64| if e == 0.0 { 0.0 } else { e.powf(p) });
+        v * Matrix4::from_diagonal(&d) * v_inv
+    };
+    let m = power(1.0);
+
+    for p in [0.3, 0.5, 1.0, 2.5] {
+        assert!(relative_eq!(m.powf(p), power(p), epsilon = 1.0e-9));
+    }
+    assert!(relative_eq!(m.powf(3.0), m.pow(3), epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_singular_errors() {
+    // Non-positive powers of a singular matrix.
+    let m = Matrix2::new(
+        1.0, 1.0,
+        1.0, 1.0);
+    assert_eq!(m.try_powf(-0.5), Err(MatrixFunctionError::NonPositiveEigenvalue));
+    assert_eq!(m.try_powf(0.0), Err(MatrixFunctionError::NonPositiveEigenvalue));
+
+    // A defective zero eigenvalue.
+    let m = Matrix3::new(
+        0.0, 1.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 1.0);
+    assert_eq!(m.try_powf(0.5), Err(MatrixFunctionError::NonPositiveEigenvalue));
+}
+
+#[test]
+fn powf_negative_eigenvalue() {
+    let m = Matrix2::new(-1.0, 0.0, 0.0, 1.0);
+    assert_eq!(
+        m.try_powf(0.5),
+        Err(MatrixFunctionError::NonPositiveEigenvalue)
+    );
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -1,9 +1,26 @@
 #![cfg(feature = "proptest-support")]
 
+use na::{allocator::Allocator, ComplexField, DefaultAllocator, Dim, OMatrix};
+
+fn is_qr_of<T, R: Dim, C: Dim>(
+    q: &OMatrix<T, R, R>,
+    r: &OMatrix<T, R, C>,
+    m: &OMatrix<T, R, C>,
+) -> bool
+where
+    T: ComplexField<RealField = f64> + approx::RelativeEq<Epsilon = f64>,
+    DefaultAllocator: Allocator<T, R, R> + Allocator<T, R, C>,
+{
+    relative_eq!(q * r, *m, epsilon = 1.0e-7)
+        && q.is_orthogonal(1.0e-7)
+        && relative_eq!(r.upper_triangle(), *r, epsilon = 1.0e-7)
+}
+
 macro_rules! gen_tests(
     ($module: ident, $scalar: expr, $scalar_type: ty) => {
         mod $module {
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{DMatrix, DVector, Matrix4x3, Matrix5x4, Vector4, Vector5};
+            use super::is_qr_of;
             use std::cmp;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
@@ -109,6 +126,65 @@ macro_rules! gen_tests(
                         prop_assert!(id2.is_identity(1.0e-5));
                     }
                 }
+
+                #[test]
+                fn qr_rank_one_update(m in dmatrix_($scalar)) {
+                    let u = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                    let v = DVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0);
+                    let mut qr = m.clone().qr().rank_one_update(&u, &v);
+                    let expected = &m + &u * v.adjoint();
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &expected));
+
+                    // Chained updates keep the decomposition consistent.
+                    qr.rank_one_update(&u, &v);
+                    let expected = &expected + &u * v.adjoint();
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &expected));
+                }
+
+                #[test]
+                fn qr_insert_remove_column(m in dmatrix_($scalar), j in 0usize..10) {
+                    let j = j.min(m.ncols());
+                    let col = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                    let qr = m.clone().qr().insert_column(j, &col);
+                    let expected = m.clone().insert_column(j, Default::default());
+                    let mut expected = expected;
+                    expected.set_column(j, &col);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &expected));
+
+                    let qr = qr.remove_column(j);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &m));
+                }
+
+                #[test]
+                fn qr_insert_remove_row(m in dmatrix_($scalar), i in 0usize..10) {
+                    let i = i.min(m.nrows());
+                    let row = DVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0).transpose();
+                    let qr = m.clone().qr().insert_row(i, &row);
+                    let mut expected = m.clone().insert_row(i, Default::default());
+                    expected.set_row(i, &row);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &expected));
+
+                    let qr = qr.remove_row(i);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &m));
+                }
+
+                #[test]
+                fn qr_update_static(m in matrix5x3_($scalar)) {
+                    let col = Vector5::<$scalar_type>::new_random().map(|e| e.0);
+                    let qr = m.qr().insert_column(1, &col);
+                    let m2 = Matrix5x4::from_columns(&[m.column(0), col.column(0), m.column(1), m.column(2)]);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &m2));
+
+                    let qr = qr.remove_row(4);
+                    let square = m2.remove_row(4);
+                    prop_assert!(is_qr_of(qr.q(), qr.r(), &square));
+
+                    let b = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+                    if let Ok(sol) = qr.solve_least_squares(&b) {
+                        let scale = sol.solution.norm().max(1.0);
+                        prop_assert!(relative_eq!(square * sol.solution, b, epsilon = 1.0e-5 * scale));
+                    }
+                }
             }
         }
     }
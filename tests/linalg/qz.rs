@@ -0,0 +1,226 @@
+use na::{Complex, DMatrix, GeneralizedEigen, Matrix3, Matrix4, QZ};
+
+fn verify_qz(a: &DMatrix<f64>, b: &DMatrix<f64>, qz: QZ<f64, na::Dyn>) -> bool {
+    let eigenvalues = qz.raw_eigenvalues();
+    let (vsl, s, t, vsr) = qz.unpack();
+    let n = a.nrows();
+    let tol = 1.0e-7 * a.amax().max(b.amax()).max(1.0);
+
+    let reconstructed = relative_eq!(&vsl * &s * vsr.transpose(), a, epsilon = tol)
+        && relative_eq!(&vsl * &t * vsr.transpose(), b, epsilon = tol);
+    let orthogonal = relative_eq!(
+        vsl.transpose() * &vsl,
+        DMatrix::identity(n, n),
+        epsilon = 1.0e-7
+    ) && relative_eq!(
+        vsr.transpose() * &vsr,
+        DMatrix::identity(n, n),
+        epsilon = 1.0e-7
+    );
+    let triangular = t.lower_triangle() == DMatrix::from_diagonal(&t.diagonal())
+        && (0..n).all(|j| (j + 2..n).all(|i| s[(i, j)] == 0.0))
+        && (1..n).all(|i| i + 1 == n || s[(i, i - 1)] == 0.0 || s[(i + 1, i)] == 0.0);
+
+    // Each `(alpha, beta)` pair must make `beta * a - alpha * b` singular.
+    let ac = a.map(Complex::from);
+    let bc = b.map(Complex::from);
+    let singular = eigenvalues.iter().all(|(alpha, beta)| {
+        *beta >= 0.0 && {
+            let pencil = &ac * Complex::from(*beta) - &bc * *alpha;
+            let scale = (alpha.norm() + beta).max(1.0) * a.amax().max(b.amax()).max(1.0);
+            pencil.singular_values().min() <= 1.0e-7 * scale
+        }
+    });
+
+    reconstructed && orthogonal && triangular && singular
+}
+
+#[test]
+#[rustfmt::skip]
+fn qz_identity_b_matches_eigenvalues() {
+    let a = Matrix4::new(
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+        -4.0, 0.0, -5.0, 0.0);
+    let qz = QZ::new(a, Matrix4::identity());
+    let mut eigenvalues: Vec<_> = qz
+        .raw_eigenvalues()
+        .iter()
+        .map(|(alpha, beta)| alpha / beta)
+        .collect();
+    eigenvalues.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+
+    // Roots of (x² + 1)(x² + 4).
+    let expected = [-2.0, -1.0, 1.0, 2.0];
+    for (e, im) in eigenvalues.iter().zip(expected.iter()) {
+        assert!(relative_eq!(e.re, 0.0, epsilon = 1.0e-10));
+        assert!(relative_eq!(e.im, *im, epsilon = 1.0e-10));
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn qz_simple_mat3() {
+    let a = Matrix3::new(
+        -2.0, -4.0, 2.0,
+        -2.0, 1.0, 2.0,
+        4.0, 2.0, 5.0);
+    let b = Matrix3::new(
+        1.0, 2.0, 0.0,
+        0.5, 3.0, -1.0,
+        2.0, 0.0, 4.0);
+
+    let qz = QZ::new(a, b);
+    let (vsl, s, t, vsr) = qz.unpack();
+
+    assert!(relative_eq!(vsl * s * vsr.transpose(), a, epsilon = 1.0e-7));
+    assert!(relative_eq!(vsl * t * vsr.transpose(), b, epsilon = 1.0e-7));
+    assert_eq!(t.lower_triangle(), Matrix3::from_diagonal(&t.diagonal()));
+}
+
+#[test]
+#[rustfmt::skip]
+fn qz_singular_b_has_infinite_eigenvalue() {
+    let a = DMatrix::from_row_slice(3, 3, &[
+        1.0, 2.0, 3.0,
+        0.5, -1.0, 2.0,
+        3.0, 1.0, -2.0,
+    ]);
+    let b = DMatrix::from_row_slice(3, 3, &[
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+        1.0, 1.0, 2.0,
+    ]);
+
+    let qz = QZ::new(a.clone(), b.clone());
+    let eigenvalues = qz.raw_eigenvalues();
+    let ninfinite = eigenvalues.iter().filter(|(_, beta)| *beta < 1.0e-10).count();
+
+    assert_eq!(ninfinite, 1);
+    assert!(verify_qz(&a, &b, qz));
+}
+
+#[test]
+fn qz_empty() {
+    let qz = QZ::new(DMatrix::<f64>::zeros(0, 0), DMatrix::zeros(0, 0));
+    assert_eq!(qz.raw_eigenvalues().len(), 0);
+}
+
+fn verify_generalized_eigen(
+    a: &DMatrix<f64>,
+    b: &DMatrix<f64>,
+    eigen: &GeneralizedEigen<f64, na::Dyn>,
+) -> bool {
+    let ac = a.map(Complex::from);
+    let bc = b.map(Complex::from);
+    let scale = a.amax().max(b.amax()).max(1.0);
+
+    // `beta * a * x = alpha * b * x` and `beta * yᴴ * a = alpha * yᴴ * b`.
+    eigen
+        .eigenvalues
+        .iter()
+        .enumerate()
+        .all(|(i, (alpha, beta))| {
+            let x = eigen.right_eigenvectors.column(i);
+            let y = eigen.left_eigenvectors.column(i);
+            let tol = 1.0e-7 * (alpha.norm() + beta).max(1.0) * scale;
+            let beta = Complex::from(*beta);
+
+            relative_eq!(x.norm(), 1.0, epsilon = 1.0e-7)
+                && relative_eq!(y.norm(), 1.0, epsilon = 1.0e-7)
+                && (&ac * x * beta - &bc * x * *alpha).norm() <= tol
+                && (y.adjoint() * &ac * beta - y.adjoint() * &bc * *alpha).norm() <= tol
+        })
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_simple_mat3() {
+    let a = DMatrix::from_row_slice(3, 3, &[
+        -2.0, -4.0, 2.0,
+        -2.0, 1.0, 2.0,
+        4.0, 2.0, 5.0,
+    ]);
+    let b = DMatrix::from_row_slice(3, 3, &[
+        1.0, 2.0, 0.0,
+        0.5, 3.0, -1.0,
+        2.0, 0.0, 4.0,
+    ]);
+
+    let eigen = GeneralizedEigen::new(a.clone(), b.clone());
+    assert!(verify_generalized_eigen(&a, &b, &eigen));
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_complex_and_infinite() {
+    // A rotation block with eigenvalues ±i, and a singular `b` with an infinite eigenvalue.
+    let a = DMatrix::<f64>::from_row_slice(4, 4, &[
+        0.0, -1.0, 2.0, 1.0,
+        1.0, 0.0, 0.5, -1.0,
+        0.0, 0.0, 3.0, 1.0,
+        0.0, 0.0, 0.0, 2.0,
+    ]);
+    let b = DMatrix::from_row_slice(4, 4, &[
+        1.0, 0.0, 1.0, 0.0,
+        0.0, 1.0, 0.0, 2.0,
+        0.0, 0.0, 1.0, 1.0,
+        0.0, 0.0, 0.0, 0.0,
+    ]);
+
+    let eigen = GeneralizedEigen::new(a.clone(), b.clone());
+    let ninfinite = eigen.eigenvalues.iter().filter(|(_, beta)| *beta < 1.0e-10).count();
+    let ncomplex = eigen.eigenvalues.iter().filter(|(alpha, _)| alpha.im.abs() > 1.0e-10).count();
+
+    assert_eq!(ninfinite, 1);
+    assert_eq!(ncomplex, 2);
+    assert!(verify_generalized_eigen(&a, &b, &eigen));
+}
+
+#[test]
+fn generalized_eigen_empty() {
+    let eigen = GeneralizedEigen::new(DMatrix::<f64>::zeros(0, 0), DMatrix::zeros(0, 0));
+    assert_eq!(eigen.eigenvalues.len(), 0);
+    assert_eq!(eigen.right_eigenvectors.len(), 0);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use super::{verify_generalized_eigen, verify_qz};
+    #[allow(unused_imports)]
+    use crate::core::helper::RandScalar;
+    use crate::proptest::*;
+    use na::{DMatrix, GeneralizedEigen, QZ};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn qz(n in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let b = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let qz = QZ::new(a.clone(), b.clone());
+            prop_assert!(verify_qz(&a, &b, qz));
+        }
+
+        #[test]
+        fn qz_singular_b(n in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let mut b = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            if n > 1 {
+                let col = b.column(0).clone_owned();
+                b.set_column(1, &col);
+            }
+            let qz = QZ::new(a.clone(), b.clone());
+            prop_assert!(verify_qz(&a, &b, qz));
+        }
+
+        #[test]
+        fn generalized_eigen(n in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let b = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let eigen = GeneralizedEigen::new(a.clone(), b.clone());
+            prop_assert!(verify_generalized_eigen(&a, &b, &eigen));
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use na::linalg::{solve_continuous_riccati, solve_discrete_riccati, RiccatiError};
+use na::{Complex, DMatrix, Matrix1, Matrix2, Matrix2x1, Matrix3, Matrix3x2};
+
+#[test]
+fn care_scalar() {
+    // x² - 2x - 1 = 0, whose stabilizing root is 1 + √2.
+    let one = Matrix1::new(1.0);
+    let sol = solve_continuous_riccati(&one, &one, &one, &one).unwrap();
+
+    assert!(relative_eq!(
+        sol.solution[0],
+        1.0 + 2.0f64.sqrt(),
+        epsilon = 1.0e-10
+    ));
+    assert!(sol.residual_norm < 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn care_double_integrator() {
+    let a = Matrix2::new(
+        0.0, 1.0,
+        0.0, 0.0);
+    let b = Matrix2x1::new(0.0, 1.0);
+    let q = Matrix2::identity();
+    let r = Matrix1::new(1.0);
+
+    let sol = solve_continuous_riccati(&a, &b, &q, &r).unwrap();
+    let s3 = 3.0f64.sqrt();
+    let expected = Matrix2::new(
+        s3,  1.0,
+        1.0, s3);
+
+    assert!(relative_eq!(sol.solution, expected, epsilon = 1.0e-10));
+    assert!(sol.residual_norm < 1.0e-10);
+
+    // The LQR closed loop is stable.
+    let k = b.transpose() * sol.solution;
+    let closed_loop = a - b * k;
+    assert!(closed_loop.complex_eigenvalues().iter().all(|e| e.re < 0.0));
+}
+
+#[test]
+fn dare_scalar() {
+    // -x² + 4x + 1 = 0, whose stabilizing root is 2 + √5.
+    let one = Matrix1::new(1.0);
+    let sol = solve_discrete_riccati(&Matrix1::new(2.0), &one, &one, &one).unwrap();
+
+    assert!(relative_eq!(
+        sol.solution[0],
+        2.0 + 5.0f64.sqrt(),
+        epsilon = 1.0e-10
+    ));
+    assert!(sol.residual_norm < 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn dare_unstable_oscillator() {
+    // A slightly unstable rotation, whose eigenvalues are complex.
+    let (s, c) = 0.3f64.sin_cos();
+    let a = Matrix3::new(
+        1.05 * c, -1.05 * s, 0.0,
+        1.05 * s,  1.05 * c, 0.1,
+        0.0,       0.0,      0.9);
+    let b = Matrix3x2::new(
+        0.0, 1.0,
+        1.0, 0.0,
+        0.5, 0.5);
+    let q = Matrix3::from_diagonal_element(2.0);
+    let r = Matrix2::new(
+        1.0, 0.2,
+        0.2, 3.0);
+
+    let sol = solve_discrete_riccati(&a, &b, &q, &r).unwrap();
+    let x = sol.solution;
+
+    assert!(sol.residual_norm < 1.0e-9);
+    assert!(relative_eq!(x, x.transpose(), epsilon = 1.0e-10));
+    assert!(x.cholesky().is_some());
+
+    let k = (r + b.transpose() * x * b).lu().solve(&(b.transpose() * x * a)).unwrap();
+    let closed_loop = a - b * k;
+    assert!(closed_loop.complex_eigenvalues().iter().all(|e| e.norm() < 1.0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn dare_singular_state_matrix() {
+    // With A = 0, the equation reduces to X = Q.
+    let one = Matrix1::new(1.0);
+    let sol = solve_discrete_riccati(&Matrix1::new(0.0), &one, &one, &one).unwrap();
+    assert!(relative_eq!(sol.solution[0], 1.0, epsilon = 1.0e-10));
+
+    // A nilpotent state matrix.
+    let a = Matrix2::new(
+        0.0, 1.0,
+        0.0, 0.0);
+    let id = Matrix2::identity();
+    let sol = solve_discrete_riccati(&a, &id, &id, &id).unwrap();
+    let x = sol.solution;
+    let expected = Matrix2::new(
+        1.0, 0.0,
+        0.0, 1.5);
+
+    assert!(relative_eq!(x, expected, epsilon = 1.0e-10));
+    assert!(sol.residual_norm < 1.0e-10);
+
+    let k = (id + x).lu().solve(&(x * a)).unwrap();
+    let closed_loop = a - k;
+    assert!(closed_loop.complex_eigenvalues().iter().all(|e| e.norm() < 1.0));
+
+    // Multiplying A by a unit complex number leaves the solution unchanged.
+    let a = a.map(|e| Complex::new(0.0, e));
+    let id = Matrix2::<Complex<f64>>::identity();
+    let sol = solve_discrete_riccati(&a, &id, &id, &id).unwrap();
+
+    assert!(relative_eq!(sol.solution, expected.map(Complex::from), epsilon = 1.0e-10));
+    assert!(sol.residual_norm < 1.0e-10);
+}
+
+#[test]
+fn riccati_errors() {
+    let one = Matrix1::new(1.0);
+    let zero = Matrix1::new(0.0);
+
+    assert_eq!(
+        solve_continuous_riccati(&one, &one, &one, &zero).unwrap_err(),
+        RiccatiError::SingularInputWeight
+    );
+    // The unstable mode cannot be controlled.
+    assert_eq!(
+        solve_continuous_riccati(&one, &zero, &one, &one).unwrap_err(),
+        RiccatiError::NoStabilizingSolution
+    );
+
+    // Same with a controllable stable mode, where `U₁₁` is singular only up to rounding errors.
+    let a = Matrix2::new(2.0, 0.0, 0.0, 0.5);
+    let b = Matrix2x1::new(0.0, 1.0);
+    let id = Matrix2::identity();
+    assert_eq!(
+        solve_continuous_riccati(&a, &b, &id, &one).unwrap_err(),
+        RiccatiError::NoStabilizingSolution
+    );
+    assert_eq!(
+        solve_discrete_riccati(&a, &b, &id, &one).unwrap_err(),
+        RiccatiError::NoStabilizingSolution
+    );
+
+    // The decompositions do not converge, instead of looping forever, on non-finite inputs.
+    let q = Matrix2::new(1.0, f64::NAN, f64::NAN, 1.0);
+    assert_eq!(
+        solve_continuous_riccati(&a, &b, &q, &one).unwrap_err(),
+        RiccatiError::NoConvergence
+    );
+    assert_eq!(
+        solve_discrete_riccati(&a, &b, &q, &one).unwrap_err(),
+        RiccatiError::NoConvergence
+    );
+}
+
+#[test]
+fn riccati_empty() {
+    let a = DMatrix::<f64>::zeros(0, 0);
+    let b = DMatrix::<f64>::zeros(0, 2);
+    let r = DMatrix::<f64>::identity(2, 2);
+
+    let sol = solve_continuous_riccati(&a, &b, &a, &r).unwrap();
+    assert_eq!(sol.solution, a);
+    let sol = solve_discrete_riccati(&a, &b, &a, &r).unwrap();
+    assert_eq!(sol.solution, a);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::linalg::{solve_continuous_riccati, solve_discrete_riccati};
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn care(n in 1usize..8, m in 1usize..4) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, m).map(|e| e.0);
+                        let q = DMatrix::identity(n, n);
+                        let r = DMatrix::identity(m, m);
+
+                        let sol = solve_continuous_riccati(&a, &b, &q, &r).unwrap();
+                        let x = sol.solution;
+                        let residual = a.adjoint() * &x + &x * &a - &x * &b * b.adjoint() * &x + &q;
+
+                        let tol = 1.0e-7 * (1.0 + x.norm()).powi(2);
+
+                        prop_assert!(relative_eq!(sol.residual_norm, residual.norm(), epsilon = tol));
+                        prop_assert!(sol.residual_norm <= tol);
+                        prop_assert!(relative_eq!(x, x.adjoint(), epsilon = 1.0e-7 * (1.0 + x.norm())));
+                    }
+
+                    #[test]
+                    fn dare(n in 1usize..8, m in 1usize..4) {
+                        // Keep the state matrix away from singularity, and its spectrum close to the unit circle.
+                        let mut a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        a.fill_diagonal(na::convert(1.0));
+                        let b = DMatrix::<$scalar_type>::new_random(n, m).map(|e| e.0);
+                        let q = DMatrix::identity(n, n);
+                        let r = DMatrix::identity(m, m);
+
+                        let sol = solve_discrete_riccati(&a, &b, &q, &r).unwrap();
+                        let x = sol.solution;
+
+                        prop_assert!(sol.residual_norm <= 1.0e-7 * (1.0 + x.norm() * a.norm() * a.norm()));
+                        prop_assert!(relative_eq!(x, x.adjoint(), epsilon = 1.0e-7 * (1.0 + x.norm())));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
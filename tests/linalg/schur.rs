@@ -96,6 +96,27 @@ fn schur_static_mat3_fail() {
     assert!(relative_eq!(vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
 }
 
+// The standard shifts cycle without ever deflating on this Hamiltonian matrix.
+#[test]
+#[rustfmt::skip]
+fn schur_hamiltonian_exceptional_shift() {
+    let m = DMatrix::from_row_slice(10, 10, &[
+          -3.0,  -10.0,   -5.0,   10.0,    2.0,  -85.0,   55.0,  -70.0,   40.0,  -12.0,
+           4.0,   -5.0,    1.0,   -4.0,   -4.0,   55.0,  -65.0,   60.0,   30.0,   66.0,
+           7.0,    2.0,   -2.0,    4.0,    6.0,  -70.0,   60.0,  -65.0,    5.0,  -39.0,
+          -7.0,    8.0,    5.0,   -7.0,   -8.0,   40.0,   30.0,    5.0, -125.0, -105.0,
+           7.0,    7.0,    7.0,   -7.0,    1.0,  -12.0,   66.0,  -39.0, -105.0, -117.0,
+        -184.0,   13.0,  -13.0,   49.0,  111.0,    3.0,   -4.0,   -7.0,    7.0,   -7.0,
+          13.0, -252.0,  -33.0,  -39.0,   74.0,   10.0,    5.0,   -2.0,   -8.0,   -7.0,
+         -13.0,  -33.0, -277.0,   16.0,  -46.0,    5.0,   -1.0,    2.0,   -5.0,   -7.0,
+          49.0,  -39.0,   16.0,  -96.0,  -36.0,  -10.0,    4.0,   -4.0,    7.0,    7.0,
+         111.0,   74.0,  -46.0,  -36.0, -126.0,   -2.0,    4.0,   -6.0,    8.0,   -1.0,
+    ]);
+
+    let (vecs, vals) = m.clone().try_schur(f64::EPSILON, 1000).unwrap().unpack();
+    assert!(relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
+}
+
 // Test proposed on the issue #176 of rulinalg.
 #[test]
 #[rustfmt::skip]
@@ -129,3 +150,4 @@ fn schur_singular() {
     let (vecs, vals) = m.clone().schur().unpack();
     assert!(relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
 }
+
@@ -0,0 +1,113 @@
+use na::{DMatrix, Matrix1, Matrix2, Matrix3, Matrix4, MatrixFunctionError};
+
+#[test]
+fn sqrt_scalar() {
+    let m = Matrix1::new(9.0_f64);
+    assert!(relative_eq!(m.sqrt(), Matrix1::new(3.0), epsilon = 1.0e-12));
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_spd() {
+    let m = Matrix3::new(
+        4.0, 1.0, 0.5,
+        1.0, 3.0, 0.2,
+        0.5, 0.2, 2.0);
+    let s = m.sqrt();
+
+    assert!(relative_eq!(s * s, m, epsilon = 1.0e-10));
+    // The principal square root of an SPD matrix is SPD.
+    assert!(relative_eq!(s, s.transpose(), epsilon = 1.0e-10));
+    assert!(s.symmetric_eigenvalues().iter().all(|e| *e > 0.0));
+}
+
+#[test]
+fn sqrt_rotation() {
+    // The square root of a rotation is the rotation by half the angle.
+    let angle = 2.5_f64;
+    let (s, c) = angle.sin_cos();
+    let (hs, hc) = (angle / 2.0).sin_cos();
+    let m = Matrix2::new(c, -s, s, c);
+    let expected = Matrix2::new(hc, -hs, hs, hc);
+
+    assert!(relative_eq!(m.sqrt(), expected, epsilon = 1.0e-10));
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_non_symmetric() {
+    let m = Matrix4::new(
+        5.0, 1.0, -2.0, 0.3,
+        0.5, 4.0, 1.0, -1.0,
+        1.0, -1.5, 6.0, 0.2,
+        0.0, 0.8, -0.4, 3.0);
+    let s = m.sqrt();
+
+    assert!(relative_eq!(s * s, m, epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_dynamic() {
+    let m = DMatrix::from_row_slice(5, 5, &[
+        6.0, 1.0, 0.0, -1.0, 2.0,
+        1.0, 5.0, 2.0, 0.0, 0.5,
+        -2.0, 0.0, 7.0, 1.0, 0.0,
+        0.0, 1.5, -1.0, 4.0, 1.0,
+        0.5, 0.0, 0.0, -2.0, 8.0,
+    ]);
+    let s = m.sqrt();
+
+    assert!(relative_eq!(&s * &s, m, epsilon = 1.0e-9));
+}
+
+#[test]
+fn sqrt_negative_eigenvalue() {
+    let m = Matrix2::new(-4.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        m.try_sqrt(),
+        Err(MatrixFunctionError::NonPositiveEigenvalue)
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_singular() {
+    let m = Matrix3::from_diagonal(&na::Vector3::new(0.0, 0.0, 4.0));
+    let expected = Matrix3::from_diagonal(&na::Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(m.try_sqrt(), Ok(expected));
+
+    let m = Matrix2::new(
+        1.0, 1.0,
+        1.0, 1.0);
+    assert!(relative_eq!(m.sqrt(), m / 2.0f64.sqrt(), epsilon = 1.0e-10));
+
+    // A non-normal matrix with a zero eigenvalue between two non-zero ones in its Schur form.
+    let m = Matrix3::new(
+        2.0, 1.0, 3.0,
+        0.0, 0.0, 1.0,
+        0.0, 0.0, 5.0);
+    let s = m.sqrt();
+    assert!(relative_eq!(s * s, m, epsilon = 1.0e-10));
+
+    // A non-normal matrix with a repeated zero eigenvalue.
+    let v = Matrix4::new(
+        1.0, 2.0, 0.0, 1.0,
+        0.0, 1.0, 1.0, -1.0,
+        1.0, 0.0, 3.0, 0.5,
+        -1.0, 0.5, 0.0, 2.0);
+    let d = Matrix4::from_diagonal(&na::Vector4::new(0.0, 2.0, 0.0, 5.0));
+    let m = v * d * v.try_inverse().unwrap();
+    let s = m.sqrt();
+    assert!(relative_eq!(s * s, m, epsilon = 1.0e-9));
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_defective_zero_eigenvalue() {
+    let m = Matrix3::new(
+        0.0, 1.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 4.0);
+    assert_eq!(m.try_sqrt(), Err(MatrixFunctionError::NonPositiveEigenvalue));
+}
@@ -1,5 +1,5 @@
 use crate::utils::is_sorted_descending;
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, Matrix4, Matrix6, Vector4};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -10,6 +10,7 @@ mod proptest_tests {
                     DMatrix, DVector, Matrix2, Matrix3, Matrix4,
                     ComplexField
                 };
+                use rand::{rngs::StdRng, SeedableRng};
                 use std::cmp;
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
@@ -119,6 +120,60 @@ mod proptest_tests {
                         prop_assert!(is_sorted_descending(s.as_slice()));
                     }
 
+                    #[test]
+                    fn svd_jacobi(m in dmatrix_($scalar)) {
+                        let svd = m.clone().svd_jacobi(true, true);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+                        let reference = m.clone().svd(false, false).singular_values;
+
+                        prop_assert!(s.iter().all(|e| *e >= 0.0));
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-5));
+                        prop_assert!((u.adjoint() * &u).is_identity(1.0e-5));
+                        prop_assert!((&v_t * v_t.adjoint()).is_identity(1.0e-5));
+                        prop_assert!(relative_eq!(s, reference, epsilon = 1.0e-5));
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                    }
+
+                    #[test]
+                    fn svd_jacobi_static_3_5(m in matrix3x5_($scalar)) {
+                        let svd = m.svd_jacobi(true, true);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = Matrix3::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        prop_assert!(relative_eq!(m, u * ds * v_t, epsilon = 1.0e-5));
+                        prop_assert!(u.is_orthogonal(1.0e-5));
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                    }
+
+                    #[test]
+                    fn svd_economy(m in dmatrix_($scalar)) {
+                        let svd = m.clone().svd_economy(true, true);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+                        let reference = m.clone().svd(false, false).singular_values;
+
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-5));
+                        prop_assert!((u.adjoint() * &u).is_identity(1.0e-5));
+                        prop_assert!((&v_t * v_t.adjoint()).is_identity(1.0e-5));
+                        prop_assert!(relative_eq!(s, reference, epsilon = 1.0e-5));
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                    }
+
+                    #[test]
+                    fn svd_truncated_full_rank(m in dmatrix_($scalar)) {
+                        // Sampling as many vectors as the smallest dimension captures the whole
+                        // range of the matrix, so the decomposition is exact.
+                        let k = cmp::min(m.nrows(), m.ncols());
+                        let svd = m.svd_truncated(k, 0, 0, &mut StdRng::seed_from_u64(0));
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+                        let reference = m.clone().svd(false, false).singular_values;
+
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(s, reference, epsilon = 1.0e-5));
+                    }
+
                     #[test]
                     fn svd_pseudo_inverse(m in dmatrix_($scalar)) {
                         let svd = m.clone().svd(true, true);
@@ -513,3 +568,80 @@ fn svd_regression_issue_1313() {
     let m2 = svd.recompose().unwrap();
     assert_relative_eq!(&m, &m2, epsilon = 1e-5);
 }
+
+#[test]
+#[rustfmt::skip]
+fn svd_jacobi_graded() {
+    // The columns of a well-conditioned matrix scaled by very different factors. The one-sided
+    // Jacobi SVD computes all the singular values of such a matrix with a high relative accuracy.
+    let b = Matrix4::new(
+        4.0, 1.0, -1.0, 0.5,
+        1.0, 3.0, 0.5, -1.0,
+        -1.0, 0.5, 5.0, 1.0,
+        0.5, -1.0, 1.0, 2.0);
+    let scales = Vector4::new(1.0f64, 1.0e-5, 1.0e-10, 1.0e-15);
+    let m = b * Matrix4::from_diagonal(&scales);
+
+    let svd = m.svd_jacobi(true, true);
+    let s = svd.singular_values;
+
+    // The product of the singular values is `|det(B)| * Π scales`.
+    let expected: f64 = b.determinant().abs() * scales.product();
+    assert_relative_eq!(s.product(), expected, max_relative = 1.0e-12);
+    assert!(is_sorted_descending(s.as_slice()));
+
+    let (u, v_t) = (svd.u.unwrap(), svd.v_t.unwrap());
+    assert!(u.is_orthogonal(1.0e-12));
+    assert!(v_t.is_orthogonal(1.0e-12));
+    assert_relative_eq!(u * Matrix4::from_diagonal(&s) * v_t, m, epsilon = 1.0e-14);
+}
+
+#[test]
+fn svd_jacobi_rank_deficient() {
+    let m = DMatrix::from_fn(5, 3, |i, j| if j == 1 { 0.0 } else { (i + j) as f64 });
+    let svd = m.clone().svd_jacobi(true, true);
+    let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+
+    assert_eq!(s[2], 0.0);
+    assert!((u.transpose() * &u).is_identity(1.0e-12));
+    assert_relative_eq!(&u * DMatrix::from_diagonal(&s) * &v_t, m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn svd_economy_tall() {
+    let m = DMatrix::from_fn(500, 4, |i, j| ((i * (j + 1)) as f64).sin() + j as f64);
+    let svd = m.clone().svd_economy(true, true);
+    let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+
+    assert_eq!(u.shape(), (500, 4));
+    assert_eq!(v_t.shape(), (4, 4));
+    assert!((u.transpose() * &u).is_identity(1.0e-12));
+    assert_relative_eq!(s, m.clone().singular_values(), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * DMatrix::from_diagonal(&s) * &v_t, m, epsilon = 1.0e-10);
+
+    // Wide matrices are decomposed through their adjoint.
+    let svd = m.transpose().svd_economy(true, false);
+    assert_eq!(svd.u.unwrap().shape(), (4, 4));
+    assert!(svd.v_t.is_none());
+    assert_relative_eq!(svd.singular_values, s, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_truncated_low_rank() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let x = DMatrix::<f64>::from_fn(200, 3, |i, j| ((i + 1) as f64 * (j + 2) as f64).cos());
+    let y = DMatrix::<f64>::from_fn(3, 50, |i, j| ((i * 7 + j) as f64).sin());
+    let m = x * y;
+
+    let svd = m.svd_truncated(3, 5, 1, &mut rng);
+    let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+    let reference = m.singular_values();
+
+    assert_eq!(u.shape(), (200, 3));
+    assert_eq!(v_t.shape(), (3, 50));
+    assert!((u.transpose() * &u).is_identity(1.0e-10));
+    assert_relative_eq!(s, reference.rows(0, 3).into_owned(), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * DMatrix::from_diagonal(&s) * &v_t, m, epsilon = 1.0e-10);
+}
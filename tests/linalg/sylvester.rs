@@ -0,0 +1,142 @@
+use na::{
+    linalg::{
+        solve_continuous_lyapunov, solve_discrete_lyapunov, solve_discrete_sylvester,
+        solve_sylvester,
+    },
+    DMatrix, Matrix2, Matrix2x3, Matrix3,
+};
+
+#[test]
+#[rustfmt::skip]
+fn sylvester_complex_eigenvalues() {
+    // Both coefficient matrices have complex eigenvalues, so their real Schur forms contain 2x2
+    // diagonal blocks.
+    let a = Matrix2::new(
+        1.0, -2.0,
+        3.0,  1.0);
+    let b = Matrix3::new(
+        2.0, 1.0, 0.0,
+       -4.0, 2.0, 1.0,
+        0.0, 0.0, 3.0);
+    let c = Matrix2x3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0);
+
+    let x = solve_sylvester(&a, &b, &c).unwrap();
+    assert!(relative_eq!(a * x + x * b, c, epsilon = 1.0e-10));
+
+    let x = solve_discrete_sylvester(&a, &b, &c).unwrap();
+    assert!(relative_eq!(a * x * b - x, c, epsilon = 1.0e-10));
+}
+
+#[test]
+fn sylvester_singular() {
+    // `A` and `-B` share the eigenvalue 1.
+    let a = Matrix2::new(1.0, 0.0, 0.0, 2.0);
+    let b = Matrix2::new(-1.0, 0.0, 0.0, 3.0);
+    assert!(solve_sylvester(&a, &b, &Matrix2::identity()).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn lyapunov_stable_system() {
+    // A damped oscillator.
+    let a = Matrix2::new(
+        0.0,  1.0,
+       -2.0, -0.5);
+    let q = Matrix2::new(
+        1.0, 0.0,
+        0.0, 2.0);
+
+    let x = solve_continuous_lyapunov(&a, &q).unwrap();
+    assert!(relative_eq!(a * x + x * a.transpose() + q, Matrix2::zeros(), epsilon = 1.0e-10));
+    // The controllability gramian of a stable system is symmetric positive-definite.
+    assert!(relative_eq!(x, x.transpose(), epsilon = 1.0e-10));
+    assert!(x.cholesky().is_some());
+
+    let ad = a * 0.5;
+    let x = solve_discrete_lyapunov(&ad, &q).unwrap();
+    assert!(relative_eq!(ad * x * ad.transpose() - x + q, Matrix2::zeros(), epsilon = 1.0e-10));
+    assert!(x.cholesky().is_some());
+}
+
+#[test]
+fn lyapunov_empty() {
+    let a = DMatrix::<f64>::zeros(0, 0);
+    assert_eq!(solve_continuous_lyapunov(&a, &a), Some(a.clone()));
+    assert_eq!(solve_discrete_lyapunov(&a, &a), Some(a));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::linalg::{
+                    solve_continuous_lyapunov, solve_discrete_lyapunov, solve_discrete_sylvester,
+                    solve_sylvester,
+                };
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn sylvester(n in PROPTEST_MATRIX_DIM, m in PROPTEST_MATRIX_DIM) {
+                        // Shift the spectra to the right half-plane so that `A` and `-B` have
+                        // no common eigenvalues.
+                        let mut a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let mut b = DMatrix::<$scalar_type>::new_random(m, m).map(|e| e.0);
+                        a.fill_diagonal(na::convert(2.0 * n as f64));
+                        b.fill_diagonal(na::convert(2.0 * m as f64));
+                        let c = DMatrix::<$scalar_type>::new_random(n, m).map(|e| e.0);
+
+                        let x = solve_sylvester(&a, &b, &c).unwrap();
+                        prop_assert!(relative_eq!(&a * &x + &x * &b, c, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn discrete_sylvester(n in PROPTEST_MATRIX_DIM, m in PROPTEST_MATRIX_DIM) {
+                        // Scale the spectra inside of the unit disk.
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0)
+                            .unscale(2.0 * n as f64);
+                        let b = DMatrix::<$scalar_type>::new_random(m, m).map(|e| e.0)
+                            .unscale(2.0 * m as f64);
+                        let c = DMatrix::<$scalar_type>::new_random(n, m).map(|e| e.0);
+
+                        let x = solve_discrete_sylvester(&a, &b, &c).unwrap();
+                        prop_assert!(relative_eq!(&a * &x * &b - &x, c, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn continuous_lyapunov(m in matrix4_($scalar)) {
+                        let mut a = m;
+                        a.fill_diagonal(na::convert(-8.0));
+                        let q = Matrix4::<$scalar_type>::new_random().map(|e| e.0);
+                        let q = q * q.adjoint();
+
+                        let x = solve_continuous_lyapunov(&a, &q).unwrap();
+                        prop_assert!(relative_eq!(a * x + x * a.adjoint() + q, Matrix4::zeros(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(x, x.adjoint(), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn discrete_lyapunov(m in matrix4_($scalar)) {
+                        let a = m.unscale(16.0);
+                        let q = Matrix4::<$scalar_type>::new_random().map(|e| e.0);
+                        let q = q * q.adjoint();
+
+                        let x = solve_discrete_lyapunov(&a, &q).unwrap();
+                        prop_assert!(relative_eq!(a * x * a.adjoint() - x + q, Matrix4::zeros(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(x, x.adjoint(), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,95 @@
+use na::{dvector, CirculantMatrix, Complex, DMatrix, DVector, Matrix3, ToeplitzMatrix};
+
+#[test]
+#[rustfmt::skip]
+fn toeplitz_conversions() {
+    let toeplitz = ToeplitzMatrix::new(dvector![1.0, 2.0, 3.0], dvector![-1.0, 4.0, 5.0]);
+    let expected = Matrix3::new(
+        1.0, 4.0, 5.0,
+        2.0, 1.0, 4.0,
+        3.0, 2.0, 1.0);
+
+    assert_eq!(toeplitz.row(), &dvector![1.0, 4.0, 5.0]);
+    assert_eq!(toeplitz.to_dense(), expected);
+    assert_eq!(ToeplitzMatrix::from_dense(&expected), toeplitz);
+
+    let v = DMatrix::from_fn(3, 2, |i, j| (i * 2 + j) as f64);
+    assert_eq!(&toeplitz * &v, expected * &v);
+    assert_eq!(DMatrix::from(toeplitz), expected);
+}
+
+#[test]
+fn toeplitz_solve() {
+    let n = 40;
+    let toeplitz = ToeplitzMatrix::new(
+        DVector::from_fn(n, |i, _| 1.0 / (1.0 + i as f64)),
+        DVector::from_fn(n, |i, _| (i as f64 * 0.3).cos() / (1.0 + i as f64)),
+    );
+    let b = DMatrix::from_fn(n, 3, |i, j| (i as f64 - j as f64).sin());
+
+    let x = toeplitz.solve(&b).unwrap();
+    assert!(relative_eq!(&toeplitz * &x, b, epsilon = 1.0e-9));
+
+    // A Hermitian-definite-positive autocorrelation matrix.
+    let hermitian = ToeplitzMatrix::hermitian(DVector::from_fn(n, |i, _| {
+        Complex::from_polar(0.8f64.powi(i as i32), 0.5 * i as f64)
+    }));
+    let dense = hermitian.to_dense();
+    assert_eq!(dense, dense.adjoint());
+
+    let b = DVector::from_fn(n, |i, _| Complex::new(i as f64, 1.0));
+    let x = hermitian.solve(&b).unwrap();
+    assert!(relative_eq!(dense * x, b, epsilon = 1.0e-9));
+
+    // A singular leading submatrix.
+    let toeplitz = ToeplitzMatrix::new(dvector![0.0, 1.0], dvector![0.0, 1.0]);
+    assert!(toeplitz.solve(&dvector![1.0, 1.0]).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn circulant() {
+    let circulant = CirculantMatrix::new(dvector![1.0, 2.0, 3.0]);
+    let expected = Matrix3::new(
+        1.0, 3.0, 2.0,
+        2.0, 1.0, 3.0,
+        3.0, 2.0, 1.0);
+
+    assert_eq!(circulant.to_dense(), expected);
+    assert_eq!(CirculantMatrix::from_dense(&expected), circulant);
+
+    let v = DMatrix::from_fn(3, 2, |i, j| (i * 2 + j) as f64);
+    assert!(relative_eq!(&circulant * &v, circulant.to_dense() * &v, epsilon = 1.0e-12));
+
+    // The eigenvalues are the DFT of the first column.
+    let h = 3.0f64.sqrt() / 2.0;
+    let eigenvalues = circulant.eigenvalues();
+    assert!(relative_eq!(eigenvalues[0], Complex::new(6.0, 0.0), epsilon = 1.0e-12));
+    assert!(relative_eq!(eigenvalues[1], Complex::new(-1.5, h), epsilon = 1.0e-12));
+
+    // Non-power-of-two dimension, with several right-hand sides.
+    let n = 37;
+    let circulant = CirculantMatrix::new(DVector::from_fn(n, |i, _| 1.0 / (1.0 + (i * i) as f64)));
+    let b = DMatrix::from_fn(n, 2, |i, j| (i + 3 * j) as f64);
+    let x = circulant.solve(&b).unwrap();
+    assert!(relative_eq!(circulant.to_dense() * x, b, epsilon = 1.0e-9));
+
+    // The row sums are zero, so the constant vector is in the kernel.
+    let singular = CirculantMatrix::new(dvector![1.0, -1.0, 0.0, 0.0]);
+    assert!(singular.solve(&dvector![1.0, 2.0, 3.0, 4.0]).is_none());
+
+    // Non-power-of-two singular matrices, whose zero eigenvalues are only zero up to rounding
+    // errors when computed with the FFT.
+    let singular = CirculantMatrix::new(dvector![1.0, 1.0, 1.0]);
+    let mut b = dvector![1.0, 2.0, 3.0];
+    assert!(singular.solve(&b).is_none());
+    assert!(!singular.solve_mut(&mut b));
+    assert_eq!(b, dvector![1.0, 2.0, 3.0]);
+
+    let singular = CirculantMatrix::new(DVector::from_fn(37, |i, _| match i {
+        0 => 2.0,
+        1 | 36 => -1.0,
+        _ => 0.0,
+    }));
+    assert!(singular.solve(&DVector::from_element(37, 1.0)).is_none());
+}